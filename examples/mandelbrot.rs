@@ -26,13 +26,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let (pw, ph) = (range.0.end - range.0.start, range.1.end - range.1.start);
     let (xr, yr) = (chart.x_range(), chart.y_range());
 
-    for (x, y, c) in mandelbrot_set(xr, yr, (pw as usize, ph as usize), 100) {
-        if c != 100 {
-            plotting_area.draw_pixel((x, y), &HSLColor(c as f64 / 100.0, 1.0, 0.5))?;
-        } else {
-            plotting_area.draw_pixel((x, y), &BLACK)?;
-        }
-    }
+    plotting_area.draw_pixels(mandelbrot_set(xr, yr, (pw as usize, ph as usize), 100).map(
+        |(x, y, c)| {
+            if c != 100 {
+                ((x, y), HSLColor(c as f64 / 100.0, 1.0, 0.5).to_rgba())
+            } else {
+                ((x, y), BLACK.to_rgba())
+            }
+        },
+    ))?;
 
     Ok(())
 }
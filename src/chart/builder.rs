@@ -1,9 +1,15 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
 use super::context::ChartContext;
 
-use crate::coord::{AsRangedCoord, RangedCoord, Shift};
+use crate::coord::{
+    AsRangedCoord, IntoKeyPointPolicy, KeyPointPolicy, RangedCoord, RangedCoordf64, Shift,
+    WithKeyPointPolicy,
+};
 use crate::drawing::backend::DrawingBackend;
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::style::TextStyle;
+use crate::style::{Color, RGBAColor, TextStyle};
 
 /// The enum used to specify the position of label area
 pub enum LabelAreaPosition {
@@ -13,12 +19,59 @@ pub enum LabelAreaPosition {
     Right = 3,
 }
 
+/// The scale of an axis used by `ChartBuilder::build_scaled`
+#[derive(Clone, Debug)]
+pub enum AxisScale {
+    /// Fit the range into the available pixels, which is what `build_ranged` does
+    Fit(Range<f64>),
+    /// Start the axis at the value with the fixed number of pixels per data unit, the end of
+    /// the range is derived from the available pixels. The number of pixels per data unit must
+    /// be positive, which also applies to `EndAt` and `Sized`.
+    StartAt(f64, f64),
+    /// End the axis at the value with the fixed number of pixels per data unit, the start of
+    /// the range is derived from the available pixels. This is useful for a scrolling chart
+    /// that shows the latest data.
+    EndAt(f64, f64),
+    /// Use the range with the fixed number of pixels per data unit, the length of the axis in
+    /// pixels is derived from the range, which may be longer than the available pixels
+    Sized(Range<f64>, f64),
+}
+
+impl AxisScale {
+    /// Get the range of the axis and the pixel range it maps to, or `None` if the number of
+    /// pixels per data unit isn't a positive finite number
+    fn resolve(&self, pixels: Range<i32>) -> Option<(Range<f64>, Range<i32>)> {
+        let size = f64::from(pixels.end - pixels.start).abs();
+        match self {
+            AxisScale::Fit(range) => Some((range.clone(), pixels)),
+            AxisScale::StartAt(_, scale)
+            | AxisScale::EndAt(_, scale)
+            | AxisScale::Sized(_, scale)
+                if !(scale.is_finite() && *scale > 0.0) =>
+            {
+                None
+            }
+            AxisScale::StartAt(start, scale) => Some((*start..*start + size / scale, pixels)),
+            AxisScale::EndAt(end, scale) => Some((*end - size / scale..*end, pixels)),
+            AxisScale::Sized(range, scale) => {
+                let len = ((range.end - range.start) * scale).round() as i32;
+                let len = if pixels.end < pixels.start { -len } else { len };
+                Some((range.clone(), pixels.start..pixels.start + len))
+            }
+        }
+    }
+}
+
 /// The helper object to create a chart context, which is used for the high-level figure drawing
 pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     label_area_size: [u32; 4], // [upper, lower, left, right]
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
     margin: u32,
+    plot_pixels: Option<(Range<i32>, Range<i32>)>,
+    plot_aspect_ratio: Option<f64>,
+    title_background: Option<RGBAColor>,
+    label_area_background: Option<RGBAColor>,
 }
 
 impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
@@ -31,6 +84,10 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             root_area: root,
             title: None,
             margin: 0,
+            plot_pixels: None,
+            plot_aspect_ratio: None,
+            title_background: None,
+            label_area_background: None,
         }
     }
 
@@ -91,6 +148,52 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /// Fill the background of the caption area, which is the strip across the top of the chart
+    /// occupied by the caption, e.g. for a boxed header
+    /// - `color`: The background color of the caption area
+    pub fn caption_background<C: Color>(&mut self, color: &C) -> &mut Self {
+        self.title_background = Some(color.to_rgba());
+        self
+    }
+
+    /// Fill the background of all the label areas, which makes the axis gutters distinct from
+    /// the plotting area
+    /// - `color`: The background color of the label areas
+    pub fn label_area_background<C: Color>(&mut self, color: &C) -> &mut Self {
+        self.label_area_background = Some(color.to_rgba());
+        self
+    }
+
+    /// Override the pixel range that the data is mapped to. By default, the data is mapped to
+    /// the plotting area computed from the margin, caption and label areas. This is useful
+    /// when the chart needs to align with a rectangle dictated by another renderer.
+    /// The label areas are still computed as usual.
+    /// - `x`: The X pixel range in the backend coordinate, from left to right
+    /// - `y`: The Y pixel range in the backend coordinate, from top to bottom
+    pub fn override_plot_pixels(&mut self, x: Range<i32>, y: Range<i32>) -> &mut Self {
+        self.plot_pixels = Some((x, y));
+        self
+    }
+
+    /// Keep the plotting area in the given aspect ratio, the plotting area is shrunk and
+    /// centered in the space left by the label areas, and the label areas follow it. Unlike
+    /// the equal scaling of the axes, this is about the shape of the plotting area in pixels.
+    /// - `w`: The relative width of the plotting area, e.g. 16.0 for 16:9
+    /// - `h`: The relative height of the plotting area, e.g. 9.0 for 16:9
+    pub fn plot_aspect_ratio(&mut self, w: f64, h: f64) -> &mut Self {
+        self.plot_aspect_ratio = if w > 0.0 && h > 0.0 {
+            Some(w / h)
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Keep the plotting area in the golden ratio, i.e. about 1.618:1, see `plot_aspect_ratio`
+    pub fn golden_plot_aspect_ratio(&mut self) -> &mut Self {
+        self.plot_aspect_ratio((1.0 + 5f64.sqrt()) / 2.0, 1.0)
+    }
+
     /// Build the chart with a 2D Cartesian coordinate system. The function will returns a chart
     /// context, where data series can be rendered on.
     /// - `x_spec`: The specification of X axis
@@ -115,9 +218,31 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         }
 
         if let Some((ref title, ref style)) = self.title {
+            if let Some(ref color) = self.title_background {
+                // The caption takes the height of the text with 10 pixels of padding, see
+                // `DrawingArea::titled`
+                let text_h = style.font.box_size(title).map_or(0, |(_, h)| h as i32);
+                drawing_area.split_vertically(text_h + 10).0.fill(color)?;
+            }
             drawing_area = drawing_area.titled(title, style.clone())?;
         }
 
+        if let Some(ratio) = self.plot_aspect_ratio {
+            let (w, h) = drawing_area.dim_in_pixel();
+            let [top, bottom, left, right] = self.label_area_size;
+            let pw = w as i32 - left as i32 - right as i32;
+            let ph = h as i32 - top as i32 - bottom as i32;
+            if pw > 0 && ph > 0 {
+                let (aw, ah) = if f64::from(pw) / f64::from(ph) > ratio {
+                    ((f64::from(ph) * ratio).round() as i32, ph)
+                } else {
+                    (pw, (f64::from(pw) / ratio).round() as i32)
+                };
+                let (dx, dy) = ((pw - aw) / 2, (ph - ah) / 2);
+                drawing_area = drawing_area.margin(dy, ph - ah - dy, dx, pw - aw - dx);
+            }
+        }
+
         let (w, h) = drawing_area.dim_in_pixel();
 
         let mut actual_drawing_area_pos = [0, h as i32, 0, w as i32];
@@ -146,9 +271,18 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             }
         }
 
+        if let Some(ref color) = self.label_area_background {
+            for area in label_areas.iter().flatten() {
+                area.fill(color)?;
+            }
+        }
+
         std::mem::swap(&mut drawing_area, splitted[4].as_mut().unwrap());
 
-        let mut pixel_range = drawing_area.get_pixel_range();
+        let mut pixel_range = self
+            .plot_pixels
+            .clone()
+            .unwrap_or_else(|| drawing_area.get_pixel_range());
         pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
 
         let mut x_label_area = [None, None];
@@ -168,6 +302,231 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 pixel_range,
             )),
             series_anno: vec![],
+            drawn_elements: 0,
+            empty_state: None,
+            hidden_series: HashSet::new(),
+            data_bounds: None,
+            extend_data_bounds: None,
         })
     }
+
+    /// Build the chart with a percentage Y axis from 0% to 100%, which has the key points on the
+    /// round percentages, e.g. 0%, 25%, 50%, 75% and 100% with 5 Y labels. Use it with
+    /// `MeshStyle::y_percent_labels` to draw the labels with the "%" sign.
+    /// - `x_spec`: The specification of X axis
+    /// - `fraction`: If the data are the fractions in `0.0..1.0` rather than the percentages in
+    ///   `0.0..100.0`
+    /// - Returns: A chart context
+    #[allow(clippy::type_complexity)]
+    pub fn build_percent_y<X: AsRangedCoord>(
+        &mut self,
+        x_spec: X,
+        fraction: bool,
+    ) -> Result<
+        ChartContext<'a, DB, RangedCoord<X::CoordDescType, WithKeyPointPolicy<RangedCoordf64>>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let y_range = if fraction { 0.0..1.0 } else { 0.0..100.0 };
+        self.build_ranged(x_spec, y_range.key_point_policy(KeyPointPolicy::Percent))
+    }
+
+    /// Build the chart with a 2D Cartesian coordinate system, where the axes may have a fixed
+    /// number of pixels per data unit, so that the visual density is kept constant. For example,
+    /// a scrolling time series with `AxisScale::EndAt(now, 10.0)` shows as many seconds as the
+    /// chart width allows, 10 pixels for each second.
+    /// - `x_scale`: The scale of X axis
+    /// - `y_scale`: The scale of Y axis
+    /// - Returns: A chart context, or `DrawingAreaErrorKind::LayoutError` if the number of pixels
+    ///   per data unit of an axis isn't a positive finite number
+    #[allow(clippy::type_complexity)]
+    pub fn build_scaled(
+        &mut self,
+        x_scale: AxisScale,
+        y_scale: AxisScale,
+    ) -> Result<
+        ChartContext<'a, DB, RangedCoord<RangedCoordf64, RangedCoordf64>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    > {
+        let mut chart = self.build_ranged(0.0..1.0, 0.0..1.0)?;
+
+        let coord = chart.drawing_area.as_coord_spec();
+        let (x_range, x_pixels) = x_scale
+            .resolve(coord.get_x_axis_pixel_range())
+            .ok_or(DrawingAreaErrorKind::LayoutError)?;
+        let (y_range, y_pixels) = y_scale
+            .resolve(coord.get_y_axis_pixel_range())
+            .ok_or(DrawingAreaErrorKind::LayoutError)?;
+
+        chart.drawing_area = chart
+            .drawing_area
+            .strip_coord_spec()
+            .apply_coord_spec(RangedCoord::new(x_range, y_range, (x_pixels, y_pixels)));
+
+        Ok(chart)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_build_percent_y() {
+    use crate::coord::MeshLine;
+    let drawing_area = crate::create_mocked_drawing_area(300, 200, |_| {});
+    let chart = ChartBuilder::on(&drawing_area)
+        .build_percent_y(0..10, true)
+        .expect("Build chart error");
+    let mut values = vec![];
+    chart
+        .plotting_area()
+        .as_coord_spec()
+        .draw_mesh(5, 1, |line| {
+            if let MeshLine::YMesh(_, _, v) = line {
+                values.push(*v);
+            }
+            Ok::<(), ()>(())
+        })
+        .ok();
+    assert_eq!(values, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+    let mut values = vec![];
+    chart
+        .plotting_area()
+        .as_coord_spec()
+        .draw_mesh(10, 1, |line| {
+            if let MeshLine::YMesh(_, _, v) = line {
+                values.push(*v);
+            }
+            Ok::<(), ()>(())
+        })
+        .ok();
+    assert_eq!(values, vec![0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_plot_aspect_ratio() {
+    let drawing_area = crate::create_mocked_drawing_area(300, 200, |_| {});
+    let chart = ChartBuilder::on(&drawing_area)
+        .plot_aspect_ratio(1.0, 1.0)
+        .build_ranged(0..1, 0..1)
+        .expect("Build chart error");
+    assert_eq!(chart.plotting_area().get_pixel_range(), (50..250, 0..200));
+}
+
+#[cfg(test)]
+#[test]
+fn test_plot_pixel_alignment() {
+    let drawing_area = crate::create_mocked_drawing_area(300, 200, |_| {});
+    let chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_ranged(0.0..3.0, 0.0..3.0)
+        .expect("Build chart error");
+    let (x_pixels, y_pixels) = chart.plotting_area().get_pixel_range();
+    assert_eq!((x_pixels.clone(), y_pixels.clone()), (40..300, 0..170));
+
+    // The ends of the ranges are on the border of the plotting area, where the axes are drawn
+    assert_eq!(chart.backend_coord(&(0.0, 0.0)), (40, 170));
+    assert_eq!(chart.backend_coord(&(3.0, 3.0)), (300, 0));
+    assert_eq!(chart.backend_coord(&(1.0, 1.0)), (126, 114));
+}
+
+#[cfg(test)]
+#[test]
+fn test_label_area_background() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_rect(|c, filled, u, d| {
+            assert_eq!(c, RED.to_rgba());
+            assert!(filled);
+            assert_eq!([u, d], [(0, 80), (100, 100)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+        });
+    });
+    ChartBuilder::on(&drawing_area)
+        .x_label_area_size(20)
+        .label_area_background(&RED)
+        .build_ranged(0..1, 0..1)
+        .expect("Build chart error");
+}
+
+#[cfg(test)]
+#[test]
+fn test_caption_background() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let style = TextStyle::from(("sans-serif", 20).into_font());
+    let (_, text_h) = style.font.box_size("Title").unwrap();
+    let chart = ChartBuilder::on(&drawing_area)
+        .caption("Title", style)
+        .caption_background(&BLUE)
+        .build_ranged(0..1, 0..1)
+        .expect("Build chart error");
+
+    // The background covers the caption area only, and the caption is drawn on top of it
+    let caption_h = text_h as i32 + 10;
+    let all = calls.all();
+    assert_eq!(all.len(), 2);
+    assert_eq!(
+        all[0],
+        MockedCall::Rect(BLUE.to_rgba(), true, (0, 0), (100, caption_h))
+    );
+    match all[1] {
+        MockedCall::Text(_, ref text, (_, y)) => {
+            assert_eq!(text, "Title");
+            assert!(y >= 0 && y < caption_h);
+        }
+        ref call => panic!("Unexpected call {:?}", call),
+    }
+    assert_eq!(chart.backend_coord(&(0, 1)), (0, caption_h));
+}
+
+#[cfg(test)]
+#[test]
+fn test_build_scaled() {
+    let drawing_area = crate::create_mocked_drawing_area(300, 200, |_| {});
+    let chart = ChartBuilder::on(&drawing_area)
+        .build_scaled(AxisScale::StartAt(5.0, 10.0), AxisScale::EndAt(100.0, 2.0))
+        .expect("Build chart error");
+    assert_eq!(chart.plotting_area().get_x_range(), 5.0..35.0);
+    assert_eq!(chart.plotting_area().get_y_range(), 0.0..100.0);
+
+    let chart = ChartBuilder::on(&drawing_area)
+        .build_scaled(AxisScale::Sized(0.0..10.0, 5.0), AxisScale::Fit(0.0..1.0))
+        .expect("Build chart error");
+    assert_eq!(chart.plotting_area().get_x_axis_pixel_range(), 0..50);
+}
+
+#[cfg(test)]
+#[test]
+fn test_build_scaled_bad_scale() {
+    let drawing_area = crate::create_mocked_drawing_area(300, 200, |_| {});
+    let fit = || AxisScale::Fit(0.0..1.0);
+    for scale in [0.0, -2.0, std::f64::NAN, std::f64::INFINITY]
+        .iter()
+        .cloned()
+    {
+        for bad in vec![
+            AxisScale::StartAt(0.0, scale),
+            AxisScale::EndAt(0.0, scale),
+            AxisScale::Sized(0.0..1.0, scale),
+        ] {
+            assert!(
+                match ChartBuilder::on(&drawing_area).build_scaled(bad.clone(), fit()) {
+                    Err(DrawingAreaErrorKind::LayoutError) => true,
+                    _ => false,
+                }
+            );
+            assert!(
+                match ChartBuilder::on(&drawing_area).build_scaled(fit(), bad) {
+                    Err(DrawingAreaErrorKind::LayoutError) => true,
+                    _ => false,
+                }
+            );
+        }
+    }
 }
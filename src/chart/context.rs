@@ -1,24 +1,94 @@
 use std::borrow::Borrow;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::ops::Range;
 
+use super::builder::ChartBuilder;
 use super::dual_coord::DualCoordChartContext;
-use super::mesh::MeshStyle;
+use super::mesh::{AxisDescAlignment, LabelOverflow, MeshStyle};
+use super::render_plan::RenderPlan;
 use super::series::SeriesLabelStyle;
 
 use crate::coord::{
-    AsRangedCoord, CoordTranslate, MeshLine, Ranged, RangedCoord, ReverseCoordTranslate, Shift,
+    AsRangedCoord, CoordTranslate, DescreteRanged, MeshLine, Ranged, RangedCoord,
+    ReverseCoordTranslate, Shift,
 };
-use crate::drawing::backend::{BackendCoord, DrawingBackend};
-use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{Drawable, DynElement, IntoDynElement, Path, PointCollection};
-use crate::style::{FontTransform, ShapeStyle, TextStyle};
+use crate::drawing::backend::{BackendCoord, BackendMetadata, DrawingBackend};
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind, PolygonClipBackend, ReportProgress};
+use crate::element::{
+    clip_segment, Drawable, DynElement, IntoDynElement, LegendSample, LegendShape, Path,
+    PointCollection, Rectangle, Shadow,
+};
+use crate::series::MovingAverage;
+use crate::style::{FontTransform, RGBAColor, ShapeStyle, TextStyle};
+
+/// The axes crossing inside the plotting area, see `MeshStyle::axes_cross_at`
+pub(super) struct CrossingAxes {
+    /// The backend coordinate where the axes cross
+    pub(super) origin: BackendCoord,
+    /// If the positive ends of the axes are terminated with arrowheads
+    pub(super) arrows: bool,
+    /// The gap between the ticks and the labels in pixels
+    pub(super) label_gap: i32,
+    /// If the label of the origin is drawn once, offset diagonally from the origin
+    pub(super) origin_label: bool,
+}
+
+/// The rectangle which a series is clipped to, see `ChartContext::draw_series_clipped`
+#[derive(Clone, Debug)]
+pub enum SeriesClip<X, Y> {
+    /// The rectangle in the data coordinates, given by two opposite corners
+    Data((X, Y), (X, Y)),
+    /// The rectangle in pixels relative to the upper left corner of the plotting area, given by
+    /// two opposite corners
+    Pixel(BackendCoord, BackendCoord),
+}
+
+/// The X positions of a grid shared by many series, which are mapped to the pixels once, see
+/// `ChartContext::map_shared_x`
+pub struct SharedX<XT> {
+    values: Vec<XT>,
+    pixels: Vec<i32>,
+}
+
+impl<XT> SharedX<XT> {
+    /// Get the number of the X positions
+    pub fn len(&self) -> usize {
+        self.pixels.len()
+    }
+
+    /// Check if there's no X position
+    pub fn is_empty(&self) -> bool {
+        self.pixels.is_empty()
+    }
+}
+
+/// The ticks which replace the key points of the X and Y axes, each tick is the pixel of the
+/// grid line it's aligned to and the value of its label
+pub(super) type AlignedTicks<'t, X, Y> = (
+    Option<&'t [(i32, <X as Ranged>::ValueType)]>,
+    Option<&'t [(i32, <Y as Ranged>::ValueType)]>,
+);
+
+/// The text of a tick label and the color overriding the color of the label style
+pub(super) type TickLabel = (String, Option<RGBAColor>);
+
+/// The position of the label of a marker line, see `ChartContext::draw_x_marker`
+#[derive(Clone, Copy)]
+pub enum MarkerLabelPosition {
+    /// The label is placed at the top of the line
+    Top,
+    /// The label is placed at the bottom of the line
+    Bottom,
+}
 
 /// The annotations (such as the label of the series, the legend element, etc)
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
+    value: Option<String>,
     draw_func: Option<Box<dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord> + 'a>>,
+    pub(super) clipped: usize,
     phantom_data: PhantomData<DB>,
 }
 
@@ -27,6 +97,10 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self.label.as_ref().map(|x| x.as_str()).unwrap_or("")
     }
 
+    pub(crate) fn get_value(&self) -> &str {
+        self.value.as_ref().map(|x| x.as_str()).unwrap_or("")
+    }
+
     pub(crate) fn get_draw_func(
         &self,
     ) -> Option<&dyn Fn(BackendCoord) -> DynElement<'a, DB, BackendCoord>> {
@@ -36,11 +110,20 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
     fn new() -> Self {
         Self {
             label: None,
+            value: None,
             draw_func: None,
+            clipped: 0,
             phantom_data: PhantomData,
         }
     }
 
+    /// Get the number of the elements of the series which are totally outside the plotting
+    /// area, e.g. the points hidden by the axis limits. This is only counted by
+    /// `ChartContext::draw_series` and `DualCoordChartContext::draw_secondary_series`.
+    pub fn clipped_count(&self) -> usize {
+        self.clipped
+    }
+
     /// Set the series label
     /// `label`: The string would be use as label for current series
     pub fn label<L: Into<String>>(&mut self, label: L) -> &mut Self {
@@ -48,6 +131,15 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         self
     }
 
+    /// Set the value shown next to the series label, e.g. a statistic of the series such as
+    /// `avg 42%`. It's shown only if the value column of the legend is enabled, see
+    /// `SeriesLabelStyle::value_column`.
+    /// - `value`: The text of the value
+    pub fn value<V: Into<String>>(&mut self, value: V) -> &mut Self {
+        self.value = Some(value.into());
+        self
+    }
+
     /// Set the legend element creator function
     /// - `func`: The function use to create the element
     /// *Note*: The creation function uses a shifted pixel-based coordinate system. And place the
@@ -69,6 +161,12 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(super) y_label_area: [Option<DrawingArea<DB, Shift>>; 2],
     pub(super) drawing_area: DrawingArea<DB, CT>,
     pub(super) series_anno: Vec<SeriesAnno<'a, DB>>,
+    pub(super) drawn_elements: usize,
+    pub(super) empty_state: Option<(String, TextStyle<'a>)>,
+    pub(super) hidden_series: HashSet<String>,
+    pub(super) data_bounds: Option<(CT::From, CT::From)>,
+    #[allow(clippy::type_complexity)]
+    pub(super) extend_data_bounds: Option<fn(&mut Option<(CT::From, CT::From)>, &CT::From)>,
 }
 
 impl<
@@ -87,22 +185,49 @@ impl<
             axis_style: None,
             x_label_offset: 0,
             y_label_offset: 0,
+            x_label_padding: None,
+            y_label_padding: None,
+            tick_lengths: (0, 5),
+            label_overflow: LabelOverflow::Drop,
             draw_x_mesh: true,
             draw_y_mesh: true,
-            draw_x_axis: true,
-            draw_y_axis: true,
+            draw_x_axis: [true, true],
+            draw_y_axis: [true, true],
             n_x_labels: 10,
             n_y_labels: 10,
             line_style_1: None,
             line_style_2: None,
+            x_line_style_1: None,
+            x_line_style_2: None,
+            y_line_style_1: None,
+            y_line_style_2: None,
+            grid_shadow: None,
             label_style: None,
-            format_x: &|x| format!("{:?}", x),
-            format_y: &|y| format!("{:?}", y),
+            format_x: Box::new(|_: usize, x: &X::ValueType| format!("{:?}", x)),
+            format_y: Box::new(|_: usize, y: &Y::ValueType| format!("{:?}", y)),
             target: Some(self),
             _pahtom_data: PhantomData,
             x_desc: None,
             y_desc: None,
             axis_desc_style: None,
+            axis_desc_alignment: AxisDescAlignment::Center,
+            axis_desc_inside: false,
+            x_value_lines: vec![],
+            y_value_lines: vec![],
+            mesh_mask: None,
+            axis_origin: None,
+            axis_arrows: false,
+            crossing_label_gap: 2,
+            origin_label: false,
+            minor_mesh_between_labels: false,
+            x_aligned_ticks: None,
+            y_aligned_ticks: None,
+            x_exponent_offset: None,
+            y_exponent_offset: None,
+            x_decade_labels: None,
+            y_decade_labels: None,
+            x_label_color: None,
+            y_label_color: None,
         }
     }
 }
@@ -113,10 +238,69 @@ impl<'a, DB: DrawingBackend + 'a, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         SeriesLabelStyle::new(self)
     }
 
+    /// Start a render plan, which draws the registered layers of the chart by their orders
+    /// rather than the order of the calls, e.g. the mesh on top of the series
+    pub fn render_plan<'b>(&'b mut self) -> RenderPlan<'a, 'b, DB, CT> {
+        RenderPlan::new(self)
+    }
+
     /// Get a reference of underlying plotting area
     pub fn plotting_area(&self) -> &DrawingArea<DB, CT> {
         &self.drawing_area
     }
+
+    /// Find the data point that is nearest to the given backend coordinate. This is typically
+    /// used with an interactive chart, for example, highlighting the clicked data point.
+    /// - `data`: The data points of the series
+    /// - `pos`: The backend coordinate
+    /// - `radius`: The max distance in pixel between the backend coordinate and the data point
+    /// - **returns**: The index and the value of the nearest data point in the radius
+    pub fn find_nearest_point<P: Borrow<CT::From>, I: IntoIterator<Item = P>>(
+        &self,
+        data: I,
+        pos: BackendCoord,
+        radius: u32,
+    ) -> Option<(usize, P)> {
+        self.drawing_area.find_nearest_point(data, pos, radius)
+    }
+
+    /// Get the number of elements that have been drawn by all the series of the chart
+    pub fn drawn_element_count(&self) -> usize {
+        self.drawn_elements
+    }
+
+    /// Configure the message that is drawn by `draw_empty_state` when the chart doesn't
+    /// have any data
+    /// - `text`: The message, e.g. "No data"
+    /// - `style`: The text style of the message
+    pub fn configure_empty_state<S: Into<String>, T: Into<TextStyle<'a>>>(
+        &mut self,
+        text: S,
+        style: T,
+    ) -> &mut Self {
+        self.empty_state = Some((text.into(), style.into()));
+        self
+    }
+
+    /// Hide or show the series drawn by `draw_keyed_series` with the key, e.g. when its legend
+    /// entry is clicked in an interactive chart
+    /// - `key`: The key of the series
+    /// - `visible`: If the series should be drawn
+    pub fn set_series_visible<K: Into<String>>(&mut self, key: K, visible: bool) -> &mut Self {
+        let key = key.into();
+        if visible {
+            self.hidden_series.remove(&key);
+        } else {
+            self.hidden_series.insert(key);
+        }
+        self
+    }
+
+    /// Check if the series with the key is drawn, see `set_series_visible`
+    /// - `key`: The key of the series
+    pub fn is_series_visible(&self, key: &str) -> bool {
+        !self.hidden_series.contains(key)
+    }
 }
 
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
@@ -138,26 +322,263 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         self.drawing_area.get_y_range()
     }
 
+    /// Start tracking the bounding box of the data drawn by the series drawing functions from
+    /// now on, which is useful for fitting the view to the data, see `data_bounds`
+    pub fn track_data_bounds(&mut self) -> &mut Self
+    where
+        X::ValueType: PartialOrd + Clone,
+        Y::ValueType: PartialOrd + Clone,
+    {
+        self.extend_data_bounds = Some(extend_data_bounds::<X::ValueType, Y::ValueType>);
+        self
+    }
+
+    /// Get the bounding box of the data drawn since `track_data_bounds` is called
+    /// - **returns**: The X and Y ranges of the data, `None` if nothing has been drawn
+    #[allow(clippy::type_complexity)]
+    pub fn data_bounds(&self) -> Option<(Range<X::ValueType>, Range<Y::ValueType>)>
+    where
+        X::ValueType: Clone,
+        Y::ValueType: Clone,
+    {
+        self.data_bounds
+            .clone()
+            .map(|((x0, y0), (x1, y1))| (x0..x1, y0..y1))
+    }
+
+    /// Replace the range of the X axis in place, which is useful for panning and zooming an
+    /// interactive chart without building the chart again. The label areas are kept, so the
+    /// mesh and the series can be redrawn against the new range.
+    /// - `range`: The new range of the X axis
+    pub fn set_x_range(&mut self, range: Range<X::ValueType>)
+    where
+        X: From<Range<X::ValueType>>,
+    {
+        self.drawing_area.as_coord_spec_mut().set_x_spec(range);
+    }
+
+    /// Replace the range of the Y axis in place, see `set_x_range` for details
+    /// - `range`: The new range of the Y axis
+    pub fn set_y_range(&mut self, range: Range<Y::ValueType>)
+    where
+        Y: From<Range<Y::ValueType>>,
+    {
+        self.drawing_area.as_coord_spec_mut().set_y_spec(range);
+    }
+
+    /// Pin a data coordinate to a backend pixel, e.g. to put the origin of the data on a
+    /// known pixel of a reference image the chart is drawn over. The plotting area and the
+    /// scale of the axes are kept, only the mapping is shifted.
+    /// - `coord`: The data coordinate to pin
+    /// - `pixel`: The backend coordinate the data coordinate is mapped to
+    pub fn pin_to_pixel(&mut self, coord: &(X::ValueType, Y::ValueType), pixel: BackendCoord) {
+        self.drawing_area
+            .as_coord_spec_mut()
+            .pin_to_pixel(coord, pixel);
+    }
+
     /// Maps the coordinate to the backend coordinate. This is typically used
     /// with an interactive chart.
     pub fn backend_coord(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
         self.drawing_area.map_coordinate(coord)
     }
 
+    /// Maps the coordinate to the backend coordinate without clamping, see
+    /// `DrawingArea::map_coordinate_raw`
+    pub fn backend_coord_raw(&self, coord: &(X::ValueType, Y::ValueType)) -> BackendCoord {
+        self.drawing_area.map_coordinate_raw(coord)
+    }
+
+    /// Extend the tracked bounding box of the data by the points of an element, every function
+    /// drawing a series calls this, see `track_data_bounds`
+    fn update_data_bounds<'e, E>(&mut self, element: &'e E)
+    where
+        &'e E: PointCollection<'e, (X::ValueType, Y::ValueType)>,
+    {
+        if let Some(extend) = self.extend_data_bounds {
+            for p in element.point_iter() {
+                extend(&mut self.data_bounds, p.borrow());
+            }
+        }
+    }
+
+    /// Draw the elements of a series
+    /// - **returns**: The number of the elements which are totally outside the plotting area
     pub(super) fn draw_series_impl<E, R, S>(
         &mut self,
         series: S,
-    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    ) -> Result<usize, DrawingAreaErrorKind<DB::ErrorType>>
     where
         for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
         E: Drawable<DB>,
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
+        let pixel_range = self.drawing_area.get_pixel_range();
+        let mut clipped = 0;
         for element in series {
-            self.drawing_area.draw(element.borrow())?;
+            let element = element.borrow();
+            self.update_data_bounds(element);
+            let mut outside = OutsideSides::default();
+            self.drawing_area
+                .draw_inspected(element, |p| outside.add(p, &pixel_range))?;
+            if outside.is_totally_outside() {
+                clipped += 1;
+            }
+            self.drawn_elements += 1;
         }
-        Ok(())
+        Ok(clipped)
+    }
+
+    /// Create a zoomed inset of the chart on the given drawing area, which uses a tighter range
+    /// so that the same series can be drawn zoomed. The zoomed region is marked on this chart
+    /// with a rectangle, which is connected to the frame of the inset with two lines.
+    /// - `inset`: The drawing area of the inset, usually a small area on top of this chart
+    /// - `x_range`: The X range of the zoomed region
+    /// - `y_range`: The Y range of the zoomed region
+    /// - `style`: The style of the region marker, the inset frame and the connecting lines
+    /// - **returns**: The chart context of the inset
+    #[allow(clippy::type_complexity)]
+    pub fn zoom_inset<'b, S: Into<ShapeStyle>>(
+        &self,
+        inset: &'b DrawingArea<DB, Shift>,
+        x_range: Range<X::ValueType>,
+        y_range: Range<Y::ValueType>,
+        style: S,
+    ) -> Result<ChartContext<'b, DB, RangedCoord<X, Y>>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        Range<X::ValueType>: AsRangedCoord<CoordDescType = X>,
+        Range<Y::ValueType>: AsRangedCoord<CoordDescType = Y>,
+    {
+        let style = style.into();
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+
+        let (sx, ex) = (
+            self.drawing_area.map_x(&x_range.start),
+            self.drawing_area.map_x(&x_range.end),
+        );
+        let (sy, ey) = (
+            self.drawing_area.map_y(&y_range.start),
+            self.drawing_area.map_y(&y_range.end),
+        );
+        let (left, right, top, bottom) = (sx.min(ex), sx.max(ex), sy.min(ey), sy.max(ey));
+
+        let (ix0, iy0) = inset.get_base_pixel();
+        let (iw, ih) = inset.dim_in_pixel();
+        let (ix1, iy1) = (ix0 + iw as i32 - 1, iy0 + ih as i32 - 1);
+
+        // Connect the sides of the region and the inset which are facing each other
+        let lines = if ix0 >= right {
+            [((right, top), (ix0, iy0)), ((right, bottom), (ix0, iy1))]
+        } else if ix1 <= left {
+            [((left, top), (ix1, iy0)), ((left, bottom), (ix1, iy1))]
+        } else if iy1 <= top {
+            [((left, top), (ix0, iy1)), ((right, top), (ix1, iy1))]
+        } else {
+            [((left, bottom), (ix0, iy0)), ((right, bottom), (ix1, iy0))]
+        };
+
+        area.draw(&Rectangle::new(
+            [(left - x0, top - y0), (right - x0, bottom - y0)],
+            style.clone(),
+        ))?;
+        for (from, to) in lines.iter() {
+            area.draw(&Path::new(
+                vec![(from.0 - x0, from.1 - y0), (to.0 - x0, to.1 - y0)],
+                style.clone(),
+            ))?;
+        }
+        inset.draw(&Rectangle::new(
+            [(0, 0), (iw as i32 - 1, ih as i32 - 1)],
+            style,
+        ))?;
+
+        ChartBuilder::on(inset).build_ranged(x_range, y_range)
+    }
+
+    /// Draw a minimap of the whole data set on the given drawing area, which helps to navigate
+    /// a zoomed chart. The data is drawn as a line fitted to the full range, and the range which
+    /// is currently shown by this chart is marked with a rectangle.
+    /// - `inset`: The drawing area of the minimap, usually a small area on top of this chart
+    /// - `data`: The points of the whole data set
+    /// - `full_x`: The X range of the whole data set
+    /// - `full_y`: The Y range of the whole data set
+    /// - `style`: The style of the line and the frame of the minimap
+    /// - `view_style`: The style of the rectangle marking the current view
+    pub fn draw_minimap<I, S, VS>(
+        &self,
+        inset: &DrawingArea<DB, Shift>,
+        data: I,
+        full_x: Range<X::ValueType>,
+        full_y: Range<Y::ValueType>,
+        style: S,
+        view_style: VS,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = (X::ValueType, Y::ValueType)>,
+        S: Into<ShapeStyle>,
+        VS: Into<ShapeStyle>,
+        X: From<Range<X::ValueType>>,
+        Y: From<Range<Y::ValueType>>,
+    {
+        let style = style.into();
+        let (ix0, iy0) = inset.get_base_pixel();
+        let (iw, ih) = inset.dim_in_pixel();
+        let (ix1, iy1) = (ix0 + iw as i32 - 1, iy0 + ih as i32 - 1);
+
+        // Keep the data off the frame of the minimap
+        let coord: RangedCoord<X, Y> =
+            RangedCoord::new(full_x, full_y, (ix0 + 1..ix1 - 1, iy1 - 1..iy0 + 1));
+
+        // The current view may exceed the full range, the marker is limited to the minimap
+        let clamp = |(x, y): BackendCoord| {
+            (
+                x.max(ix0 + 1).min(ix1 - 1) - ix0,
+                y.max(iy0 + 1).min(iy1 - 1) - iy0,
+            )
+        };
+        let (x_range, y_range) = (self.x_range(), self.y_range());
+        let from = clamp(coord.translate(&(x_range.start, y_range.start)));
+        let to = clamp(coord.translate(&(x_range.end, y_range.end)));
+
+        inset.draw(&Rectangle::new(
+            [(0, 0), (iw as i32 - 1, ih as i32 - 1)],
+            style.clone(),
+        ))?;
+        inset
+            .apply_coord_spec(coord)
+            .draw(&Path::new(data.into_iter().collect::<Vec<_>>(), style))?;
+        inset.draw(&Rectangle::new(
+            [
+                (from.0.min(to.0), from.1.min(to.1)),
+                (from.0.max(to.0), from.1.max(to.1)),
+            ],
+            view_style,
+        ))
+    }
+
+    /// Draw the message configured by `configure_empty_state` at the center of the plotting area
+    /// if no element has been drawn by any series. This should be called after all the series
+    /// are drawn.
+    /// - **returns**: If the message has been drawn
+    pub fn draw_empty_state(&self) -> Result<bool, DrawingAreaErrorKind<DB::ErrorType>> {
+        let (text, style) = match self.empty_state {
+            Some(ref empty_state) if self.drawn_elements == 0 => empty_state,
+            _ => return Ok(false),
+        };
+
+        let plotting_area = self.drawing_area.strip_coord_spec();
+        let (pw, ph) = plotting_area.dim_in_pixel();
+        let (w, h) = style.font.box_size(text).unwrap_or((0, 0));
+
+        plotting_area.draw_text(
+            text,
+            style,
+            ((pw as i32 - w as i32) / 2, (ph as i32 - h as i32) / 2),
+        )?;
+
+        Ok(true)
     }
 
     pub(super) fn alloc_series_anno(&mut self) -> &mut SeriesAnno<'a, DB> {
@@ -177,62 +598,497 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        self.draw_series_impl(series)?;
+        let clipped = self.draw_series_impl(series)?;
+        let anno = self.alloc_series_anno();
+        anno.clipped = clipped;
+        Ok(anno)
+    }
+
+    /// Draw a data series and report the completion, e.g. to show a progress bar while a huge
+    /// series is rendered. The callback is invoked whenever another percent of the elements is
+    /// drawn, and with `1.0` at the end.
+    /// - `series`: The data series
+    /// - `total`: The number of the elements, if it's `None`, it's estimated from the size hint
+    ///   of the series, which is unknown for the nested loops like `flat_map`
+    /// - `progress`: The callback receiving the completed fraction
+    pub fn draw_series_with_progress<E, R, S, F>(
+        &mut self,
+        series: S,
+        total: Option<usize>,
+        progress: F,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+        F: FnMut(f64),
+    {
+        self.draw_series(ReportProgress::new(series.into_iter(), total, progress))
+    }
+
+    /// Draw a data series with a stable key, the series is skipped if the key is hidden by
+    /// `set_series_visible`. The legend entry of a hidden series is still kept, so that an
+    /// interactive legend can be clicked to show the series again.
+    /// - `key`: The key of the series, e.g. the label of the series
+    /// - `series`: The data series
+    pub fn draw_keyed_series<K, E, R, S>(
+        &mut self,
+        key: K,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        K: AsRef<str>,
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let clipped = if !self.hidden_series.contains(key.as_ref()) {
+            self.draw_series_impl(series)?
+        } else {
+            0
+        };
+        let anno = self.alloc_series_anno();
+        anno.clipped = clipped;
+        Ok(anno)
+    }
+
+    /// Draw a data series back to front, i.e. the last element of the series is drawn first,
+    /// so the first element ends up on the top of the overlapping ones
+    pub fn draw_series_rev<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let mut elements: Vec<_> = series.into_iter().collect();
+        elements.reverse();
+        self.draw_series(elements)
+    }
+
+    /// Draw a data series whose points out of the plotting area are pinned to the boundary of
+    /// the plotting area, and optionally mark each of the pinned points with a small arrow at
+    /// the boundary, which points to the direction of the actual position. This is useful when
+    /// losing the points silently is worse than showing them at the boundary.
+    /// - `series`: The data series
+    /// - `indicator`: The style of the out of range indicators, `None` for no indicator
+    pub fn draw_series_clamped<E, R, S>(
+        &mut self,
+        series: S,
+        indicator: Option<ShapeStyle>,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let (x_range, y_range) = self.drawing_area.get_pixel_range();
+        let (x0, y0) = (x_range.start, y_range.start);
+        let plotting_area = self.drawing_area.strip_coord_spec();
+
+        for element in series {
+            let element = element.borrow();
+            self.update_data_bounds(element);
+            self.drawing_area.draw_clamped(element)?;
+            self.drawn_elements += 1;
+
+            let style = match indicator {
+                Some(ref style) => style,
+                None => continue,
+            };
+            for point in element.point_iter() {
+                let point = self.drawing_area.map_coordinate(point.borrow());
+                if let Some(marker) = out_of_range_marker(point, (x_range.clone(), y_range.clone()))
+                {
+                    plotting_area.draw(&Path::new(
+                        marker
+                            .into_iter()
+                            .map(|(x, y)| (x - x0, y - y0))
+                            .collect::<Vec<_>>(),
+                        style.clone(),
+                    ))?;
+                }
+            }
+        }
+
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a data series which is clipped to a rectangle inside the plotting area, e.g. a
+    /// highlighted sub-region drawn on top of the full series
+    /// - `series`: The data series
+    /// - `clip`: The clip rectangle, either in the data coordinates or in pixels
+    pub fn draw_series_clipped<E, R, S>(
+        &mut self,
+        series: S,
+        clip: SeriesClip<X::ValueType, Y::ValueType>,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let (from, to) = match clip {
+            SeriesClip::Data(from, to) => (
+                self.drawing_area.map_coordinate(&from),
+                self.drawing_area.map_coordinate(&to),
+            ),
+            SeriesClip::Pixel(from, to) => ((from.0 + x0, from.1 + y0), (to.0 + x0, to.1 + y0)),
+        };
+        let clip = (
+            from.0.min(to.0)..from.0.max(to.0),
+            from.1.min(to.1)..from.1.max(to.1),
+        );
+
+        for element in series {
+            self.update_data_bounds(element.borrow());
+            self.drawing_area
+                .draw_clipped(element.borrow(), clip.clone())?;
+            self.drawn_elements += 1;
+        }
+
+        Ok(self.alloc_series_anno())
+    }
+
+    /// Draw a data series which is masked to the inside of a polygon, e.g. a heatmap clipped to
+    /// the boundary of a country on a map. Unless the backend clips the shapes by itself, like
+    /// the SVG backend does, this is much slower than `draw_series_clipped`, since the shapes
+    /// are clipped during the rasterization.
+    /// - `series`: The data series
+    /// - `polygon`: The vertices of the clip polygon in the data coordinates
+    pub fn draw_series_clipped_to_polygon<E, R, S>(
+        &mut self,
+        series: S,
+        polygon: &[(X::ValueType, Y::ValueType)],
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB> + for<'b> Drawable<PolygonClipBackend<'b, DB>>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let polygon: Vec<_> = polygon
+            .iter()
+            .map(|p| self.drawing_area.map_coordinate(p))
+            .collect();
+
+        for element in series {
+            self.update_data_bounds(element.borrow());
+            self.drawing_area
+                .draw_clipped_to_polygon(element.borrow(), &polygon)?;
+            self.drawn_elements += 1;
+        }
+
         Ok(self.alloc_series_anno())
     }
 
+    /// Map the X values of a grid shared by many series to the pixels once, so that the series
+    /// drawn with `draw_shared_x_series` only need to map their Y values. This is useful for the
+    /// multi-channel time series with thousands of points per channel.
+    /// - `xs`: The X values of the grid
+    /// - **returns**: The mapped X positions of the grid
+    pub fn map_shared_x<'x, I: IntoIterator<Item = &'x X::ValueType>>(
+        &self,
+        xs: I,
+    ) -> SharedX<X::ValueType>
+    where
+        X::ValueType: Clone + 'x,
+    {
+        let (x0, _) = self.drawing_area.get_base_pixel();
+        let values: Vec<_> = xs.into_iter().cloned().collect();
+        SharedX {
+            pixels: values
+                .iter()
+                .map(|x| self.drawing_area.map_x(x) - x0)
+                .collect(),
+            values,
+        }
+    }
+
+    /// Draw a line series against the X grid mapped by `map_shared_x`. The legend of the series
+    /// is set to a short line in the style of the series.
+    /// - `xs`: The mapped X positions of the grid
+    /// - `ys`: The Y values of the series, one for each X value of the grid
+    /// - `style`: The style of the line
+    pub fn draw_shared_x_series<S: Into<ShapeStyle>>(
+        &mut self,
+        xs: &SharedX<X::ValueType>,
+        ys: &[Y::ValueType],
+        style: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X::ValueType: Clone,
+        Y::ValueType: Clone,
+    {
+        let style = style.into();
+        if let Some(extend) = self.extend_data_bounds {
+            for (x, y) in xs.values.iter().zip(ys.iter()) {
+                extend(&mut self.data_bounds, &(x.clone(), y.clone()));
+            }
+        }
+        let (_, y0) = self.drawing_area.get_base_pixel();
+        let points: Vec<_> = xs
+            .pixels
+            .iter()
+            .zip(ys.iter())
+            .map(|(x, y)| (*x, self.drawing_area.map_y(y) - y0))
+            .collect();
+
+        self.drawing_area
+            .strip_coord_spec()
+            .draw(&Path::new(points, style.clone()))?;
+        self.drawn_elements += 1;
+
+        let anno = self.alloc_series_anno();
+        let shape = LegendShape::Line(style);
+        anno.draw_func = Some(Box::new(move |p| shape.sample(p)));
+        Ok(anno)
+    }
+
+    /// Draw a marker line across the plotting area at the given X value, with an optional
+    /// label next to the line, e.g. the "now" line of a time series. Nothing is drawn when the
+    /// value is out of the range of the X axis.
+    /// - `value`: The X value of the line
+    /// - `style`: The style of the line
+    /// - `label`: The label text and its style
+    /// - `position`: Where the label is placed along the line
+    pub fn draw_x_marker<S: Into<ShapeStyle>>(
+        &mut self,
+        value: &X::ValueType,
+        style: S,
+        label: Option<(&str, &TextStyle)>,
+        position: MarkerLabelPosition,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let x_range = self.drawing_area.get_x_axis_pixel_range();
+        let y_range = self.drawing_area.get_y_axis_pixel_range();
+
+        let x = self.drawing_area.map_x(value);
+        if x < x_range.start.min(x_range.end) || x_range.start.max(x_range.end) < x {
+            return Ok(());
+        }
+        let x = x - x0;
+        let (top, bottom) = (
+            y_range.start.min(y_range.end) - y0,
+            y_range.start.max(y_range.end) - y0,
+        );
+
+        area.draw(&Path::new(vec![(x, top), (x, bottom)], style))?;
+        self.drawn_elements += 1;
+
+        if let Some((text, style)) = label {
+            let (w, h) = style.font.box_size(text).unwrap_or((0, 0));
+            let (w, h) = (w as i32, h as i32);
+            let (pw, _) = area.dim_in_pixel();
+            // The label goes to the right of the line, unless it doesn't fit in the plotting area
+            let lx = if x + 3 + w <= pw as i32 {
+                x + 3
+            } else {
+                x - 3 - w
+            };
+            let ly = match position {
+                MarkerLabelPosition::Top => top + 2,
+                MarkerLabelPosition::Bottom => bottom - 2 - h,
+            };
+            area.draw_text(text, style, (lx, ly))?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw a single segment of a line, which is useful for a live chart that appends one point
+    /// at a time, since only the new segment needs to be drawn instead of the whole line. The
+    /// segment is clipped by the plotting area.
+    /// - `from`: The previous last point of the line
+    /// - `to`: The new point of the line
+    /// - `style`: The style of the line
+    pub fn append_line_segment<S: Into<ShapeStyle>>(
+        &mut self,
+        from: &(X::ValueType, Y::ValueType),
+        to: &(X::ValueType, Y::ValueType),
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let from = self.drawing_area.map_coordinate(from);
+        let to = self.drawing_area.map_coordinate(to);
+        let (x_range, y_range) = self.drawing_area.get_pixel_range();
+
+        if let Some((from, to)) = clip_segment(from, to, (x_range.clone(), y_range.clone())) {
+            let (x0, y0) = (x_range.start, y_range.start);
+            self.drawing_area.strip_coord_spec().draw(&Path::new(
+                vec![(from.0 - x0, from.1 - y0), (to.0 - x0, to.1 - y0)],
+                style,
+            ))?;
+            self.drawn_elements += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Draw a data series and set the legend of it to a sample matching the first element of
+    /// the series, e.g. a short line for the line series, so the legend doesn't need to repeat
+    /// the style of the series
+    pub fn draw_series_with_legend<E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        for<'b> &'b E: PointCollection<'b, (X::ValueType, Y::ValueType)>,
+        E: Drawable<DB> + LegendSample,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R>,
+    {
+        let mut shape = None;
+        for element in series {
+            if shape.is_none() {
+                shape = Some(element.borrow().legend_shape());
+            }
+            self.update_data_bounds(element.borrow());
+            self.drawing_area.draw(element.borrow())?;
+            self.drawn_elements += 1;
+        }
+
+        let anno = self.alloc_series_anno();
+        if let Some(shape) = shape {
+            anno.draw_func = Some(Box::new(move |p| shape.sample(p)));
+        }
+        Ok(anno)
+    }
+
+    /// Draw the moving average of a data series as a line, the legend of the line is set to
+    /// the label of the moving average, e.g. "MA(7)"
+    /// - `average`: The moving average
+    /// - `style`: The style of the line
+    pub fn draw_moving_average<S: Into<ShapeStyle>>(
+        &mut self,
+        average: &MovingAverage,
+        style: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        X: Ranged<ValueType = f64>,
+        Y: Ranged<ValueType = f64>,
+    {
+        let anno = self.draw_series_with_legend(average.series(style))?;
+        Ok(anno.label(average.label()))
+    }
+
     /// The actual function that draws the mesh lines.
     /// It also returns the label that suppose to be there.
+    #[allow(clippy::type_complexity)]
     fn draw_mesh_lines<FmtLabel>(
         &mut self,
         (r, c): (usize, usize),
         (x_mesh, y_mesh): (bool, bool),
-        mesh_line_style: &ShapeStyle,
+        (x_line_style, y_line_style): (&ShapeStyle, &ShapeStyle),
+        mesh_mask: Option<&dyn Fn(&RangedCoord<X, Y>, BackendCoord) -> bool>,
+        grid_shadow: Option<&Shadow<()>>,
+        (x_ticks, y_ticks): AlignedTicks<'_, X, Y>,
         mut fmt_label: FmtLabel,
-    ) -> Result<(Vec<(i32, String)>, Vec<(i32, String)>), DrawingAreaErrorKind<DB::ErrorType>>
+    ) -> Result<(Vec<(i32, TickLabel)>, Vec<(i32, TickLabel)>), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(usize, &MeshLine<X, Y>) -> Option<TickLabel>,
     {
         let mut x_labels = vec![];
         let mut y_labels = vec![];
+        let (mut x_idx, mut y_idx) = (0, 0);
+        let coord = self.drawing_area.as_coord_spec();
         self.drawing_area.draw_mesh(
             |b, l| {
-                let draw;
+                let (draw, mesh_line_style);
                 match l {
+                    // The given ticks replace the key points of the axis
+                    MeshLine::XMesh(..) if x_ticks.is_some() => return Ok(()),
                     MeshLine::XMesh((x, _), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
+                        let mut label = fmt_label(x_idx, &l);
+                        x_idx += 1;
+                        if is_repeated_label(&x_labels, &label) {
+                            label = label.map(|(_, color)| (String::new(), color));
+                        }
+                        if let Some(label_text) = label {
                             x_labels.push((x, label_text));
                         }
                         draw = x_mesh;
+                        mesh_line_style = x_line_style;
                     }
+                    MeshLine::YMesh(..) if y_ticks.is_some() => return Ok(()),
                     MeshLine::YMesh((_, y), _, _) => {
-                        if let Some(label_text) = fmt_label(&l) {
+                        let mut label = fmt_label(y_idx, &l);
+                        y_idx += 1;
+                        if is_repeated_label(&y_labels, &label) {
+                            label = label.map(|(_, color)| (String::new(), color));
+                        }
+                        if let Some(label_text) = label {
                             y_labels.push((y, label_text));
                         }
                         draw = y_mesh;
+                        mesh_line_style = y_line_style;
                     }
                 };
-                if draw {
-                    l.draw(b, mesh_line_style)
-                } else {
-                    Ok(())
+                if let (true, Some(shadow)) = (draw, grid_shadow) {
+                    shadow.draw_shadow(b, |shadow| match mesh_mask {
+                        Some(mask) => l.draw_masked(shadow, mesh_line_style, |p| mask(coord, p)),
+                        None => l.draw(shadow, mesh_line_style),
+                    })?;
+                    b.ensure_prepared()?;
+                }
+                match (draw, mesh_mask) {
+                    (true, Some(mask)) => l.draw_masked(b, mesh_line_style, |p| mask(coord, p)),
+                    (true, None) => l.draw(b, mesh_line_style),
+                    _ => Ok(()),
                 }
             },
             r,
             c,
         )?;
+
+        // Only the labels are drawn for the given ticks, since they share the grid lines with
+        // another axis
+        let (x_range, y_range) = self.drawing_area.get_pixel_range();
+        for (idx, (x, value)) in x_ticks.unwrap_or(&[]).iter().enumerate() {
+            let line = MeshLine::XMesh((*x, y_range.start), (*x, y_range.end), value);
+            let mut label = fmt_label(idx, &line);
+            if is_repeated_label(&x_labels, &label) {
+                label = label.map(|(_, color)| (String::new(), color));
+            }
+            if let Some(label_text) = label {
+                x_labels.push((*x, label_text));
+            }
+        }
+        for (idx, (y, value)) in y_ticks.unwrap_or(&[]).iter().enumerate() {
+            let line = MeshLine::YMesh((x_range.start, *y), (x_range.end, *y), value);
+            let mut label = fmt_label(idx, &line);
+            if is_repeated_label(&y_labels, &label) {
+                label = label.map(|(_, color)| (String::new(), color));
+            }
+            if let Some(label_text) = label {
+                y_labels.push((*y, label_text));
+            }
+        }
+
         Ok((x_labels, y_labels))
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn draw_axis_and_labels(
         &self,
         area: Option<&DrawingArea<DB, Shift>>,
         axis_style: Option<&ShapeStyle>,
-        labels: &[(i32, String)],
+        labels: &[(i32, TickLabel)],
         label_style: &TextStyle,
         label_offset: i32,
+        label_padding: Option<(i32, i32)>,
+        (tick_inner, tick_outer): (i32, i32),
+        label_overflow: LabelOverflow,
         orientation: (i16, i16),
-        axis_desc: Option<(&str, &TextStyle)>,
+        axis_desc: Option<(&str, &TextStyle, (AxisDescAlignment, bool))>,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let area = if let Some(target) = area {
             target
@@ -244,7 +1100,12 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
 
         /* TODO: make this configure adjustable */
         let knob_size = 5;
-        let label_dist = if orientation.1 > 0 { 0 } else { 10 };
+        // The gap between the axis and the labels, and the optional gap between the labels and
+        // the outer edge of the label area
+        let (label_dist, outer_dist) = match label_padding {
+            Some((inner, outer)) => (inner, Some(outer)),
+            None => (if orientation.1 > 0 { 0 } else { 10 }, None),
+        };
 
         let (tw, th) = area.dim_in_pixel();
 
@@ -262,6 +1123,21 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
             axis_range.end -= y0;
         }
 
+        let mut axis_breaks: Vec<_> = if orientation.0 == 0 {
+            self.drawing_area
+                .get_x_axis_breaks()
+                .into_iter()
+                .map(|p| p - x0)
+                .collect()
+        } else {
+            self.drawing_area
+                .get_y_axis_breaks()
+                .into_iter()
+                .map(|p| p - y0)
+                .collect()
+        };
+        axis_breaks.sort_unstable();
+
         if let Some(style) = axis_style {
             let mut x0 = if orientation.0 > 0 { 0 } else { tw as i32 };
             let mut y0 = if orientation.1 > 0 { 0 } else { th as i32 };
@@ -275,21 +1151,55 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 y0 = axis_range.start;
                 y1 = axis_range.end;
             }
-            area.draw(&Path::new(vec![(x0, y0), (x1, y1)], style.clone()))?;
+            if axis_breaks.is_empty() {
+                area.draw(&Path::new(vec![(x0, y0), (x1, y1)], style.clone()))?;
+            } else {
+                // Leave a gap on the axis line and draw the zig-zag marker where the axis is broken
+                let (from, to) = if orientation.0 == 0 {
+                    (x0.min(x1), x0.max(x1))
+                } else {
+                    (y0.min(y1), y0.max(y1))
+                };
+                let to_coord = |p: i32| if orientation.0 == 0 { (p, y0) } else { (x0, p) };
+
+                let mut points = vec![from];
+                for p in axis_breaks.iter() {
+                    points.push(p - knob_size);
+                    points.push(p + knob_size);
+                }
+                points.push(to);
+
+                for part in points.chunks(2) {
+                    area.draw(&Path::new(
+                        vec![to_coord(part[0]), to_coord(part[1])],
+                        style.clone(),
+                    ))?;
+                }
+
+                for p in axis_breaks.iter() {
+                    let marker = if orientation.0 == 0 {
+                        vec![(p - 5, y0), (p - 2, y0 - 4), (p + 2, y0 + 4), (p + 5, y0)]
+                    } else {
+                        vec![(x0, p - 5), (x0 - 4, p - 2), (x0 + 4, p + 2), (x0, p + 5)]
+                    };
+                    area.draw(&Path::new(marker, style.clone()))?;
+                }
+            }
         }
 
         let right_most = if orientation.0 > 0 && orientation.1 == 0 {
-            labels
+            let right_most = labels
                 .iter()
-                .map(|(_, t)| label_style.font.box_size(t).unwrap_or((0, 0)).0)
+                .map(|(_, (t, _))| label_style.font.box_size(t).unwrap_or((0, 0)).0)
                 .max()
                 .unwrap_or(0) as i32
-                + label_dist as i32
+                + label_dist;
+            outer_dist.map_or(right_most, |outer| right_most.min(tw as i32 - outer))
         } else {
             0
         };
 
-        for (p, t) in labels {
+        for (p, (t, color)) in labels {
             let rp = if orientation.0 == 0 { *p - x0 } else { *p - y0 };
 
             if rp < axis_range.start.min(axis_range.end)
@@ -298,6 +1208,22 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 continue;
             }
 
+            // The labels go away from the axis, so the depth of the label area limits them
+            let depth = if orientation.0 == 0 { th } else { tw } as i32;
+            let room = depth - label_dist - outer_dist.unwrap_or(0);
+            let (t, mut label_style) = fit_label(
+                t,
+                label_style,
+                room,
+                orientation.0 != 0,
+                label_overflow,
+                |t, s| s.font.box_size(t).unwrap_or((0, 0)),
+            );
+            if let Some(color) = color {
+                label_style.color = color.clone();
+            }
+            let label_style = &label_style;
+
             let (w, h) = label_style.font.box_size(&t).unwrap_or((0, 0));
 
             let (cx, cy) = match orientation {
@@ -307,6 +1233,12 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                 (dx, dy) if dx == 0 && dy < 0 => (*p - x0, th as i32 - label_dist - h as i32),
                 _ => panic!("Bug: Invlid orientation specification"),
             };
+            let (cx, cy) = match (outer_dist, orientation) {
+                (Some(outer), (dx, _)) if dx < 0 => (cx.max(outer), cy),
+                (Some(outer), (_, dy)) if dy > 0 => (cx, cy.min(th as i32 - outer - h as i32)),
+                (Some(outer), (_, dy)) if dy < 0 => (cx, cy.max(outer)),
+                _ => (cx, cy),
+            };
 
             let should_draw = if orientation.0 == 0 {
                 cx >= 0 && cx + label_offset + w as i32 / 2 <= tw as i32
@@ -321,100 +1253,370 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
                     (cx, cy - h as i32 / 2 + label_offset)
                 };
 
+                set_role(area, Some("plotters-label"))?;
                 area.draw_text(&t, label_style, (text_x, text_y))?;
+                set_role(area, Some("plotters-axis"))?;
 
-                if let Some(style) = axis_style {
+                if let (Some(style), true) = (axis_style, tick_outer > 0) {
                     let (kx0, ky0, kx1, ky1) = match orientation {
-                        (dx, dy) if dx > 0 && dy == 0 => (0, *p - y0, knob_size, *p - y0),
+                        (dx, dy) if dx > 0 && dy == 0 => (0, *p - y0, tick_outer, *p - y0),
                         (dx, dy) if dx < 0 && dy == 0 => {
-                            (tw as i32 - knob_size, *p - y0, tw as i32, *p - y0)
+                            (tw as i32 - tick_outer, *p - y0, tw as i32, *p - y0)
                         }
-                        (dx, dy) if dx == 0 && dy > 0 => (*p - x0, 0, *p - x0, knob_size),
+                        (dx, dy) if dx == 0 && dy > 0 => (*p - x0, 0, *p - x0, tick_outer),
                         (dx, dy) if dx == 0 && dy < 0 => {
-                            (*p - x0, th as i32 - knob_size, *p - x0, th as i32)
+                            (*p - x0, th as i32 - tick_outer, *p - x0, th as i32)
                         }
                         _ => panic!("Bug: Invlid orientation specification"),
                     };
                     let line = Path::new(vec![(kx0, ky0), (kx1, ky1)], style.clone());
                     area.draw(&line)?;
                 }
+
+                // The inner part of the tick goes into the plotting area from the axis
+                if let (Some(style), true) = (axis_style, tick_inner > 0) {
+                    let plotting_area = self.drawing_area.strip_coord_spec();
+                    let (pw, ph) = plotting_area.dim_in_pixel();
+                    let (pw, ph) = (pw as i32, ph as i32);
+                    let (kx0, ky0, kx1, ky1) = match orientation {
+                        (dx, dy) if dx > 0 && dy == 0 => (pw - tick_inner, *p - y0, pw, *p - y0),
+                        (dx, dy) if dx < 0 && dy == 0 => (0, *p - y0, tick_inner, *p - y0),
+                        (dx, dy) if dx == 0 && dy > 0 => (*p - x0, ph - tick_inner, *p - x0, ph),
+                        (dx, dy) if dx == 0 && dy < 0 => (*p - x0, 0, *p - x0, tick_inner),
+                        _ => panic!("Bug: Invlid orientation specification"),
+                    };
+                    plotting_area.draw(&Path::new(vec![(kx0, ky0), (kx1, ky1)], style.clone()))?;
+                }
             }
         }
 
-        if let Some((text, style)) = axis_desc {
-            let actual_style = if orientation.0 == 0 {
-                style.clone()
-            } else {
-                if orientation.0 == -1 {
-                    style.transform(FontTransform::Rotate270)
-                } else {
-                    style.transform(FontTransform::Rotate90)
-                }
+        if let Some((text, style, (alignment, inside))) = axis_desc {
+            set_role(area, Some("plotters-axis-desc"))?;
+            let actual_style = match (orientation.0, style.font.get_transform()) {
+                // The vertical text is already readable along the Y axis, so don't rotate it
+                (0, _) | (_, FontTransform::Vertical) => style.clone(),
+                (-1, _) => style.transform(FontTransform::Rotate270),
+                _ => style.transform(FontTransform::Rotate90),
             };
 
             let (w, h) = actual_style.font.box_size(text).unwrap_or((0, 0));
+            let (w, h) = (w as i32, h as i32);
+            let (tw, th) = (tw as i32, th as i32);
 
-            let (x0, y0) = match orientation {
-                (dx, dy) if dx > 0 && dy == 0 => (tw - w, (th - h) / 2),
-                (dx, dy) if dx < 0 && dy == 0 => (0, (th - h) / 2),
-                (dx, dy) if dx == 0 && dy > 0 => ((tw - w) / 2, th - h),
-                (dx, dy) if dx == 0 && dy < 0 => ((tw - w) / 2, 0),
-                _ => panic!("Bug: Invlid orientation specification"),
+            // The label area and the plotting area have the same span along the axis, so the
+            // position along the axis is the same for both of them
+            let along = match (orientation.0, alignment) {
+                (0, AxisDescAlignment::Start) => 0,
+                (0, AxisDescAlignment::Center) => (tw - w) / 2,
+                (0, AxisDescAlignment::End) => tw - w,
+                (_, AxisDescAlignment::Start) => th - h,
+                (_, AxisDescAlignment::Center) => (th - h) / 2,
+                (_, AxisDescAlignment::End) => 0,
             };
 
-            area.draw_text(&text, &actual_style, (x0 as i32, y0 as i32))?;
+            if inside {
+                let plotting_area = self.drawing_area.strip_coord_spec();
+                let (pw, ph) = plotting_area.dim_in_pixel();
+                let (pw, ph) = (pw as i32, ph as i32);
+                let (x0, y0) = match orientation {
+                    (dx, dy) if dx > 0 && dy == 0 => (pw - w - knob_size, along),
+                    (dx, dy) if dx < 0 && dy == 0 => (knob_size, along),
+                    (dx, dy) if dx == 0 && dy > 0 => (along, ph - h - knob_size),
+                    (dx, dy) if dx == 0 && dy < 0 => (along, knob_size),
+                    _ => panic!("Bug: Invlid orientation specification"),
+                };
+                plotting_area.draw_text(text, &actual_style, (x0, y0))?;
+            } else {
+                let (x0, y0) = match orientation {
+                    (dx, dy) if dx > 0 && dy == 0 => (tw - w, along),
+                    (dx, dy) if dx < 0 && dy == 0 => (0, along),
+                    (dx, dy) if dx == 0 && dy > 0 => (along, th - h),
+                    (dx, dy) if dx == 0 && dy < 0 => (along, 0),
+                    _ => panic!("Bug: Invlid orientation specification"),
+                };
+                area.draw_text(text, &actual_style, (x0, y0))?;
+            }
+            set_role(area, Some("plotters-axis"))?;
         }
 
         Ok(())
     }
 
-    #[allow(clippy::too_many_arguments)]
+    /// Draw the emphasized lines across the plotting area at the given values, the lines with
+    /// a value out of the axis range are skipped
+    pub(super) fn draw_value_lines(
+        &self,
+        x_lines: &[(X::ValueType, ShapeStyle)],
+        y_lines: &[(Y::ValueType, ShapeStyle)],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let x_range = self.drawing_area.get_x_axis_pixel_range();
+        let y_range = self.drawing_area.get_y_axis_pixel_range();
+
+        for (value, style) in x_lines {
+            let x = self.drawing_area.map_x(value);
+            if x < x_range.start.min(x_range.end) || x_range.start.max(x_range.end) < x {
+                continue;
+            }
+            area.draw(&Path::new(
+                vec![(x - x0, y_range.start - y0), (x - x0, y_range.end - y0)],
+                style.clone(),
+            ))?;
+        }
+
+        for (value, style) in y_lines {
+            let y = self.drawing_area.map_y(value);
+            if y < y_range.start.min(y_range.end) || y_range.start.max(y_range.end) < y {
+                continue;
+            }
+            area.draw(&Path::new(
+                vec![(x_range.start - x0, y - y0), (x_range.end - x0, y - y0)],
+                style.clone(),
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw the axes crossing at the given point inside the plotting area, with the ticks and
+    /// labels along the axes. The axes stay on the edge when the point is out of range, and the
+    /// labels move to the inner side of the axes when they don't fit between the axes and the
+    /// edge of the plotting area.
+    fn draw_crossing_axes(
+        &self,
+        crossing: &CrossingAxes,
+        (x_axis, y_axis): (bool, bool),
+        axis_style: &ShapeStyle,
+        label_style: &TextStyle,
+        x_labels: &[(i32, TickLabel)],
+        y_labels: &[(i32, TickLabel)],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        let (x0, y0) = self.drawing_area.get_base_pixel();
+        let (_, ph) = area.dim_in_pixel();
+        let x_range = self.drawing_area.get_x_axis_pixel_range();
+        let y_range = self.drawing_area.get_y_axis_pixel_range();
+        let origin = crossing.origin;
+        let gap = crossing.label_gap;
+
+        let knob_size = 5;
+        let ox = origin
+            .0
+            .max(x_range.start.min(x_range.end))
+            .min(x_range.start.max(x_range.end))
+            - x0;
+        let oy = origin
+            .1
+            .max(y_range.start.min(y_range.end))
+            .min(y_range.start.max(y_range.end))
+            - y0;
+
+        // The labels go below the X axis and to the left of the Y axis, unless the axis is too
+        // close to the edge of the plotting area
+        let max_label_size = |labels: &[(i32, TickLabel)]| {
+            labels.iter().fold((0, 0), |(mw, mh), (_, (text, _))| {
+                let (w, h) = label_style.font.box_size(text).unwrap_or((0, 0));
+                (mw.max(w as i32), mh.max(h as i32))
+            })
+        };
+        let (_, x_label_h) = max_label_size(x_labels);
+        let (y_label_w, _) = max_label_size(y_labels);
+        let x_labels_below = oy + knob_size + gap + x_label_h <= ph as i32;
+        let y_labels_left = ox - knob_size - gap - y_label_w >= 0;
+
+        let x_label_pos = |x: i32, (w, h): (u32, u32)| {
+            if x_labels_below {
+                (x - w as i32 / 2, oy + knob_size + gap)
+            } else {
+                (x - w as i32 / 2, oy - knob_size - gap - h as i32)
+            }
+        };
+        let y_label_pos = |y: i32, (w, h): (u32, u32)| {
+            if y_labels_left {
+                (ox - knob_size - gap - w as i32, y - h as i32 / 2)
+            } else {
+                (ox + knob_size + gap, y - h as i32 / 2)
+            }
+        };
+
+        if x_axis {
+            let (start, end) = (x_range.start - x0, x_range.end - x0);
+            area.draw(&Path::new(vec![(start, oy), (end, oy)], axis_style.clone()))?;
+            if crossing.arrows {
+                let dir = (end - start).signum();
+                area.draw(&Path::new(
+                    vec![
+                        (end - dir * knob_size * 2, oy - knob_size),
+                        (end, oy),
+                        (end - dir * knob_size * 2, oy + knob_size),
+                    ],
+                    axis_style.clone(),
+                ))?;
+            }
+            for (x, (text, color)) in x_labels {
+                let x = x - x0;
+                area.draw(&Path::new(
+                    vec![(x, oy), (x, oy + knob_size)],
+                    axis_style.clone(),
+                ))?;
+                if x == ox && y_axis {
+                    continue;
+                }
+                let size = label_style.font.box_size(text).unwrap_or((0, 0));
+                let style = tick_label_style(label_style, color);
+                set_role(&area, Some("plotters-label"))?;
+                area.draw_text(text, &style, x_label_pos(x, size))?;
+                set_role(&area, Some("plotters-axis"))?;
+            }
+        }
+
+        if y_axis {
+            let (start, end) = (y_range.start - y0, y_range.end - y0);
+            area.draw(&Path::new(vec![(ox, start), (ox, end)], axis_style.clone()))?;
+            if crossing.arrows {
+                let dir = (end - start).signum();
+                area.draw(&Path::new(
+                    vec![
+                        (ox - knob_size, end - dir * knob_size * 2),
+                        (ox, end),
+                        (ox + knob_size, end - dir * knob_size * 2),
+                    ],
+                    axis_style.clone(),
+                ))?;
+            }
+            for (y, (text, color)) in y_labels {
+                let y = y - y0;
+                area.draw(&Path::new(
+                    vec![(ox - knob_size, y), (ox, y)],
+                    axis_style.clone(),
+                ))?;
+                if y == oy && x_axis {
+                    continue;
+                }
+                let size = label_style.font.box_size(text).unwrap_or((0, 0));
+                let style = tick_label_style(label_style, color);
+                set_role(&area, Some("plotters-label"))?;
+                area.draw_text(text, &style, y_label_pos(y, size))?;
+                set_role(&area, Some("plotters-axis"))?;
+            }
+        }
+
+        // The label of the origin is shared by both axes, so it's drawn once in the corner
+        // between the labels of the two axes
+        if crossing.origin_label && x_axis && y_axis {
+            if let Some((_, (text, color))) = x_labels.iter().find(|(x, _)| x - x0 == ox) {
+                let (w, h) = label_style.font.box_size(text).unwrap_or((0, 0));
+                let (w, h) = (w as i32, h as i32);
+                let x = if y_labels_left {
+                    ox - gap - w
+                } else {
+                    ox + gap
+                };
+                let y = if x_labels_below {
+                    oy + gap
+                } else {
+                    oy - gap - h
+                };
+                set_role(&area, Some("plotters-label"))?;
+                area.draw_text(text, &tick_label_style(label_style, color), (x, y))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     pub(super) fn draw_mesh<FmtLabel>(
         &mut self,
         (r, c): (usize, usize),
-        mesh_line_style: &ShapeStyle,
+        mesh_line_style: (&ShapeStyle, &ShapeStyle),
         label_style: &TextStyle,
         fmt_label: FmtLabel,
         x_mesh: bool,
         y_mesh: bool,
         x_label_offset: i32,
         y_label_offset: i32,
-        x_axis: bool,
-        y_axis: bool,
+        mut x_axis: [bool; 2],
+        mut y_axis: [bool; 2],
         axis_style: &ShapeStyle,
         axis_desc_style: &TextStyle,
         x_desc: Option<String>,
         y_desc: Option<String>,
+        axis_desc_pos: (AxisDescAlignment, bool),
+        mesh_mask: Option<&dyn Fn(&RangedCoord<X, Y>, BackendCoord) -> bool>,
+        grid_shadow: Option<&Shadow<()>>,
+        aligned_ticks: AlignedTicks<'_, X, Y>,
+        (x_label_padding, y_label_padding): (Option<(i32, i32)>, Option<(i32, i32)>),
+        tick_lengths: (i32, i32),
+        label_overflow: LabelOverflow,
+        crossing_axes: Option<CrossingAxes>,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
-        FmtLabel: FnMut(&MeshLine<X, Y>) -> Option<String>,
+        FmtLabel: FnMut(usize, &MeshLine<X, Y>) -> Option<TickLabel>,
     {
-        let (x_labels, y_labels) =
-            self.draw_mesh_lines((r, c), (x_mesh, y_mesh), mesh_line_style, fmt_label)?;
+        set_role(&self.drawing_area, Some("plotters-grid"))?;
+        let (mut x_labels, mut y_labels) = self.draw_mesh_lines(
+            (r, c),
+            (x_mesh, y_mesh),
+            mesh_line_style,
+            mesh_mask,
+            grid_shadow,
+            aligned_ticks,
+            fmt_label,
+        )?;
+        set_role(&self.drawing_area, Some("plotters-axis"))?;
+
+        if let Some(ref crossing) = crossing_axes {
+            // The axes and labels are drawn inside the plotting area, so the label areas only
+            // keep the axis descriptions
+            self.draw_crossing_axes(
+                crossing,
+                (x_axis.contains(&true), y_axis.contains(&true)),
+                axis_style,
+                label_style,
+                &x_labels[..],
+                &y_labels[..],
+            )?;
+            x_labels.clear();
+            y_labels.clear();
+            x_axis = [false, false];
+            y_axis = [false, false];
+        }
 
         for idx in 0..2 {
             self.draw_axis_and_labels(
                 self.x_label_area[idx].as_ref(),
-                if x_axis { Some(axis_style) } else { None },
+                if x_axis[idx] { Some(axis_style) } else { None },
                 &x_labels[..],
                 label_style,
                 x_label_offset,
+                x_label_padding,
+                tick_lengths,
+                label_overflow,
                 (0, -1 + idx as i16 * 2),
-                x_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                x_desc
+                    .as_ref()
+                    .map(|desc| (&desc[..], axis_desc_style, axis_desc_pos)),
             )?;
 
             self.draw_axis_and_labels(
                 self.y_label_area[idx].as_ref(),
-                if y_axis { Some(axis_style) } else { None },
+                if y_axis[idx] { Some(axis_style) } else { None },
                 &y_labels[..],
                 label_style,
                 y_label_offset,
+                y_label_padding,
+                tick_lengths,
+                label_overflow,
                 (-1 + idx as i16 * 2, 0),
-                y_desc.as_ref().map(|desc| (&desc[..], axis_desc_style)),
+                y_desc
+                    .as_ref()
+                    .map(|desc| (&desc[..], axis_desc_style, axis_desc_pos)),
             )?;
         }
 
-        Ok(())
+        set_role(&self.drawing_area, None)
     }
 
     /// Attach a secondary coord to the chart
@@ -434,3 +1636,1076 @@ impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, RangedCo
         DualCoordChartContext::new(self, RangedCoord::new(x_coord, y_coord, pixel_range))
     }
 }
+
+impl<'a, DB: DrawingBackend, X: DescreteRanged, Y: Ranged> ChartContext<'a, DB, RangedCoord<X, Y>>
+where
+    X::ValueType: Eq + PartialOrd,
+{
+    /// Get the categories of the X axis and the pixel spans of their bands relative to the
+    /// plotting area, the band of a category goes from the category to the next one, like the bars
+    fn category_bands(&self) -> Vec<(X::ValueType, i32, i32)> {
+        let (x0, _) = self.drawing_area.get_base_pixel();
+        let x_range = self.drawing_area.get_x_axis_pixel_range();
+        let (left, right) = (
+            x_range.start.min(x_range.end),
+            x_range.start.max(x_range.end),
+        );
+        let range = self.drawing_area.get_x_range();
+
+        let mut bands = vec![];
+        let mut value = range.start;
+        while value < range.end {
+            let next = X::next_value(&value);
+            if next <= value {
+                break;
+            }
+            let (a, b) = (
+                self.drawing_area.map_x(&value),
+                self.drawing_area.map_x(&next),
+            );
+            bands.push((value, a.min(b).max(left) - x0, a.max(b).min(right) - x0));
+            value = next;
+        }
+        bands
+    }
+
+    /// Get the top and bottom of the plotting area relative to itself
+    fn plotting_area_span(&self) -> (i32, i32) {
+        let (_, y0) = self.drawing_area.get_base_pixel();
+        let y_range = self.drawing_area.get_y_axis_pixel_range();
+        (
+            y_range.start.min(y_range.end) - y0,
+            y_range.start.max(y_range.end) - y0,
+        )
+    }
+
+    /// Shade every other category band of the X axis across the plotting area, i.e. the zebra
+    /// striping, starting from the second band. This should be drawn before the series, so the
+    /// stripes stay behind them.
+    /// - `style`: The style of the stripes, which should be filled
+    pub fn draw_category_stripes<S: Into<ShapeStyle>>(
+        &mut self,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let area = self.drawing_area.strip_coord_spec();
+        let (top, bottom) = self.plotting_area_span();
+        for (_, left, right) in self.category_bands().into_iter().skip(1).step_by(2) {
+            area.draw(&Rectangle::new(
+                [(left, top), (right, bottom)],
+                style.clone(),
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Draw the separator lines between the category bands of the X axis across the plotting
+    /// area. This should be drawn before the series, so the lines stay behind them.
+    /// - `style`: The style of the separator lines
+    pub fn draw_category_separators<S: Into<ShapeStyle>>(
+        &mut self,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let area = self.drawing_area.strip_coord_spec();
+        let (top, bottom) = self.plotting_area_span();
+        let bands = self.category_bands();
+        for (_, _, x) in bands.iter().take(bands.len().saturating_sub(1)) {
+            area.draw(&Path::new(vec![(*x, top), (*x, bottom)], style.clone()))?;
+        }
+        Ok(())
+    }
+
+    /// Draw the second tier of the X axis labels for the nested categories, e.g. the years of
+    /// the quarters. The consecutive categories in the same group are spanned by a bracket in
+    /// the lower X label area with the group label under it, and the groups are separated by
+    /// vertical lines. The label area should be tall enough for both tiers of the labels.
+    /// - `group_of`: The function that gives the group of a category
+    /// - `offset`: The distance in pixels from the axis to the brackets, which should clear the
+    ///   category labels
+    /// - `label_style`: The style of the group labels
+    /// - `line_style`: The style of the brackets and the separators
+    pub fn draw_category_groups<'b, G, F, LS, S>(
+        &mut self,
+        group_of: F,
+        offset: u32,
+        label_style: LS,
+        line_style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        G: PartialEq + std::fmt::Display,
+        F: Fn(&X::ValueType) -> G,
+        LS: Into<TextStyle<'b>>,
+        S: Into<ShapeStyle>,
+    {
+        let area = match self.x_label_area[1].as_ref() {
+            Some(area) => area,
+            None => return Ok(()),
+        };
+        let (label_style, line_style) = (label_style.into(), line_style.into());
+        let height = area.dim_in_pixel().1 as i32;
+        let offset = offset as i32;
+
+        let mut groups: Vec<(G, i32, i32)> = vec![];
+        for (value, left, right) in self.category_bands() {
+            let group = group_of(&value);
+            match groups.last_mut() {
+                Some(last) if last.0 == group => last.2 = right,
+                _ => groups.push((group, left, right)),
+            }
+        }
+
+        set_role(area, Some("plotters-axis"))?;
+        for (idx, (_, left, right)) in groups.iter().enumerate() {
+            if idx > 0 {
+                area.draw(&Path::new(
+                    vec![(*left, 0), (*left, height)],
+                    line_style.clone(),
+                ))?;
+            }
+            let (l, r) = (left + 2, right - 2);
+            area.draw(&Path::new(
+                vec![(l, offset - 4), (l, offset), (r, offset), (r, offset - 4)],
+                line_style.clone(),
+            ))?;
+        }
+
+        set_role(area, Some("plotters-label"))?;
+        for (group, left, right) in groups {
+            let text = group.to_string();
+            let (w, _) = label_style.font.box_size(&text).unwrap_or((0, 0));
+            area.draw_text(
+                &text,
+                &label_style,
+                ((left + right - w as i32) / 2, offset + 4),
+            )?;
+        }
+
+        set_role(area, None)
+    }
+}
+
+/// The outer sides of the plotting area which all the points added so far are on, i.e. left,
+/// right, top and bottom
+struct OutsideSides {
+    sides: [bool; 4],
+    empty: bool,
+}
+
+impl Default for OutsideSides {
+    fn default() -> Self {
+        Self {
+            sides: [true; 4],
+            empty: true,
+        }
+    }
+}
+
+impl OutsideSides {
+    /// Add a point of the element
+    /// - `(x, y)`: The backend coordinate of the point
+    /// - `(x_range, y_range)`: The pixel ranges of the plotting area, the ends are included just
+    ///   like the clipping of the drawing area
+    fn add(&mut self, (x, y): BackendCoord, (x_range, y_range): &(Range<i32>, Range<i32>)) {
+        self.empty = false;
+        self.sides[0] &= x < x_range.start;
+        self.sides[1] &= x > x_range.end;
+        self.sides[2] &= y < y_range.start;
+        self.sides[3] &= y > y_range.end;
+    }
+
+    /// Check if all the points are on the same outer side of the plotting area, so that nothing
+    /// of the element is visible
+    fn is_totally_outside(&self) -> bool {
+        !self.empty && self.sides.iter().any(|side| *side)
+    }
+}
+
+/// Extend the bounding box of the data with a point, the values that can't be compared, e.g.
+/// NaN, are ignored
+/// - `bounds`: The lower and upper corners of the bounding box
+/// - `point`: The point to include
+#[allow(clippy::type_complexity)]
+fn extend_data_bounds<XT: PartialOrd + Clone, YT: PartialOrd + Clone>(
+    bounds: &mut Option<((XT, YT), (XT, YT))>,
+    (x, y): &(XT, YT),
+) {
+    if x.partial_cmp(x).is_none() || y.partial_cmp(y).is_none() {
+        return;
+    }
+    match bounds {
+        None => *bounds = Some(((x.clone(), y.clone()), (x.clone(), y.clone()))),
+        Some(((x0, y0), (x1, y1))) => {
+            if x < x0 {
+                *x0 = x.clone();
+            }
+            if x > x1 {
+                *x1 = x.clone();
+            }
+            if y < y0 {
+                *y0 = y.clone();
+            }
+            if y > y1 {
+                *y1 = y.clone();
+            }
+        }
+    }
+}
+
+/// Mark the shapes drawn after this call with the CSS class of their role in the chart, e.g.
+/// `plotters-axis`, which is emitted by the SVG backend
+/// - `area`: Any drawing area on the backend
+/// - `role`: The class of the role, `None` clears the role
+fn set_role<DB: DrawingBackend, CT: CoordTranslate>(
+    area: &DrawingArea<DB, CT>,
+    role: Option<&str>,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    area.set_metadata(role.map(BackendMetadata::with_class).as_ref())
+}
+
+/// Fit a label into the room of the label area with the overflow policy
+/// - `text`: The label
+/// - `style`: The style of the label
+/// - `room`: The room of the label area in pixels, away from the axis
+/// - `horizontal`: If the room is horizontal, i.e. the width of a Y label area
+/// - `policy`: The overflow policy
+/// - `measure`: The function measuring the size of a text in a style
+/// - **returns**: The label and the style to draw it with
+fn fit_label<'b, M: Fn(&str, &TextStyle) -> (u32, u32)>(
+    text: &str,
+    style: &TextStyle<'b>,
+    room: i32,
+    horizontal: bool,
+    policy: LabelOverflow,
+    measure: M,
+) -> (String, TextStyle<'b>) {
+    let extent = |text: &str, style: &TextStyle| {
+        let (w, h) = measure(text, style);
+        if horizontal {
+            w as i32
+        } else {
+            h as i32
+        }
+    };
+    let mut style = style.clone();
+    if extent(text, &style) <= room {
+        return (text.to_string(), style);
+    }
+    match policy {
+        LabelOverflow::Drop => (text.to_string(), style),
+        LabelOverflow::Ellipsis if horizontal => {
+            let mut chars: Vec<_> = text.chars().collect();
+            loop {
+                let cut: String = chars.iter().chain(std::iter::once(&'…')).collect();
+                if chars.is_empty() || extent(&cut, &style) <= room {
+                    return (cut, style);
+                }
+                chars.pop();
+            }
+        }
+        // An X label is shrunk even with the ellipsis, since the cut doesn't make it any lower
+        LabelOverflow::Shrink | LabelOverflow::Ellipsis => {
+            while extent(text, &style) > room && style.font.get_size() > 1.0 {
+                style.font = style.font.resize(style.font.get_size() * 0.9);
+            }
+            (text.to_string(), style)
+        }
+    }
+}
+
+/// Get the style of a tick label, which is the label style with the color of the tick if the
+/// tick has its own color
+fn tick_label_style<'b>(style: &TextStyle<'b>, color: &Option<RGBAColor>) -> TextStyle<'b> {
+    let mut style = style.clone();
+    if let Some(color) = color {
+        style.color = color.clone();
+    }
+    style
+}
+
+/// Check if the label is the same as the previous label of the axis, which happens when the
+/// formatter rounds the adjacent key points of a zoomed axis to the same text, e.g. `3`, `3`,
+/// `4`. The empty labels are skipped, since they are the ticks left unlabeled on purpose, e.g.
+/// by a formatter labeling every other tick. Only the text of a repeated label is dropped, the
+/// tick and the grid line are kept.
+fn is_repeated_label(labels: &[(i32, TickLabel)], label: &Option<TickLabel>) -> bool {
+    let last = labels.iter().rev().find(|(_, (text, _))| !text.is_empty());
+    match (last, label) {
+        (Some((_, (last, _))), Some((label, _))) => !label.is_empty() && last == label,
+        _ => false,
+    }
+}
+
+/// Make the arrow marking a point out of the pixel range, the tip of the arrow is the point
+/// pinned to the boundary and it points to the direction of the actual position
+/// - **returns**: The closed outline of the arrow, or `None` if the point is in the range
+fn out_of_range_marker(
+    (x, y): BackendCoord,
+    (x_range, y_range): (Range<i32>, Range<i32>),
+) -> Option<Vec<BackendCoord>> {
+    let direction = |v: i32, range: &Range<i32>| {
+        if v < range.start {
+            -1
+        } else if v > range.end {
+            1
+        } else {
+            0
+        }
+    };
+    let (dx, dy) = (direction(x, &x_range), direction(y, &y_range));
+    if (dx, dy) == (0, 0) {
+        return None;
+    }
+
+    let tip = (
+        x.max(x_range.start).min(x_range.end),
+        y.max(y_range.start).min(y_range.end),
+    );
+    let (size, half_width) = (6, 3);
+    let base = (tip.0 - dx * size, tip.1 - dy * size);
+    let left = (base.0 - dy * half_width, base.1 + dx * half_width);
+    let right = (base.0 + dy * half_width, base.1 - dx * half_width);
+    Some(vec![left, tip, right, left])
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_series_clamped() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart
+        .draw_series_clamped(
+            vec![(5.0, 5.0), (20.0, 5.0), (5.0, -3.0)]
+                .into_iter()
+                .map(|p| Circle::new(p, 3, RED.filled())),
+            None,
+        )
+        .expect("Drawing Failure");
+
+    let centers: Vec<_> = calls.circles().into_iter().map(|(_, _, c, _)| c).collect();
+    assert_eq!(centers, vec![(50, 50), (100, 50), (50, 100)]);
+
+    calls.clear();
+    chart
+        .draw_series_clamped(
+            vec![(10.0, 0.0), (0.0, 10.0)]
+                .into_iter()
+                .map(|p| Circle::new(p, 3, RED.filled())),
+            Some(ShapeStyle::from(&RED)),
+        )
+        .expect("Drawing Failure");
+
+    let centers: Vec<_> = calls.circles().into_iter().map(|(_, _, c, _)| c).collect();
+    assert_eq!(centers, vec![(100, 100), (0, 0)]);
+    assert!(calls.paths().is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_out_of_range_marker() {
+    let rect = (0..100, 0..100);
+    assert_eq!(out_of_range_marker((50, 50), rect.clone()), None);
+    assert_eq!(out_of_range_marker((100, 100), rect.clone()), None);
+    assert_eq!(
+        out_of_range_marker((150, 50), rect.clone()),
+        Some(vec![(94, 53), (100, 50), (94, 47), (94, 53)])
+    );
+    assert_eq!(
+        out_of_range_marker((50, -20), rect),
+        Some(vec![(53, 6), (50, 0), (47, 6), (53, 6)])
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_shared_x_series() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_path(|_, path| {
+            assert_eq!(path[0], (0, 100));
+            assert_eq!(path[1].0, 50);
+            assert_eq!(path[2].0, 100);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 2);
+        });
+    });
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    let xs = chart.map_shared_x(&[0.0, 5.0, 10.0]);
+    assert_eq!(xs.len(), 3);
+    chart
+        .draw_shared_x_series(&xs, &[0.0, 2.0, 10.0], &RED)
+        .expect("Drawing Failure");
+    chart
+        .draw_shared_x_series(&xs, &[0.0, 8.0, 3.0], &BLUE)
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_repeated_labels() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(20)
+        .build_ranged(0.0..1.0, 0.0..1.0)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .x_labels(10)
+        .x_label_formatter(&|x| format!("{:.0}", x))
+        .line_style_1(&RED)
+        .disable_y_mesh()
+        .draw()
+        .expect("Drawing Failure");
+
+    // The key points 0.1, 0.2, ..., 1.0 are formatted to `0` and `1` only, each of them is
+    // drawn once, but every key point keeps its tick and its grid line
+    let texts: Vec<_> = calls.texts().into_iter().map(|(_, t, _)| t).collect();
+    assert_eq!(texts, vec!["0", "", "", "", "", "1", "", "", "", ""]);
+    let all_ticks: Vec<_> = (1..=10).map(|x| x * 10).collect();
+    assert_eq!(grid_lines_and_ticks(&calls), (all_ticks.clone(), all_ticks));
+}
+
+#[cfg(test)]
+#[test]
+fn test_repeated_labels_with_index_formatter() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(20)
+        .build_ranged(0.0..1.0, 0.0..1.0)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .x_labels(10)
+        .x_label_formatter_with_index(&|i, x| {
+            if i % 2 == 0 {
+                format!("{:.0}", x)
+            } else {
+                String::new()
+            }
+        })
+        .line_style_1(&RED)
+        .disable_y_mesh()
+        .draw()
+        .expect("Drawing Failure");
+
+    // Every other key point is left unlabeled, the unlabeled ones are never taken as repeated
+    // and don't hide the repeated ones either, i.e. 0.3 and 0.5 repeat the `0` of 0.1
+    let texts: Vec<_> = calls.texts().into_iter().map(|(_, t, _)| t).collect();
+    assert_eq!(texts, vec!["0", "", "", "", "", "", "1", "", "", ""]);
+    let all_ticks: Vec<_> = (1..=10).map(|x| x * 10).collect();
+    assert_eq!(grid_lines_and_ticks(&calls), (all_ticks.clone(), all_ticks));
+}
+
+/// The X positions of the red grid lines and of the ticks under a 100x100 chart with a 20
+/// pixel X label area
+#[cfg(test)]
+fn grid_lines_and_ticks(calls: &crate::drawing::MockedCalls) -> (Vec<i32>, Vec<i32>) {
+    use crate::prelude::*;
+
+    let grid_lines = calls
+        .lines()
+        .into_iter()
+        .filter(|(c, ..)| *c == RED.to_rgba())
+        .map(|(_, from, _)| from.0)
+        .collect();
+    let ticks = calls
+        .paths()
+        .into_iter()
+        .filter(|(_, path)| path.len() == 2 && path[0].0 == path[1].0 && path[0].1 >= 80)
+        .map(|(_, path)| path[0].0)
+        .collect();
+    (grid_lines, ticks)
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_x_marker() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_path(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path, vec![(50, 0), (50, 100)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart
+        .draw_x_marker(&5.0, &RED, None, MarkerLabelPosition::Top)
+        .expect("Drawing Failure");
+    chart
+        .draw_x_marker(&20.0, &RED, None, MarkerLabelPosition::Top)
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_series_clipped() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_rect(|_, _, u, d| {
+            assert_eq!([u, d], [(20, 30), (50, 60)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 2);
+        });
+    });
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart
+        .draw_series_clipped(
+            std::iter::once(Rectangle::new([(0.0, 0.0), (10.0, 10.0)], &RED)),
+            SeriesClip::Pixel((20, 30), (50, 60)),
+        )
+        .expect("Drawing Failure");
+    chart
+        .draw_series_clipped(
+            std::iter::once(Rectangle::new([(0.0, 10.0), (10.0, 0.0)], &RED)),
+            SeriesClip::Data((2.0, 7.0), (5.0, 4.0)),
+        )
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_clipped_count() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |_| {});
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    let anno = chart
+        .draw_series(
+            vec![(5.0, 5.0), (20.0, 5.0), (-1.0, 3.0), (10.0, 0.0)]
+                .into_iter()
+                .map(|p| Circle::new(p, 3, &RED)),
+        )
+        .expect("Drawing Failure");
+    assert_eq!(anno.clipped_count(), 2);
+
+    let range = (0..100, 0..100);
+    let is_totally_outside = |points: Vec<BackendCoord>, range| {
+        let mut outside = OutsideSides::default();
+        for p in points {
+            outside.add(p, range);
+        }
+        outside.is_totally_outside()
+    };
+    assert!(!is_totally_outside(vec![(-10, 50), (110, 50)], &range));
+    assert!(is_totally_outside(vec![(-10, 50), (-5, 150)], &range));
+    assert!(!is_totally_outside(vec![(100, 100)], &range));
+    assert!(is_totally_outside(vec![(101, 50)], &range));
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_category_bands() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_rect(|_, _, u, d| {
+            assert!(u == (25, 0) && d == (50, 100) || u == (75, 0) && d == (100, 100));
+        });
+        m.check_draw_path(|_, path| {
+            assert!([25, 50, 75].contains(&path[0].0));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 2);
+            assert_eq!(b.num_draw_path_call, 3);
+        });
+    });
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0..4, 0.0..1.0)
+        .expect("Build chart error");
+    chart
+        .draw_category_stripes(BLACK.mix(0.1).filled())
+        .expect("Drawing Failure");
+    chart
+        .draw_category_separators(&BLACK)
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_category_groups() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(80, 100, |m| {
+        m.check_draw_path(|_, path| match path.len() {
+            2 => assert_eq!(path, vec![(40, 60), (40, 100)]),
+            4 => assert!(path[1] == (2, 80) && path[2] == (38, 80) || path[1] == (42, 80)),
+            _ => panic!("Unexpected path"),
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 3);
+            assert_eq!(b.num_draw_text_call, 2);
+        });
+    });
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(40)
+        .build_ranged(0..8, 0.0..1.0)
+        .expect("Build chart error");
+    chart
+        .draw_category_groups(|q| 2019 + q / 4, 20, ("sans-serif", 10), &BLACK)
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_data_bounds() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |_| {});
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart.track_data_bounds();
+    assert_eq!(chart.data_bounds(), None);
+
+    chart
+        .draw_series(LineSeries::new(vec![(1.0, 5.0), (3.0, -2.0)], &RED))
+        .expect("Drawing Failure");
+    chart
+        .draw_series(std::iter::once(Circle::new((12.0, 4.0), 3, &BLUE)))
+        .expect("Drawing Failure");
+    assert_eq!(chart.data_bounds(), Some((1.0..12.0, -2.0..5.0)));
+
+    let mut bounds = None;
+    extend_data_bounds(&mut bounds, &(std::f64::NAN, 1.0));
+    assert_eq!(bounds, None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_data_bounds_of_all_series() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |_| {});
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart.track_data_bounds();
+
+    chart
+        .draw_series_with_legend(LineSeries::new(vec![(2.0, 3.0), (4.0, 6.0)], &RED))
+        .expect("Drawing Failure");
+    assert_eq!(chart.data_bounds(), Some((2.0..4.0, 3.0..6.0)));
+
+    chart
+        .draw_series_clamped(std::iter::once(Circle::new((15.0, 1.0), 3, &BLUE)), None)
+        .expect("Drawing Failure");
+    assert_eq!(chart.data_bounds(), Some((2.0..15.0, 1.0..6.0)));
+
+    let xs = chart.map_shared_x(&[0.5, 5.0]);
+    chart
+        .draw_shared_x_series(&xs, &[7.0, 4.0], &RED)
+        .expect("Drawing Failure");
+    assert_eq!(chart.data_bounds(), Some((0.5..15.0, 1.0..7.0)));
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_keyed_series() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_path(|c, _| {
+            assert_eq!(c, BLUE.to_rgba());
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart.set_series_visible("red", false);
+    assert!(!chart.is_series_visible("red"));
+    assert!(chart.is_series_visible("blue"));
+
+    let data = vec![(1.0, 1.0), (5.0, 5.0)];
+    chart
+        .draw_keyed_series("red", LineSeries::new(data.clone(), &RED))
+        .expect("Drawing Failure")
+        .label("red");
+    chart
+        .draw_keyed_series("blue", LineSeries::new(data, &BLUE))
+        .expect("Drawing Failure")
+        .label("blue");
+    assert_eq!(chart.series_anno.len(), 2);
+}
+
+#[cfg(test)]
+#[test]
+fn test_pin_to_pixel() {
+    let drawing_area = crate::create_mocked_drawing_area(200, 200, |_| {});
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(-10.0..10.0, -10.0..10.0)
+        .expect("Create chart");
+    let scale = chart.backend_coord_raw(&(5.0, 5.0));
+    let origin = chart.backend_coord_raw(&(0.0, 0.0));
+
+    chart.pin_to_pixel(&(0.0, 0.0), (37, 151));
+    assert_eq!(chart.backend_coord_raw(&(0.0, 0.0)), (37, 151));
+    assert_eq!(
+        chart.backend_coord_raw(&(5.0, 5.0)),
+        (37 + scale.0 - origin.0, 151 + scale.1 - origin.1)
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_fit_label() {
+    // Every character is half of the font size wide, so the test doesn't depend on the
+    // installed fonts
+    let measure = |t: &str, s: &TextStyle| {
+        let size = s.font.get_size();
+        ((t.chars().count() as f64 * size / 2.0) as u32, size as u32)
+    };
+    let style: TextStyle = ("sans-serif", 20).into();
+    let label = "123456789";
+    // The full label is 90 pixels wide
+    let room = 45;
+
+    let (text, dropped) = fit_label(label, &style, room, true, LabelOverflow::Drop, measure);
+    assert_eq!((text.as_str(), dropped.font.get_size()), (label, 20.0));
+
+    let (text, shrunk) = fit_label(label, &style, room, true, LabelOverflow::Shrink, measure);
+    assert_eq!(text, label);
+    assert!(shrunk.font.get_size() < 20.0 && measure(&text, &shrunk).0 <= 45);
+    assert!(
+        measure(
+            &text,
+            &shrunk.font.resize(shrunk.font.get_size() / 0.9).into()
+        )
+        .0 > 45
+    );
+
+    let (text, _) = fit_label(label, &style, room, true, LabelOverflow::Ellipsis, measure);
+    assert_eq!(text, "123…");
+
+    // The height is measured for the X labels, which are shrunk rather than cut off
+    let (text, shrunk) = fit_label(label, &style, 15, false, LabelOverflow::Ellipsis, measure);
+    assert_eq!(text, label);
+    assert!(shrunk.font.get_size() < 20.0 && measure(&text, &shrunk).1 <= 15);
+    let (text, kept) = fit_label(label, &style, 25, false, LabelOverflow::Ellipsis, measure);
+    assert_eq!((text.as_str(), kept.font.get_size()), (label, 20.0));
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_minimap() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    let inset = drawing_area.clone().shrink((60, 0), (40, 40));
+    chart
+        .draw_minimap(
+            &inset,
+            vec![(0.0, 0.0), (20.0, 20.0)],
+            0.0..20.0,
+            0.0..20.0,
+            &BLACK,
+            &RED,
+        )
+        .expect("Drawing Failure");
+    let rects: Vec<_> = calls
+        .rects()
+        .into_iter()
+        .map(|(_, _, u, d)| (u, d))
+        .collect();
+    assert_eq!(rects, vec![((60, 0), (99, 39)), ((61, 20), (79, 38))]);
+    let paths: Vec<_> = calls.paths().into_iter().map(|(_, path)| path).collect();
+    assert_eq!(paths, vec![vec![(61, 38), (98, 1)]]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_series_with_progress() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0..10, 0..10)
+        .expect("Build chart error");
+    let grid = || (0..10).flat_map(|x| (0..10).map(move |y| Circle::new((x, y), 1, &RED)));
+
+    // The size hint of the nested loop is unknown, so only the end is reported
+    let mut reported = vec![];
+    chart
+        .draw_series_with_progress(grid(), None, |p| reported.push(p))
+        .expect("Drawing Failure");
+    assert_eq!(reported, vec![1.0]);
+    assert_eq!(calls.circles().len(), 100);
+
+    calls.clear();
+    let mut reported = vec![];
+    chart
+        .draw_series_with_progress(grid(), Some(100), |p| reported.push(p))
+        .expect("Drawing Failure");
+    assert_eq!(reported.len(), 100);
+    assert_eq!(reported[0], 0.01);
+    assert_eq!(reported[49], 0.5);
+    assert_eq!(reported[99], 1.0);
+    assert_eq!(calls.circles().len(), 100);
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_series_clipped_to_polygon() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0..10, 0..10)
+        .expect("Build chart error");
+    let cells = (0..10)
+        .flat_map(|x| (0..10).map(move |y| Rectangle::new([(x, y), (x + 1, y + 1)], RED.filled())));
+    let anno = chart
+        .draw_series_clipped_to_polygon(cells, &[(0, 0), (10, 0), (0, 10)])
+        .expect("Drawing Failure");
+    assert_eq!(anno.clipped_count(), 0);
+
+    // The mocked backend can't clip, so the cells are filled row by row with the runs inside
+    // of the lower left triangle
+    let ((left, bottom), (right, top)) =
+        (chart.backend_coord(&(0, 0)), chart.backend_coord(&(10, 10)));
+    let edge = |y: i32| {
+        let y = f64::from(y) + 0.5;
+        f64::from(left) + f64::from(right - left) * (y - f64::from(top)) / f64::from(bottom - top)
+    };
+    let lines = calls.lines();
+    assert!(!lines.is_empty());
+    assert!(calls.rects().is_empty());
+    for (_, from, to) in lines.iter() {
+        assert_eq!(from.1, to.1);
+        assert!(from.0 >= left && f64::from(to.0) + 0.5 <= edge(to.1));
+    }
+    let rightmost = |row: i32| {
+        lines
+            .iter()
+            .filter(|(_, from, _)| from.1 == row)
+            .map(|(_, _, to)| to.0)
+            .max()
+            .expect("No run in the row")
+    };
+    assert!(rightmost(bottom - 1) > right - 5);
+    assert!(rightmost(top + 10) < left + 15);
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_empty_state() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .y_label_area_size(20)
+        .build_ranged(0..10, 0..10)
+        .expect("Build chart error");
+    assert!(!chart.draw_empty_state().expect("Drawing Failure"));
+
+    let style = TextStyle::from(("sans-serif", 12).into_font()).color(&RED);
+    let (w, h) = style.font.box_size("No data").unwrap();
+    chart.configure_empty_state("No data", style);
+    chart
+        .draw_series(std::iter::empty::<Circle<_>>())
+        .expect("Drawing Failure");
+    assert!(chart.draw_empty_state().expect("Drawing Failure"));
+
+    // The message is centered in the plotting area, which is right of the label area
+    let texts = calls.texts();
+    assert_eq!(
+        texts,
+        vec![(
+            RED.to_rgba(),
+            "No data".to_string(),
+            (20 + (80 - w as i32) / 2, (100 - h as i32) / 2)
+        )]
+    );
+
+    // Once a series has drawn an element, the chart isn't empty anymore
+    calls.clear();
+    chart
+        .draw_series(std::iter::once(Circle::new((5, 5), 3, &RED)))
+        .expect("Drawing Failure");
+    assert!(!chart.draw_empty_state().expect("Drawing Failure"));
+    assert!(calls.texts().is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_zoom_inset() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(200, 100);
+    let calls = backend.record_calls();
+    let root = backend.into_drawing_area();
+    let chart = ChartBuilder::on(&root)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    let inset_area = root.clone().shrink((140, 10), (50, 40));
+    let inset = chart
+        .zoom_inset(&inset_area, 2.0..4.0, 2.0..4.0, &RED)
+        .expect("Drawing Failure");
+
+    // The region is marked on the chart, and the inset is framed
+    let (left, bottom) = chart.backend_coord(&(2.0, 2.0));
+    let (right, top) = chart.backend_coord(&(4.0, 4.0));
+    let rects: Vec<_> = calls
+        .rects()
+        .into_iter()
+        .map(|(_, filled, u, d)| (filled, u, d))
+        .collect();
+    assert_eq!(
+        rects,
+        vec![
+            (false, (left, top), (right, bottom)),
+            (false, (140, 10), (189, 49)),
+        ]
+    );
+    // The inset is right of the region, so the right side of the region is connected to the
+    // left side of the inset
+    let paths: Vec<_> = calls.paths().into_iter().map(|(_, p)| p).collect();
+    assert_eq!(
+        paths,
+        vec![
+            vec![(right, top), (140, 10)],
+            vec![(right, bottom), (140, 49)]
+        ]
+    );
+
+    // The inset maps the zoomed region to its whole area, the end of the range is the edge
+    // of the area like on any other chart
+    assert_eq!(inset.backend_coord(&(2.0, 2.0)), (140, 50));
+    assert_eq!(inset.backend_coord(&(4.0, 4.0)), (190, 10));
+}
+
+#[cfg(test)]
+#[test]
+fn test_set_range() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(120, 120);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(20)
+        .y_label_area_size(20)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    assert_eq!(chart.backend_coord(&(5.0, 5.0)), (70, 50));
+
+    // Panning and zooming keeps the plotting area, only the mapping to it changes
+    chart.set_x_range(5.0..15.0);
+    chart.set_y_range(0.0..20.0);
+    assert_eq!(chart.backend_coord(&(5.0, 5.0)), (20, 75));
+    assert_eq!(chart.backend_coord(&(15.0, 20.0)), (120, 0));
+    chart
+        .draw_series(std::iter::once(Circle::new((10.0, 10.0), 3, &RED)))
+        .expect("Drawing Failure");
+    assert_eq!(calls.circles(), vec![(RED.to_rgba(), false, (70, 50), 3)]);
+
+    // The mesh is labeled with the new range, in the same label areas
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_y_mesh()
+        .draw()
+        .expect("Drawing Failure");
+    let texts = calls.texts();
+    let x_labels: Vec<_> = texts.iter().filter(|(_, _, (_, y))| *y >= 100).collect();
+    let y_labels: Vec<_> = texts.iter().filter(|(_, _, (x, _))| *x < 20).collect();
+    assert!(x_labels.iter().any(|(_, t, _)| t == "13.0"));
+    assert!(y_labels.iter().any(|(_, t, _)| t == "18.0"));
+    assert_eq!(x_labels.len() + y_labels.len(), texts.len());
+}
+
+#[cfg(test)]
+#[test]
+fn test_draw_series_with_legend() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(200, 200);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0..10, 0..10)
+        .expect("Build chart error");
+    chart
+        .draw_series_with_legend(LineSeries::new(vec![(1, 1), (2, 2)], &RED))
+        .expect("Drawing Failure")
+        .label("line");
+    chart
+        .draw_series_with_legend(std::iter::once(Circle::new((3, 3), 8, BLUE.filled())))
+        .expect("Drawing Failure")
+        .label("point");
+    chart
+        .draw_series_with_legend(std::iter::once(Rectangle::new(
+            [(4, 0), (5, 4)],
+            GREEN.filled(),
+        )))
+        .expect("Drawing Failure")
+        .label("bar");
+    calls.clear();
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperLeft)
+        .draw()
+        .expect("Drawing Failure");
+
+    // Each sample has the style of its series, and is placed in the legend area at the left
+    // of its entry, from the top to the bottom
+    let x = calls.texts()[0].2 .0 - 30;
+    let paths = calls.paths();
+    assert_eq!(paths.len(), 1);
+    let line_y = paths[0].1[0].1;
+    assert_eq!(
+        paths,
+        vec![(RED.to_rgba(), vec![(x, line_y), (x + 20, line_y)])]
+    );
+    // The sample is no larger than the entry, so the large point is shrunk
+    let circles = calls.circles();
+    assert_eq!(circles.len(), 1);
+    let point_y = (circles[0].2).1;
+    assert_eq!(circles, vec![(BLUE.to_rgba(), true, (x + 10, point_y), 5)]);
+    let bars: Vec<_> = calls
+        .rects()
+        .into_iter()
+        .filter(|(c, ..)| *c == GREEN.to_rgba())
+        .collect();
+    assert_eq!(bars.len(), 1);
+    let bar_y = (bars[0].2).1 + 5;
+    assert_eq!(
+        bars,
+        vec![(GREEN.to_rgba(), true, (x, bar_y - 5), (x + 10, bar_y + 5))]
+    );
+    assert!(line_y < point_y && point_y < bar_y);
+}
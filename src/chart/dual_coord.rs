@@ -1,12 +1,15 @@
 /// The dual coordinate system support
 use std::borrow::{Borrow, BorrowMut};
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 
 use super::context::{ChartContext, SeriesAnno};
 use super::mesh::SecondaryMeshStyle;
 
-use crate::coord::{CoordTranslate, Ranged, RangedCoord, ReverseCoordTranslate};
+use crate::coord::{
+    CoordTranslate, MeshLine, Ranged, RangedCoord, ReversableRanged, ReverseCoordTranslate,
+};
 use crate::drawing::backend::{BackendCoord, DrawingBackend};
 use crate::drawing::DrawingArea;
 use crate::drawing::DrawingAreaErrorKind;
@@ -45,6 +48,11 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 y_label_area: secondary_y_label_area,
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
+                drawn_elements: 0,
+                empty_state: None,
+                hidden_series: HashSet::new(),
+                data_bounds: None,
+                extend_data_bounds: None,
             },
         }
     }
@@ -97,7 +105,72 @@ where
 {
     /// Start configure the style for the secondary axes
     pub fn configure_secondary_axes<'b>(&'b mut self) -> SecondaryMeshStyle<'a, 'b, SX, SY, DB> {
-        SecondaryMeshStyle::new(&mut self.secondary)
+        SecondaryMeshStyle::new(&mut self.secondary, None, None)
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged, SX, SY>
+    DualCoordChartContext<'a, DB, RangedCoord<X, Y>, RangedCoord<SX, SY>>
+where
+    SX: ReversableRanged,
+    SY: ReversableRanged,
+    SX::ValueType: Debug,
+    SY::ValueType: Debug,
+{
+    /// Start configure the style for the secondary axes, whose Y labels are placed at the key
+    /// points of the primary Y axis and show the secondary values at those pixels. The
+    /// secondary Y axis shares the horizontal grid lines of the primary one rather than adding
+    /// its own, which keeps both scales readable without a cluttered double grid.
+    /// - `y_labels`: The maximum number of the labels of the primary Y axis, which should be the
+    ///   same as the one used by the primary mesh
+    pub fn configure_secondary_axes_aligned<'b>(
+        &'b mut self,
+        y_labels: usize,
+    ) -> SecondaryMeshStyle<'a, 'b, SX, SY, DB> {
+        let (x, _) = self.primiary.drawing_area.get_base_pixel();
+        let secondary = self.secondary.drawing_area.as_coord_spec();
+        let mut ticks = vec![];
+        self.primiary
+            .drawing_area
+            .as_coord_spec()
+            .draw_mesh(y_labels, 1, |line| {
+                if let MeshLine::YMesh((_, y), _, _) = line {
+                    if let Some((_, value)) = secondary.reverse_translate((x, y)) {
+                        ticks.push((y, value));
+                    }
+                }
+                Ok::<(), ()>(())
+            })
+            .ok();
+        SecondaryMeshStyle::new(&mut self.secondary, None, Some(ticks))
+    }
+
+    /// Start configure the style for the secondary axes, whose X labels are placed at the key
+    /// points of the primary X axis and show the secondary values at those pixels, e.g. the
+    /// frequencies on the top of a wavelength axis. Like the aligned Y axis, the secondary X
+    /// axis shares the vertical grid lines of the primary one.
+    /// - `x_labels`: The maximum number of the labels of the primary X axis, which should be the
+    ///   same as the one used by the primary mesh
+    pub fn configure_secondary_axes_aligned_x<'b>(
+        &'b mut self,
+        x_labels: usize,
+    ) -> SecondaryMeshStyle<'a, 'b, SX, SY, DB> {
+        let (_, y) = self.primiary.drawing_area.get_base_pixel();
+        let secondary = self.secondary.drawing_area.as_coord_spec();
+        let mut ticks = vec![];
+        self.primiary
+            .drawing_area
+            .as_coord_spec()
+            .draw_mesh(1, x_labels, |line| {
+                if let MeshLine::XMesh((x, _), _, _) = line {
+                    if let Some((value, _)) = secondary.reverse_translate((x, y)) {
+                        ticks.push((x, value));
+                    }
+                }
+                Ok::<(), ()>(())
+            })
+            .ok();
+        SecondaryMeshStyle::new(&mut self.secondary, Some(ticks), None)
     }
 }
 
@@ -122,8 +195,12 @@ where
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
-        self.secondary.draw_series_impl(series)?;
-        Ok(self.primiary.alloc_series_anno())
+        let drawn_elements = self.secondary.drawn_elements;
+        let clipped = self.secondary.draw_series_impl(series)?;
+        self.primiary.drawn_elements += self.secondary.drawn_elements - drawn_elements;
+        let anno = self.primiary.alloc_series_anno();
+        anno.clipped = clipped;
+        Ok(anno)
     }
 }
 
@@ -1,11 +1,37 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use super::context::ChartContext;
-use crate::coord::{MeshLine, Ranged, RangedCoord};
+use super::context::{ChartContext, CrossingAxes};
+use crate::coord::{MeshLine, Ranged, RangedCoord, ReversableRanged, ReverseCoordTranslate};
+use crate::drawing::backend::BackendCoord;
 use crate::drawing::backend::DrawingBackend;
 use crate::drawing::DrawingAreaErrorKind;
-use crate::style::{Color, FontDesc, RGBColor, ShapeStyle, TextStyle};
+use crate::element::Shadow;
+use crate::style::{Color, FontDesc, RGBAColor, RGBColor, ShapeStyle, TextStyle};
+
+/// The alignment of the axis description along the axis
+#[derive(Clone, Copy)]
+pub enum AxisDescAlignment {
+    /// Place the description at the left end of the X axis or the bottom end of the Y axis
+    Start,
+    /// Place the description at the center of the axis
+    Center,
+    /// Place the description at the right end of the X axis or the top end of the Y axis
+    End,
+}
+
+/// What to do with a label which is deeper than the label area, e.g. a Y label wider than
+/// the Y label area
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LabelOverflow {
+    /// Draw the label as it is, the label may be dropped or clipped by the label area
+    Drop,
+    /// Shrink the font of the label until it fits
+    Shrink,
+    /// Cut the end of the label off and append an ellipsis, so that it fits. The X labels are
+    /// limited by their height, which the cut doesn't change, so they are shrunk instead.
+    Ellipsis,
+}
 
 /// The style used to describe the mesh for a secondary coordinate system.
 pub struct SecondaryMeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
@@ -17,10 +43,16 @@ where
     X::ValueType: Debug,
     Y::ValueType: Debug,
 {
-    pub(super) fn new(target: &'b mut ChartContext<'a, DB, RangedCoord<X, Y>>) -> Self {
+    pub(super) fn new(
+        target: &'b mut ChartContext<'a, DB, RangedCoord<X, Y>>,
+        x_aligned_ticks: Option<Vec<(i32, X::ValueType)>>,
+        y_aligned_ticks: Option<Vec<(i32, Y::ValueType)>>,
+    ) -> Self {
         let mut style = target.configure_mesh();
         style.draw_x_mesh = false;
         style.draw_y_mesh = false;
+        style.x_aligned_ticks = x_aligned_ticks;
+        style.y_aligned_ticks = y_aligned_ticks;
         Self { style }
     }
 
@@ -47,6 +79,54 @@ where
         self
     }
 
+    /// Set the padding of the X labels, see `MeshStyle::x_label_padding`
+    /// - `inner`: The gap between the axis and the labels in pixels
+    /// - `outer`: The gap between the labels and the outer edge of the label area in pixels
+    pub fn x_label_padding(&mut self, inner: i32, outer: i32) -> &mut Self {
+        self.style.x_label_padding(inner, outer);
+        self
+    }
+
+    /// Set the padding of the Y labels, see `MeshStyle::y_label_padding`
+    /// - `inner`: The gap between the axis and the labels in pixels
+    /// - `outer`: The gap between the labels and the outer edge of the label area in pixels
+    pub fn y_label_padding(&mut self, inner: i32, outer: i32) -> &mut Self {
+        self.style.y_label_padding(inner, outer);
+        self
+    }
+
+    /// Set the lengths of the ticks on both sides of the axis line, see `MeshStyle::tick_lengths`
+    /// - `inner`: The length of the tick inside the plotting area in pixels
+    /// - `outer`: The length of the tick inside the label area in pixels
+    pub fn tick_lengths(&mut self, inner: i32, outer: i32) -> &mut Self {
+        self.style.tick_lengths(inner, outer);
+        self
+    }
+
+    /// Set what to do with the labels which don't fit the label areas, see
+    /// `MeshStyle::label_overflow_policy`
+    /// - `policy`: The policy of the overflowing labels
+    pub fn label_overflow_policy(&mut self, policy: LabelOverflow) -> &mut Self {
+        self.style.label_overflow_policy(policy);
+        self
+    }
+
+    /// Set which X label areas draw the axis line, see `MeshStyle::x_axis_sides`
+    /// - `top`: If the top label area draws the axis line
+    /// - `bottom`: If the bottom label area draws the axis line
+    pub fn x_axis_sides(&mut self, top: bool, bottom: bool) -> &mut Self {
+        self.style.x_axis_sides(top, bottom);
+        self
+    }
+
+    /// Set which Y label areas draw the axis line, see `MeshStyle::y_axis_sides`
+    /// - `left`: If the left label area draws the axis line
+    /// - `right`: If the right label area draws the axis line
+    pub fn y_axis_sides(&mut self, left: bool, right: bool) -> &mut Self {
+        self.style.y_axis_sides(left, right);
+        self
+    }
+
     /// Set how many labels for the X axis at most
     /// - `value`: The maximum desired number of labels in the X axis
     pub fn x_labels(&mut self, value: usize) -> &mut Self {
@@ -75,6 +155,46 @@ where
         self
     }
 
+    /// Set the formatter function for the X label text, which also takes the index of the tick
+    /// - `fmt`: The formatter function, which takes the index of the tick and the value
+    pub fn x_label_formatter_with_index(
+        &mut self,
+        fmt: &'b dyn Fn(usize, &X::ValueType) -> String,
+    ) -> &mut Self {
+        self.style.x_label_formatter_with_index(fmt);
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which also takes the index of the tick
+    /// - `fmt`: The formatter function, which takes the index of the tick and the value
+    pub fn y_label_formatter_with_index(
+        &mut self,
+        fmt: &'b dyn Fn(usize, &Y::ValueType) -> String,
+    ) -> &mut Self {
+        self.style.y_label_formatter_with_index(fmt);
+        self
+    }
+
+    /// Set the colors of the X labels tick by tick, see `MeshStyle::x_label_color`
+    /// - `color`: The function mapping the value of a tick to the color of its label
+    pub fn x_label_color<C: Color, F: Fn(&X::ValueType) -> Option<C> + 'b>(
+        &mut self,
+        color: F,
+    ) -> &mut Self {
+        self.style.x_label_color(color);
+        self
+    }
+
+    /// Set the colors of the Y labels tick by tick, see `MeshStyle::y_label_color`
+    /// - `color`: The function mapping the value of a tick to the color of its label
+    pub fn y_label_color<C: Color, F: Fn(&Y::ValueType) -> Option<C> + 'b>(
+        &mut self,
+        color: F,
+    ) -> &mut Self {
+        self.style.y_label_color(color);
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: Into<TextStyle<'b>>>(&mut self, style: T) -> &mut Self {
@@ -96,6 +216,20 @@ where
         self
     }
 
+    /// Set the alignment of the axis descriptions along the axis
+    /// - `alignment`: The alignment of the descriptions
+    pub fn axis_desc_alignment(&mut self, alignment: AxisDescAlignment) -> &mut Self {
+        self.style.axis_desc_alignment(alignment);
+        self
+    }
+
+    /// Set if the axis descriptions should be drawn inside the plotting area, next to the axis
+    /// - `inside`: If the descriptions should be drawn inside the plotting area
+    pub fn axis_desc_inside(&mut self, inside: bool) -> &mut Self {
+        self.style.axis_desc_inside(inside);
+        self
+    }
+
     /// Draw the axes for the secondary coordinate system
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         self.style.draw()
@@ -109,21 +243,57 @@ where
 {
     pub(super) draw_x_mesh: bool,
     pub(super) draw_y_mesh: bool,
-    pub(super) draw_x_axis: bool,
-    pub(super) draw_y_axis: bool,
+    pub(super) draw_x_axis: [bool; 2],
+    pub(super) draw_y_axis: [bool; 2],
     pub(super) x_label_offset: i32,
     pub(super) y_label_offset: i32,
+    pub(super) x_label_padding: Option<(i32, i32)>,
+    pub(super) y_label_padding: Option<(i32, i32)>,
+    pub(super) tick_lengths: (i32, i32),
+    pub(super) label_overflow: LabelOverflow,
     pub(super) n_x_labels: usize,
     pub(super) n_y_labels: usize,
     pub(super) axis_desc_style: Option<TextStyle<'b>>,
     pub(super) x_desc: Option<String>,
     pub(super) y_desc: Option<String>,
+    pub(super) axis_desc_alignment: AxisDescAlignment,
+    pub(super) axis_desc_inside: bool,
+    pub(super) x_value_lines: Vec<(X::ValueType, ShapeStyle)>,
+    pub(super) y_value_lines: Vec<(Y::ValueType, ShapeStyle)>,
+    #[allow(clippy::type_complexity)]
+    pub(super) mesh_mask: Option<Box<dyn Fn(&RangedCoord<X, Y>, BackendCoord) -> bool + 'b>>,
+    pub(super) axis_origin: Option<(X::ValueType, Y::ValueType)>,
+    pub(super) axis_arrows: bool,
+    pub(super) crossing_label_gap: i32,
+    pub(super) origin_label: bool,
+    pub(super) minor_mesh_between_labels: bool,
+    pub(super) x_aligned_ticks: Option<Vec<(i32, X::ValueType)>>,
+    pub(super) y_aligned_ticks: Option<Vec<(i32, Y::ValueType)>>,
+    #[allow(clippy::type_complexity)]
+    pub(super) x_exponent_offset: Option<Box<dyn Fn(&X::ValueType) -> f64 + 'b>>,
+    #[allow(clippy::type_complexity)]
+    pub(super) y_exponent_offset: Option<Box<dyn Fn(&Y::ValueType) -> f64 + 'b>>,
+    #[allow(clippy::type_complexity)]
+    pub(super) x_decade_labels: Option<Box<dyn Fn(&X::ValueType) -> f64 + 'b>>,
+    #[allow(clippy::type_complexity)]
+    pub(super) y_decade_labels: Option<Box<dyn Fn(&Y::ValueType) -> f64 + 'b>>,
+    #[allow(clippy::type_complexity)]
+    pub(super) x_label_color: Option<Box<dyn Fn(&X::ValueType) -> Option<RGBAColor> + 'b>>,
+    #[allow(clippy::type_complexity)]
+    pub(super) y_label_color: Option<Box<dyn Fn(&Y::ValueType) -> Option<RGBAColor> + 'b>>,
     pub(super) line_style_1: Option<ShapeStyle>,
     pub(super) line_style_2: Option<ShapeStyle>,
+    pub(super) x_line_style_1: Option<ShapeStyle>,
+    pub(super) x_line_style_2: Option<ShapeStyle>,
+    pub(super) y_line_style_1: Option<ShapeStyle>,
+    pub(super) y_line_style_2: Option<ShapeStyle>,
+    pub(super) grid_shadow: Option<Shadow<()>>,
     pub(super) axis_style: Option<ShapeStyle>,
     pub(super) label_style: Option<TextStyle<'b>>,
-    pub(super) format_x: &'b dyn Fn(&X::ValueType) -> String,
-    pub(super) format_y: &'b dyn Fn(&Y::ValueType) -> String,
+    #[allow(clippy::type_complexity)]
+    pub(super) format_x: Box<dyn Fn(usize, &X::ValueType) -> String + 'b>,
+    #[allow(clippy::type_complexity)]
+    pub(super) format_y: Box<dyn Fn(usize, &Y::ValueType) -> String + 'b>,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, RangedCoord<X, Y>>>,
     pub(super) _pahtom_data: PhantomData<(X, Y)>,
 }
@@ -150,6 +320,44 @@ where
         self
     }
 
+    /// Set the padding of the X labels, which replaces the default gap between the axis and the
+    /// labels. The labels are moved toward the axis if they would be closer to the outer edge of
+    /// the label area than the outer padding.
+    /// - `inner`: The gap between the axis and the labels in pixels
+    /// - `outer`: The gap between the labels and the outer edge of the label area in pixels
+    pub fn x_label_padding(&mut self, inner: i32, outer: i32) -> &mut Self {
+        self.x_label_padding = Some((inner, outer));
+        self
+    }
+
+    /// Set the padding of the Y labels, which replaces the default gap between the axis and the
+    /// labels. The labels are moved toward the axis if they would be closer to the outer edge of
+    /// the label area than the outer padding.
+    /// - `inner`: The gap between the axis and the labels in pixels
+    /// - `outer`: The gap between the labels and the outer edge of the label area in pixels
+    pub fn y_label_padding(&mut self, inner: i32, outer: i32) -> &mut Self {
+        self.y_label_padding = Some((inner, outer));
+        self
+    }
+
+    /// Set the lengths of the ticks on both sides of the axis line, e.g. the ticks straddling
+    /// the axis line with both lengths set. By default, the ticks only point outward by 5 pixels.
+    /// - `inner`: The length of the tick inside the plotting area in pixels
+    /// - `outer`: The length of the tick inside the label area in pixels
+    pub fn tick_lengths(&mut self, inner: i32, outer: i32) -> &mut Self {
+        self.tick_lengths = (inner, outer);
+        self
+    }
+
+    /// Set what to do with the labels which are deeper than the label area, e.g. the Y labels
+    /// wider than `y_label_area_size`. By default the labels are drawn as they are, so they may
+    /// be dropped or clipped.
+    /// - `policy`: The policy of the overflowing labels
+    pub fn label_overflow_policy(&mut self, policy: LabelOverflow) -> &mut Self {
+        self.label_overflow = policy;
+        self
+    }
+
     /// Disable the mesh for the x axis.
     pub fn disable_x_mesh(&mut self) -> &mut Self {
         self.draw_x_mesh = false;
@@ -164,13 +372,29 @@ where
 
     /// Disable drawing the X axis
     pub fn disable_x_axis(&mut self) -> &mut Self {
-        self.draw_x_axis = false;
+        self.draw_x_axis = [false, false];
         self
     }
 
     /// Disable drawing the Y axis
     pub fn disable_y_axis(&mut self) -> &mut Self {
-        self.draw_y_axis = false;
+        self.draw_y_axis = [false, false];
+        self
+    }
+
+    /// Set which X label areas draw the axis line, the labels are drawn on both sides anyway
+    /// - `top`: If the top label area draws the axis line
+    /// - `bottom`: If the bottom label area draws the axis line
+    pub fn x_axis_sides(&mut self, top: bool, bottom: bool) -> &mut Self {
+        self.draw_x_axis = [top, bottom];
+        self
+    }
+
+    /// Set which Y label areas draw the axis line, the labels are drawn on both sides anyway
+    /// - `left`: If the left label area draws the axis line
+    /// - `right`: If the right label area draws the axis line
+    pub fn y_axis_sides(&mut self, left: bool, right: bool) -> &mut Self {
+        self.draw_y_axis = [left, right];
         self
     }
 
@@ -194,20 +418,59 @@ where
         self
     }
 
-    /// Set the style for the coarse grind grid
-    /// - `style`: This is the fcoarse grind grid style
+    /// Set the style for the coarse grid
+    /// - `style`: This is the coarse grid style
     pub fn line_style_1<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
         self.line_style_1 = Some(style.into());
         self
     }
 
-    /// Set the style for the fine grind grid
-    /// - `style`: The fine grind grid style
+    /// Set the style for the fine grid
+    /// - `style`: The fine grid style
     pub fn line_style_2<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
         self.line_style_2 = Some(style.into());
         self
     }
 
+    /// Set the style for the coarse grid lines of the X axis, i.e. the vertical lines,
+    /// which overrides `line_style_1`
+    /// - `style`: The coarse grid style of the X axis
+    pub fn x_line_style_1<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.x_line_style_1 = Some(style.into());
+        self
+    }
+
+    /// Set the style for the fine grid lines of the X axis, which overrides `line_style_2`
+    /// - `style`: The fine grid style of the X axis
+    pub fn x_line_style_2<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.x_line_style_2 = Some(style.into());
+        self
+    }
+
+    /// Set the style for the coarse grid lines of the Y axis, i.e. the horizontal lines,
+    /// which overrides `line_style_1`
+    /// - `style`: The coarse grid style of the Y axis
+    pub fn y_line_style_1<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.y_line_style_1 = Some(style.into());
+        self
+    }
+
+    /// Set the style for the fine grid lines of the Y axis, which overrides `line_style_2`
+    /// - `style`: The fine grid style of the Y axis
+    pub fn y_line_style_2<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
+        self.y_line_style_2 = Some(style.into());
+        self
+    }
+
+    /// Draw a drop shadow under the grid lines, which gives a sense of depth to the grid
+    /// - `offset`: The offset of the shadow in pixels
+    /// - `color`: The color of the shadow
+    /// - `blur`: The blur radius of the shadow in pixels, 0 means a hard shadow
+    pub fn grid_shadow<C: Color>(&mut self, offset: (i32, i32), color: &C, blur: u32) -> &mut Self {
+        self.grid_shadow = Some(Shadow::new((), offset, color).blur(blur));
+        self
+    }
+
     /// Set the style of the label text
     /// - `style`: The text style that would be applied to the labels
     pub fn label_style<T: Into<TextStyle<'b>>>(&mut self, style: T) -> &mut Self {
@@ -218,14 +481,71 @@ where
     /// Set the formatter function for the X label text
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
-        self.format_x = fmt;
+        self.format_x = Box::new(move |_: usize, v: &X::ValueType| fmt(v));
+        self
+    }
+
+    /// Set the formatter function for the X label text, which also takes the index of the tick
+    /// among all the ticks of the axis. For example, this can be used to label every 5th tick.
+    /// - `fmt`: The formatter function, which takes the index of the tick and the value
+    pub fn x_label_formatter_with_index(
+        &mut self,
+        fmt: &'b dyn Fn(usize, &X::ValueType) -> String,
+    ) -> &mut Self {
+        self.format_x = Box::new(fmt);
         self
     }
 
     /// Set the formatter function for the Y label text
     /// - `fmt`: The formatter function
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
-        self.format_y = fmt;
+        self.format_y = Box::new(move |_: usize, v: &Y::ValueType| fmt(v));
+        self
+    }
+
+    /// Set the formatter function for the Y label text, which also takes the index of the tick
+    /// among all the ticks of the axis. For example, this can be used to label every 5th tick.
+    /// - `fmt`: The formatter function, which takes the index of the tick and the value
+    pub fn y_label_formatter_with_index(
+        &mut self,
+        fmt: &'b dyn Fn(usize, &Y::ValueType) -> String,
+    ) -> &mut Self {
+        self.format_y = Box::new(fmt);
+        self
+    }
+
+    /// Set the colors of the X labels tick by tick, e.g. the negative values in red. The
+    /// labels without a color use the color of the label style.
+    /// - `color`: The function mapping the value of a tick to the color of its label
+    pub fn x_label_color<C: Color, F: Fn(&X::ValueType) -> Option<C> + 'b>(
+        &mut self,
+        color: F,
+    ) -> &mut Self {
+        self.x_label_color = Some(Box::new(move |x| color(x).map(|c| c.to_rgba())));
+        self
+    }
+
+    /// Set the colors of the Y labels tick by tick, see `x_label_color` for details
+    /// - `color`: The function mapping the value of a tick to the color of its label
+    pub fn y_label_color<C: Color, F: Fn(&Y::ValueType) -> Option<C> + 'b>(
+        &mut self,
+        color: F,
+    ) -> &mut Self {
+        self.y_label_color = Some(Box::new(move |y| color(y).map(|c| c.to_rgba())));
+        self
+    }
+
+    /// Show the Y labels as percentages, e.g. "25%". The axis built by
+    /// `ChartBuilder::build_percent_y` puts the labels on the round percentages, e.g. every 25%
+    /// with `y_labels(5)`.
+    /// - `fraction`: If the values are the fractions in `0.0..1.0`, which are scaled to `0..100`
+    pub fn y_percent_labels(&mut self, fraction: bool) -> &mut Self
+    where
+        Y::ValueType: Clone + Into<f64>,
+    {
+        let scale = if fraction { 100.0 } else { 1.0 };
+        self.format_y =
+            Box::new(move |_: usize, v: &Y::ValueType| format_percent(v.clone().into() * scale));
         self
     }
 
@@ -250,6 +570,158 @@ where
         self
     }
 
+    /// Set the alignment of the axis descriptions along the axis. By default the descriptions
+    /// are centered. For example, use `AxisDescAlignment::End` to place the units at the tip
+    /// of the axis.
+    /// - `alignment`: The alignment of the descriptions
+    pub fn axis_desc_alignment(&mut self, alignment: AxisDescAlignment) -> &mut Self {
+        self.axis_desc_alignment = alignment;
+        self
+    }
+
+    /// Set if the axis descriptions should be drawn inside the plotting area, next to the axis,
+    /// instead of the outer edge of the label area
+    /// - `inside`: If the descriptions should be drawn inside the plotting area
+    pub fn axis_desc_inside(&mut self, inside: bool) -> &mut Self {
+        self.axis_desc_inside = inside;
+        self
+    }
+
+    /// Draw an emphasized vertical line across the plotting area at the given X value.
+    /// The line is only drawn when the value is in the range of the X axis.
+    /// - `value`: The X value of the line
+    /// - `style`: The style of the line
+    pub fn x_value_line<S: Into<ShapeStyle>>(
+        &mut self,
+        value: X::ValueType,
+        style: S,
+    ) -> &mut Self {
+        self.x_value_lines.push((value, style.into()));
+        self
+    }
+
+    /// Draw an emphasized horizontal line across the plotting area at the given Y value.
+    /// The line is only drawn when the value is in the range of the Y axis.
+    /// - `value`: The Y value of the line
+    /// - `style`: The style of the line
+    pub fn y_value_line<S: Into<ShapeStyle>>(
+        &mut self,
+        value: Y::ValueType,
+        style: S,
+    ) -> &mut Self {
+        self.y_value_lines.push((value, style.into()));
+        self
+    }
+
+    /// Draw an emphasized horizontal line at Y = 0, which is useful when the data has both
+    /// negative and positive values
+    /// - `style`: The style of the line
+    pub fn zero_line<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self
+    where
+        Y::ValueType: Default,
+    {
+        self.y_value_line(Y::ValueType::default(), style)
+    }
+
+    /// Draw the axes through the given point inside the plotting area, so that they cross each
+    /// other, e.g. at the origin like the axes in a math textbook. The ticks and labels are
+    /// drawn along the crossing axes instead of in the label areas.
+    /// - `x`: The X value where the Y axis crosses the X axis
+    /// - `y`: The Y value where the X axis crosses the Y axis
+    pub fn axes_cross_at(&mut self, x: X::ValueType, y: Y::ValueType) -> &mut Self {
+        self.axis_origin = Some((x, y));
+        self
+    }
+
+    /// Terminate the positive ends of the crossing axes with arrowheads, which is the
+    /// conventional presentation of a math plot. This only applies to `axes_cross_at`.
+    pub fn axis_arrows(&mut self) -> &mut Self {
+        self.axis_arrows = true;
+        self
+    }
+
+    /// Set the gap between the crossing axes and their labels, so that the labels stay clear
+    /// of the data near the axes. This only applies to `axes_cross_at`.
+    /// - `gap`: The gap between the ticks and the labels in pixels, 2 by default
+    pub fn crossing_label_gap(&mut self, gap: i32) -> &mut Self {
+        self.crossing_label_gap = gap;
+        self
+    }
+
+    /// Draw the label of the origin of the crossing axes once, offset diagonally from the
+    /// origin, instead of omitting it since it would overlap both axes. This only applies to
+    /// `axes_cross_at`.
+    pub fn origin_label(&mut self) -> &mut Self {
+        self.origin_label = true;
+        self
+    }
+
+    /// Factor out the common power of ten of the X labels, so the labels only show the
+    /// mantissas and the shared exponent, such as `×10^6`, is drawn once near the end of the
+    /// axis. Nothing is factored out if the labels are neither large nor small.
+    pub fn x_exponent_offset(&mut self) -> &mut Self
+    where
+        X::ValueType: Clone + Into<f64>,
+    {
+        self.x_exponent_offset = Some(Box::new(|x: &X::ValueType| x.clone().into()));
+        self
+    }
+
+    /// Factor out the common power of ten of the Y labels, see `x_exponent_offset` for details
+    pub fn y_exponent_offset(&mut self) -> &mut Self
+    where
+        Y::ValueType: Clone + Into<f64>,
+    {
+        self.y_exponent_offset = Some(Box::new(|y: &Y::ValueType| y.clone().into()));
+        self
+    }
+
+    /// Only label the powers of ten on the X axis, the ticks within the decades of a log axis,
+    /// e.g. 2, 3, ..., 9, keep the grid lines but have no labels
+    pub fn x_decade_labels_only(&mut self) -> &mut Self
+    where
+        X::ValueType: Clone + Into<f64>,
+    {
+        self.x_decade_labels = Some(Box::new(|x: &X::ValueType| x.clone().into()));
+        self
+    }
+
+    /// Only label the powers of ten on the Y axis, see `x_decade_labels_only` for details
+    pub fn y_decade_labels_only(&mut self) -> &mut Self
+    where
+        Y::ValueType: Clone + Into<f64>,
+    {
+        self.y_decade_labels = Some(Box::new(|y: &Y::ValueType| y.clone().into()));
+        self
+    }
+
+    /// Only draw the fine grid lines between the first and the last labeled grid lines of each
+    /// axis, instead of extending them to the edges of the plotting area
+    pub fn minor_mesh_between_labels(&mut self) -> &mut Self {
+        self.minor_mesh_between_labels = true;
+        self
+    }
+
+    /// Only draw the mesh lines within the region where the mask is satisfied, this is useful
+    /// when the data only covers an irregular part of the plotting area. The axes and labels
+    /// are not affected.
+    /// - `mask`: The function that decides if the point (X, Y) is in the region
+    pub fn mesh_mask<F: Fn(&X::ValueType, &Y::ValueType) -> bool + 'b>(
+        &mut self,
+        mask: F,
+    ) -> &mut Self
+    where
+        X: ReversableRanged,
+        Y: ReversableRanged,
+    {
+        self.mesh_mask = Some(Box::new(move |coord, pos| {
+            coord
+                .reverse_translate(pos)
+                .map_or(false, |(x, y)| mask(&x, &y))
+        }));
+        self
+    }
+
     /// Draw the configured mesh on the target plot
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let mut target = None;
@@ -269,6 +741,14 @@ where
             .line_style_2
             .clone()
             .unwrap_or_else(|| (&default_mesh_color_2).into());
+        let (x_mesh_style_1, y_mesh_style_1) = (
+            self.x_line_style_1.as_ref().unwrap_or(&mesh_style_1),
+            self.y_line_style_1.as_ref().unwrap_or(&mesh_style_1),
+        );
+        let (x_mesh_style_2, y_mesh_style_2) = (
+            self.x_line_style_2.as_ref().unwrap_or(&mesh_style_2),
+            self.y_line_style_2.as_ref().unwrap_or(&mesh_style_2),
+        );
         let axis_style = self
             .axis_style
             .clone()
@@ -284,30 +764,133 @@ where
             .clone()
             .unwrap_or_else(|| label_style.clone());
 
+        let crossing_axes = self.axis_origin.as_ref().map(|(x, y)| CrossingAxes {
+            origin: (target.drawing_area.map_x(x), target.drawing_area.map_y(y)),
+            arrows: self.axis_arrows,
+            label_gap: self.crossing_label_gap,
+            origin_label: self.origin_label,
+        });
+
+        let mesh_mask = self
+            .mesh_mask
+            .as_ref()
+            .map(|mask| mask.as_ref() as &dyn Fn(&RangedCoord<X, Y>, BackendCoord) -> bool);
+
+        // Find the labeled grid lines and the shared exponents over all the key points of the axes
+        let (mut x_values, mut y_values) = (vec![], vec![]);
+        let (mut x_pixels, mut y_pixels) = (vec![], vec![]);
+        target
+            .drawing_area
+            .as_coord_spec()
+            .draw_mesh(self.n_y_labels, self.n_x_labels, |m| {
+                match m {
+                    MeshLine::XMesh((x, _), _, v) => {
+                        x_pixels.push(x);
+                        if let Some(ref to_f64) = self.x_exponent_offset {
+                            x_values.push(to_f64(v));
+                        }
+                    }
+                    MeshLine::YMesh((_, y), _, v) => {
+                        y_pixels.push(y);
+                        if let Some(ref to_f64) = self.y_exponent_offset {
+                            y_values.push(to_f64(v));
+                        }
+                    }
+                }
+                Ok::<(), ()>(())
+            })
+            .ok();
+        if let Some(ticks) = &self.x_aligned_ticks {
+            x_pixels = ticks.iter().map(|(x, _)| *x).collect();
+            if let Some(to_f64) = &self.x_exponent_offset {
+                x_values = ticks.iter().map(|(_, v)| to_f64(v)).collect();
+            }
+        }
+        if let Some(ticks) = &self.y_aligned_ticks {
+            y_pixels = ticks.iter().map(|(y, _)| *y).collect();
+            if let Some(to_f64) = &self.y_exponent_offset {
+                y_values = ticks.iter().map(|(_, v)| to_f64(v)).collect();
+            }
+        }
+
+        let minor_span = if self.minor_mesh_between_labels {
+            Some((pixel_span(&x_pixels), pixel_span(&y_pixels)))
+        } else {
+            None
+        };
+        let minor_mask = |coord: &RangedCoord<X, Y>, p: BackendCoord| {
+            let within =
+                |span: Option<(i32, i32)>, v: i32| span.map_or(true, |(a, b)| a <= v && v <= b);
+            let (x_span, y_span) = minor_span.unwrap_or((None, None));
+            within(x_span, p.0)
+                && within(y_span, p.1)
+                && mesh_mask.map_or(true, |mask| mask(coord, p))
+        };
+        let minor_mesh_mask = if minor_span.is_some() {
+            Some(&minor_mask as &dyn Fn(&RangedCoord<X, Y>, BackendCoord) -> bool)
+        } else {
+            mesh_mask
+        };
+
         target.draw_mesh(
             (self.n_y_labels * 10, self.n_x_labels * 10),
-            &mesh_style_2,
+            (x_mesh_style_2, y_mesh_style_2),
             &label_style,
-            |_| None,
+            |_, _| None,
             self.draw_x_mesh,
             self.draw_y_mesh,
             self.x_label_offset,
             self.y_label_offset,
-            false,
-            false,
+            [false, false],
+            [false, false],
             &axis_style,
             &axis_desc_style,
             self.x_desc.clone(),
             self.y_desc.clone(),
+            (self.axis_desc_alignment, self.axis_desc_inside),
+            minor_mesh_mask,
+            self.grid_shadow.as_ref(),
+            (
+                self.x_aligned_ticks.as_ref().map(|ticks| &ticks[..]),
+                self.y_aligned_ticks.as_ref().map(|ticks| &ticks[..]),
+            ),
+            (self.x_label_padding, self.y_label_padding),
+            self.tick_lengths,
+            self.label_overflow,
+            None,
         )?;
 
+        let x_exponent = common_exponent(&x_values);
+        let y_exponent = common_exponent(&y_values);
+
+        let format_x = |idx: usize, v: &X::ValueType| match (x_exponent, &self.x_exponent_offset) {
+            (Some((exp, digits)), Some(to_f64)) => format_mantissa(to_f64(v), exp, digits),
+            _ => (self.format_x)(idx, v),
+        };
+        let format_y = |idx: usize, v: &Y::ValueType| match (y_exponent, &self.y_exponent_offset) {
+            (Some((exp, digits)), Some(to_f64)) => format_mantissa(to_f64(v), exp, digits),
+            _ => (self.format_y)(idx, v),
+        };
+
         target.draw_mesh(
             (self.n_y_labels, self.n_x_labels),
-            &mesh_style_1,
+            (x_mesh_style_1, y_mesh_style_1),
             &label_style,
-            |m| match m {
-                MeshLine::XMesh(_, _, v) => Some((self.format_x)(v)),
-                MeshLine::YMesh(_, _, v) => Some((self.format_y)(v)),
+            |idx, m| match m {
+                MeshLine::XMesh(_, _, v) => match &self.x_decade_labels {
+                    Some(to_f64) if !is_decade(to_f64(v)) => None,
+                    _ => Some((
+                        format_x(idx, v),
+                        self.x_label_color.as_ref().and_then(|color| color(v)),
+                    )),
+                },
+                MeshLine::YMesh(_, _, v) => match &self.y_decade_labels {
+                    Some(to_f64) if !is_decade(to_f64(v)) => None,
+                    _ => Some((
+                        format_y(idx, v),
+                        self.y_label_color.as_ref().and_then(|color| color(v)),
+                    )),
+                },
             },
             self.draw_x_mesh,
             self.draw_y_mesh,
@@ -319,6 +902,796 @@ where
             &axis_desc_style,
             None,
             None,
-        )
+            (self.axis_desc_alignment, self.axis_desc_inside),
+            mesh_mask,
+            self.grid_shadow.as_ref(),
+            (
+                self.x_aligned_ticks.as_ref().map(|ticks| &ticks[..]),
+                self.y_aligned_ticks.as_ref().map(|ticks| &ticks[..]),
+            ),
+            (self.x_label_padding, self.y_label_padding),
+            self.tick_lengths,
+            self.label_overflow,
+            crossing_axes,
+        )?;
+
+        // The X offset goes to the bottom-right corner and the Y offset goes to the top-left
+        // corner of the plotting area, next to the end of the axis
+        let plotting_area = target.drawing_area.strip_coord_spec();
+        let (w, h) = plotting_area.dim_in_pixel();
+        if let Some((exp, _)) = x_exponent {
+            let text = format!("×10^{}", exp);
+            let (tw, th) = label_style.font.box_size(&text).unwrap_or((0, 0));
+            plotting_area.draw_text(
+                &text,
+                &label_style,
+                (w as i32 - tw as i32 - 2, h as i32 - th as i32 - 2),
+            )?;
+        }
+        if let Some((exp, _)) = y_exponent {
+            plotting_area.draw_text(&format!("×10^{}", exp), &label_style, (2, 2))?;
+        }
+
+        target.draw_value_lines(&self.x_value_lines[..], &self.y_value_lines[..])
+    }
+}
+
+/// Find the pixel span covered by the labeled grid lines of an axis
+/// - `pixels`: The pixel positions of the labeled grid lines
+/// - **returns**: The smallest and the largest pixel, `None` if there's no labeled grid line
+fn pixel_span(pixels: &[i32]) -> Option<(i32, i32)> {
+    let min = pixels.iter().min()?;
+    let max = pixels.iter().max()?;
+    Some((*min, *max))
+}
+
+/// Find the common power of ten of the values, which is factored out only if the values are
+/// large or small enough, i.e. the exponent is at least 4 or at most -3
+/// - `values`: The values of the labels
+/// - **returns**: The exponent and the number of the fraction digits needed by the mantissas
+fn common_exponent(values: &[f64]) -> Option<(i32, usize)> {
+    let largest = values
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold(0.0f64, |acc, v| acc.max(v.abs()));
+    if largest == 0.0 {
+        return None;
+    }
+
+    let exp = largest.log10().floor() as i32;
+    if exp < 4 && exp > -3 {
+        return None;
+    }
+
+    // Use the fewest fraction digits that represent all the mantissas
+    let scale = 10f64.powi(exp);
+    let digits = (0..6)
+        .find(|digits| {
+            values.iter().all(|v| {
+                let m = v / scale * 10f64.powi(*digits as i32);
+                (m - m.round()).abs() < 1e-6
+            })
+        })
+        .unwrap_or(6);
+
+    Some((exp, digits))
+}
+
+/// Check if the value is a power of ten
+fn is_decade(value: f64) -> bool {
+    let exp = value.log10();
+    value > 0.0 && (exp - exp.round()).abs() < 1e-9
+}
+
+/// Format the percentage without the trailing zeros, e.g. "25%" or "12.5%"
+fn format_percent(value: f64) -> String {
+    format!("{}%", (value * 1e6).round() / 1e6)
+}
+
+/// Format the mantissa of the value with the given exponent factored out
+fn format_mantissa(value: f64, exp: i32, digits: usize) -> String {
+    format!("{:.*}", digits, value / 10f64.powi(exp))
+}
+
+#[cfg(test)]
+#[test]
+fn test_common_exponent() {
+    let values: Vec<f64> = (0..5).map(|x| f64::from(x) * 2.5e6).collect();
+    assert_eq!(common_exponent(&values), Some((7, 2)));
+    assert_eq!(format_mantissa(2.5e6, 7, 2), "0.25");
+    assert_eq!(common_exponent(&[0.0, 50.0, 100.0]), None);
+    assert_eq!(common_exponent(&[0.001, 0.002]), Some((-3, 0)));
+}
+
+#[cfg(test)]
+#[test]
+fn test_is_decade() {
+    assert!(is_decade(1.0));
+    assert!(is_decade(1000.0));
+    assert!(is_decade(0.01));
+    assert!(!is_decade(20.0));
+    assert!(!is_decade(0.0));
+}
+
+#[cfg(test)]
+#[test]
+fn test_format_percent() {
+    assert_eq!(format_percent(25.0), "25%");
+    assert_eq!(format_percent(0.125 * 100.0), "12.5%");
+    assert_eq!(format_percent(0.07 * 100.0), "7%");
+}
+
+#[cfg(test)]
+#[test]
+fn test_pixel_span() {
+    assert_eq!(pixel_span(&[]), None);
+    assert_eq!(pixel_span(&[80, 20, 50]), Some((20, 80)));
+}
+
+#[cfg(test)]
+#[test]
+fn test_secondary_axes_aligned() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |_| {});
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error")
+        .set_secondary_coord(0.0..10.0, 0.0..100.0);
+
+    let mut primary_ticks = vec![];
+    chart
+        .plotting_area()
+        .as_coord_spec()
+        .draw_mesh(10, 1, |line| {
+            if let MeshLine::YMesh((_, y), _, _) = line {
+                primary_ticks.push(y);
+            }
+            Ok::<(), ()>(())
+        })
+        .ok();
+
+    let style = chart.configure_secondary_axes_aligned(10);
+    let ticks = style.style.y_aligned_ticks.as_ref().unwrap();
+    let pixels: Vec<_> = ticks.iter().map(|(y, _)| *y).collect();
+    let values: Vec<_> = ticks
+        .iter()
+        .map(|(_, v): &(i32, f64)| v.round() as i32)
+        .collect();
+    assert_eq!(pixels, primary_ticks);
+    assert_eq!(values, (1..=10).map(|v| v * 10).collect::<Vec<_>>());
+}
+
+#[cfg(test)]
+#[test]
+fn test_secondary_axes_aligned_x() {
+    use crate::prelude::*;
+    let drawing_area = crate::create_mocked_drawing_area(100, 100, |_| {});
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error")
+        .set_secondary_coord(0.0..1000.0, 0.0..10.0);
+
+    let mut primary_ticks = vec![];
+    chart
+        .plotting_area()
+        .as_coord_spec()
+        .draw_mesh(1, 10, |line| {
+            if let MeshLine::XMesh((x, _), _, _) = line {
+                primary_ticks.push(x);
+            }
+            Ok::<(), ()>(())
+        })
+        .ok();
+
+    let style = chart.configure_secondary_axes_aligned_x(10);
+    assert!(style.style.y_aligned_ticks.is_none());
+    let ticks = style.style.x_aligned_ticks.as_ref().unwrap();
+    let pixels: Vec<_> = ticks.iter().map(|(x, _)| *x).collect();
+    let values: Vec<_> = ticks
+        .iter()
+        .map(|(_, v): &(i32, f64)| v.round() as i32)
+        .collect();
+    assert_eq!(pixels, primary_ticks);
+    assert_eq!(values, (1..=10).map(|v| v * 100).collect::<Vec<_>>());
+}
+
+#[cfg(test)]
+#[test]
+fn test_axis_sides() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(10)
+        .top_x_label_area_size(10)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_y_mesh()
+        .x_axis_sides(false, true)
+        .draw()
+        .expect("Drawing Failure");
+
+    let axis_lines: Vec<_> = calls
+        .paths()
+        .into_iter()
+        .map(|(_, path)| path)
+        .filter(|p| p.len() == 2 && p[0].1 == p[1].1 && (p[0].0 - p[1].0).abs() > 50)
+        .map(|p| p[0].1)
+        .collect();
+    assert_eq!(axis_lines, vec![90]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_tick_lengths() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(20)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_y_mesh()
+        .tick_lengths(3, 4)
+        .draw()
+        .expect("Drawing Failure");
+
+    let ticks: Vec<_> = calls
+        .paths()
+        .into_iter()
+        .map(|(_, path)| path)
+        .filter(|p| p.len() == 2 && p[0].0 == p[1].0 && (p[0].1 - p[1].1).abs() < 10)
+        .map(|p| (p[0].1, p[1].1))
+        .collect();
+    assert!(!ticks.is_empty());
+    assert!(ticks.iter().all(|t| *t == (80, 84) || *t == (77, 80)));
+    assert!(ticks.contains(&(77, 80)));
+}
+
+#[cfg(test)]
+#[test]
+fn test_label_color() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .y_label_area_size(20)
+        .build_ranged(0.0..10.0, -10.0..10.0)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .y_label_color(|y: &f64| if *y < 0.0 { Some(RED) } else { None })
+        .draw()
+        .expect("Drawing Failure");
+
+    let texts = calls.texts();
+    assert!(texts.iter().any(|(_, t, _)| t.starts_with('-')));
+    for (color, text, _) in texts.iter() {
+        let expected = if text.starts_with('-') { RED } else { BLACK };
+        assert_eq!(*color, expected.to_rgba());
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_grid_shadow() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .grid_shadow((2, 0), &BLUE, 0)
+        .draw()
+        .expect("Drawing Failure");
+
+    let lines = calls.lines();
+    let (shadows, grid): (Vec<_>, Vec<_>) =
+        lines.iter().partition(|(c, ..)| c.rgb() == (0, 0, 255));
+    assert!(!grid.is_empty());
+    assert_eq!(shadows.len(), grid.len());
+    // Each shadow is drawn right before its grid line, with the opacity of the line
+    for pair in lines.chunks(2) {
+        let ((shadow, s0, s1), (line, l0, l1)) = (&pair[0], &pair[1]);
+        assert_eq!(shadow.rgb(), (0, 0, 255));
+        assert_eq!(shadow.alpha(), line.alpha());
+        assert_eq!((*s0, *s1), ((l0.0 + 2, l0.1), (l1.0 + 2, l1.1)));
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_secondary_top_x_axis() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(20)
+        .top_x_label_area_size(20)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error")
+        .set_secondary_coord(0.0..1000.0, 0.0..10.0);
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_y_mesh()
+        .x_label_formatter(&|x| format!("P{}", x))
+        .draw()
+        .expect("Drawing Failure");
+    chart
+        .configure_secondary_axes()
+        .x_labels(5)
+        .x_label_formatter(&|x| format!("S{}", x))
+        .draw()
+        .expect("Drawing Failure");
+
+    // The secondary X axis has its own key points and labels in the top label area, while the
+    // primary labels stay in the bottom label area
+    let texts = calls.texts();
+    let secondary: Vec<_> = texts
+        .iter()
+        .filter(|(_, t, _)| t.starts_with('S'))
+        .collect();
+    let primary: Vec<_> = texts
+        .iter()
+        .filter(|(_, t, _)| t.starts_with('P'))
+        .collect();
+    let labels: Vec<_> = secondary.iter().map(|(_, t, _)| t.as_str()).collect();
+    assert_eq!(labels, vec!["S200", "S400", "S600", "S800"]);
+    assert!(secondary.iter().all(|(_, _, (_, y))| *y < 20));
+    assert!(!primary.is_empty());
+    assert!(primary.iter().all(|(_, _, (_, y))| *y >= 80));
+    // The top axis and its ticks are at the secondary key points, along the plotting area
+    let paths = calls.paths();
+    assert!(paths.contains(&(BLACK.to_rgba(), vec![(0, 20), (100, 20)])));
+    let ticks: Vec<_> = paths
+        .iter()
+        .filter(|(_, p)| p[0].1 == 15 && p[1].1 == 20)
+        .map(|(_, p)| p[0].0)
+        .collect();
+    assert_eq!(ticks, vec![20, 40, 60, 80]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_per_axis_line_styles() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .line_style_2(&WHITE)
+        .x_line_style_1(&RED)
+        .y_line_style_1(&BLUE)
+        .y_line_style_2(&GREEN)
+        .draw()
+        .expect("Drawing Failure");
+
+    // The vertical lines are the X grid, the horizontal lines are the Y grid, and the styles
+    // which aren't set per axis fall back to the shared ones
+    let lines = calls.lines();
+    let vertical: Vec<_> = lines.iter().filter(|(_, f, t)| f.0 == t.0).collect();
+    let horizontal: Vec<_> = lines.iter().filter(|(_, f, t)| f.1 == t.1).collect();
+    assert_eq!(vertical.len() + horizontal.len(), lines.len());
+    let colors = |lines: &[&(RGBAColor, BackendCoord, BackendCoord)]| {
+        let mut colors: Vec<_> = lines.iter().map(|(c, ..)| c.rgb()).collect();
+        colors.sort();
+        colors.dedup();
+        colors
+    };
+    assert_eq!(colors(&vertical), vec![(255, 0, 0), (255, 255, 255)]);
+    assert_eq!(colors(&horizontal), vec![(0, 0, 255), (0, 255, 0)]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_crossing_axis_arrows() {
+    use crate::prelude::*;
+
+    let draw = |origin: (i32, i32)| {
+        let mut backend = MockedBackend::new(100, 100);
+        let calls = backend.record_calls();
+        let drawing_area = backend.into_drawing_area();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(-10..10, -10..10)
+            .expect("Build chart error");
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .disable_y_mesh()
+            .axes_cross_at(origin.0, origin.1)
+            .axis_arrows()
+            .origin_label()
+            .draw()
+            .expect("Drawing Failure");
+        (chart.backend_coord(&origin), calls)
+    };
+
+    // The arrowheads are at the positive ends of the axes, i.e. the right and the top edges
+    let ((ox, oy), calls) = draw((0, 0));
+    assert_eq!((ox, oy), (50, 50));
+    let paths: Vec<_> = calls.paths().into_iter().map(|(_, p)| p).collect();
+    assert!(paths.contains(&vec![(0, 50), (100, 50)]));
+    assert!(paths.contains(&vec![(90, 45), (100, 50), (90, 55)]));
+    assert!(paths.contains(&vec![(50, 100), (50, 0)]));
+    assert!(paths.contains(&vec![(45, 10), (50, 0), (55, 10)]));
+
+    // The origin is labeled once, in the corner below the X axis and left of the Y axis,
+    // instead of once for each axis
+    let texts = calls.texts();
+    let origin: Vec<_> = texts.iter().filter(|(_, t, _)| t == "0").collect();
+    assert_eq!(origin.len(), 1);
+    let (_, _, (x, y)) = origin[0];
+    assert!(*x < ox - 2 && *y == oy + 2);
+    // The other labels are offset from the axes by the ticks and the gap
+    let x_labels: Vec<_> = texts.iter().filter(|(_, _, (_, y))| *y == oy + 7).collect();
+    assert_eq!(x_labels.len(), 4);
+
+    // When the origin is in the bottom left corner, the labels don't fit below the X axis or
+    // left of the Y axis, so they move to the inner side of the axes
+    let ((ox, oy), calls) = draw((-10, -10));
+    assert_eq!((ox, oy), (0, 100));
+    let texts = calls.texts();
+    assert!(!texts.is_empty());
+    assert!(texts.iter().all(|(_, _, (x, y))| *x >= ox && *y < oy));
+    assert_eq!(texts.iter().filter(|(_, t, _)| t == "-10").count(), 1);
+}
+
+#[cfg(test)]
+#[test]
+fn test_label_padding() {
+    use crate::prelude::*;
+
+    let draw = |x_padding: Option<(i32, i32)>, y_padding: Option<(i32, i32)>| {
+        let mut backend = MockedBackend::new(100, 100);
+        let calls = backend.record_calls();
+        let drawing_area = backend.into_drawing_area();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(30)
+            .y_label_area_size(30)
+            .build_ranged(0..10, 0..10)
+            .expect("Build chart error");
+        let mut mesh = chart.configure_mesh();
+        mesh.disable_x_mesh().disable_y_mesh();
+        if let Some((inner, outer)) = x_padding {
+            mesh.x_label_padding(inner, outer);
+        }
+        if let Some((inner, outer)) = y_padding {
+            mesh.y_label_padding(inner, outer);
+        }
+        mesh.draw().expect("Drawing Failure");
+        // The Y labels are in the left label area, the X labels are in the bottom one
+        let (y_labels, x_labels): (Vec<_>, Vec<_>) = calls
+            .texts()
+            .into_iter()
+            .map(|(_, _, pos)| pos)
+            .partition(|(x, y)| *x < 30 && *y < 70);
+        (x_labels, y_labels)
+    };
+    let shift = |from: &[BackendCoord], to: &[BackendCoord]| {
+        assert_eq!(from.len(), to.len());
+        let mut shift: Vec<_> = from
+            .iter()
+            .zip(to.iter())
+            .map(|(a, b)| (b.0 - a.0, b.1 - a.1))
+            .collect();
+        shift.dedup();
+        shift
+    };
+
+    // By default, the X labels touch the axis and the Y labels are 10 pixels away from it, the
+    // inner padding replaces these gaps
+    let (x_default, y_default) = draw(None, None);
+    assert!(!x_default.is_empty() && !y_default.is_empty());
+    let (x_padded, y_padded) = draw(Some((4, 0)), Some((3, 0)));
+    assert_eq!(shift(&x_default, &x_padded), vec![(0, 4)]);
+    assert_eq!(shift(&y_default, &y_padded), vec![(7, 0)]);
+
+    // The outer padding keeps the labels away from the outer edge of the label areas, so the
+    // labels are moved back toward the axes
+    let (x_outer, y_outer) = draw(Some((4, 15)), Some((12, 15)));
+    assert!(x_outer.iter().zip(x_padded.iter()).all(|(a, b)| a.1 < b.1));
+    assert!(y_outer.iter().all(|(x, _)| *x == 15));
+}
+
+#[cfg(test)]
+#[test]
+fn test_axis_desc_alignment() {
+    use crate::prelude::*;
+
+    let draw = |alignment: AxisDescAlignment, inside: bool| {
+        let mut backend = MockedBackend::new(100, 100);
+        let calls = backend.record_calls();
+        let drawing_area = backend.into_drawing_area();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(30)
+            .y_label_area_size(30)
+            .build_ranged(0..10, 0..10)
+            .expect("Build chart error");
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .disable_y_mesh()
+            .x_desc("Time")
+            .y_desc("Value")
+            .axis_desc_alignment(alignment)
+            .axis_desc_inside(inside)
+            .draw()
+            .expect("Drawing Failure");
+        let texts = calls.texts();
+        let find = |desc: &str| {
+            texts
+                .iter()
+                .find(|(_, t, _)| t == desc)
+                .map(|(_, _, pos)| *pos)
+                .expect("No axis description")
+        };
+        (find("Time"), find("Value"))
+    };
+
+    // The plotting area spans 30..100 horizontally and 0..70 vertically. The X description
+    // goes from the left end to the right end of the axis, and the Y description, which is
+    // rotated so its height is the length of the text, goes from the bottom to the top.
+    let (x_start, y_start) = draw(AxisDescAlignment::Start, false);
+    let (x_center, y_center) = draw(AxisDescAlignment::Center, false);
+    let (x_end, y_end) = draw(AxisDescAlignment::End, false);
+    let (x_w, y_h) = (100 - x_end.0, 70 - y_start.1);
+    assert!(x_w > 0 && y_h > 0);
+    assert_eq!((x_start.0, x_center.0), (30, 30 + (70 - x_w) / 2));
+    assert_eq!((y_center.1, y_end.1), ((70 - y_h) / 2, 0));
+
+    // Outside of the plotting area, the descriptions are at the outer edge of the label areas
+    let x_h = 100 - x_end.1;
+    assert!(x_h > 0 && x_h < 30);
+    assert_eq!((x_start.1, x_center.1), (x_end.1, x_end.1));
+    assert_eq!((y_start.0, y_center.0, y_end.0), (0, 0, 0));
+
+    // Inside of the plotting area, the descriptions are next to the axes
+    let (x_inside, y_inside) = draw(AxisDescAlignment::End, true);
+    assert_eq!(x_inside, (x_end.0, 70 - x_h - 5));
+    assert_eq!(y_inside, (35, 0));
+}
+
+#[cfg(test)]
+#[test]
+fn test_value_lines() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .y_label_area_size(20)
+        .build_ranged(0..10, -10..10)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_y_mesh()
+        .zero_line(&RED)
+        .y_value_line(5, &GREEN)
+        .x_value_line(4, &BLUE)
+        .x_value_line(20, &BLUE)
+        .draw()
+        .expect("Drawing Failure");
+
+    // The lines go across the plotting area, which is right of the label area, and the line
+    // out of the range of the X axis is skipped
+    let (x, y) = chart.backend_coord(&(4, 0));
+    let (_, y5) = chart.backend_coord(&(0, 5));
+    let paths = calls.paths();
+    assert!(paths.contains(&(RED.to_rgba(), vec![(20, y), (100, y)])));
+    assert!(paths.contains(&(GREEN.to_rgba(), vec![(20, y5), (100, y5)])));
+    let blue: Vec<_> = paths.iter().filter(|(c, _)| *c == BLUE.to_rgba()).collect();
+    assert_eq!(blue, vec![&(BLUE.to_rgba(), vec![(x, 100), (x, 0)])]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_mesh_mask() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .x_labels(5)
+        .y_labels(5)
+        .mesh_mask(|x, y| x + y <= 10.0)
+        .draw()
+        .expect("Drawing Failure");
+
+    // Only the parts of the grid lines in the lower left triangle are drawn, so each line
+    // stops at the diagonal instead of going across the plotting area
+    let to_coord = chart.into_coord_trans();
+    let sum = |p: BackendCoord| -> f64 {
+        let (x, y) = to_coord(p).expect("Out of the plotting area");
+        x + y
+    };
+    let lines = calls.lines();
+    assert!(!lines.is_empty());
+    for (_, from, to) in lines {
+        assert!(sum(from) <= 10.0 + 0.2 && sum(to) <= 10.0 + 0.2);
+        assert!(sum(from).max(sum(to)) >= 10.0 - 0.2);
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_axes_cross_at() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(20)
+        .y_label_area_size(20)
+        .build_ranged(0..8, -4..4)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_y_mesh()
+        .x_labels(5)
+        .y_labels(5)
+        .axes_cross_at(2, -2)
+        .draw()
+        .expect("Drawing Failure");
+
+    // The axes go through the point instead of along the label areas, and the ticks are on
+    // the axes at the key points
+    assert_eq!(chart.backend_coord(&(2, -2)), (40, 60));
+    let paths: Vec<_> = calls.paths().into_iter().map(|(_, p)| p).collect();
+    let mut expected = vec![vec![(20, 60), (100, 60)]];
+    expected.extend((0..5).map(|i| vec![(20 + i * 20, 60), (20 + i * 20, 65)]));
+    expected.push(vec![(40, 80), (40, 0)]);
+    expected.extend((0..5).map(|i| vec![(35, 80 - i * 20), (40, 80 - i * 20)]));
+    assert_eq!(paths, expected);
+
+    // The labels follow the axes into the plotting area
+    let texts = calls.texts();
+    assert!(!texts.is_empty());
+    assert!(texts.iter().all(|(_, _, (_, y))| *y < 80));
+}
+
+#[cfg(test)]
+#[test]
+fn test_minor_mesh_between_labels() {
+    use crate::prelude::*;
+
+    let draw = |between_labels: bool| {
+        let mut backend = MockedBackend::new(100, 100);
+        let calls = backend.record_calls();
+        let drawing_area = backend.into_drawing_area();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0.5..9.5, 0.5..9.5)
+            .expect("Build chart error");
+        let mut mesh = chart.configure_mesh();
+        mesh.x_labels(5)
+            .y_labels(5)
+            .line_style_1(&RED)
+            .line_style_2(&BLUE);
+        if between_labels {
+            mesh.minor_mesh_between_labels();
+        }
+        mesh.draw().expect("Drawing Failure");
+        calls.lines()
+    };
+    // The span of the positions of the vertical and the horizontal grid lines of the color,
+    // and the span of the pixels the lines cover on the other direction
+    let extent = |lines: &[(RGBAColor, BackendCoord, BackendCoord)], color: &RGBColor| {
+        let color = color.to_rgba();
+        let span =
+            |values: Vec<i32>| (*values.iter().min().unwrap(), *values.iter().max().unwrap());
+        let lines: Vec<_> = lines.iter().filter(|(c, ..)| *c == color).collect();
+        let vertical = lines.iter().filter(|(_, from, to)| from.0 == to.0);
+        let horizontal = lines.iter().filter(|(_, from, to)| from.1 == to.1);
+        (
+            span(vertical.clone().map(|(_, from, _)| from.0).collect()),
+            span(horizontal.clone().map(|(_, from, _)| from.1).collect()),
+            span(
+                vertical
+                    .flat_map(|(_, from, to)| vec![from.1, to.1])
+                    .collect(),
+            ),
+            span(
+                horizontal
+                    .flat_map(|(_, from, to)| vec![from.0, to.0])
+                    .collect(),
+            ),
+        )
+    };
+
+    // By default, the fine grid goes over the whole plotting area
+    let lines = draw(false);
+    let (x_labeled, y_labeled, ..) = extent(&lines, &RED);
+    assert_eq!(x_labeled, (16, 83));
+    assert_eq!(y_labeled, (17, 84));
+    let (x_fine, y_fine, x_fine_length, y_fine_length) = extent(&lines, &BLUE);
+    assert!(x_fine.0 < 16 && x_fine.1 > 83);
+    assert!(y_fine.0 < 17 && y_fine.1 > 84);
+    assert_eq!((x_fine_length, y_fine_length), ((0, 100), (0, 100)));
+
+    // Otherwise, it stays between the first and the last labeled grid lines on both directions
+    let lines = draw(true);
+    assert_eq!(extent(&lines, &RED).0, x_labeled);
+    assert_eq!(
+        extent(&lines, &BLUE),
+        (x_labeled, y_labeled, y_labeled, x_labeled)
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_label_formatter_with_index() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .x_label_area_size(20)
+        .y_label_area_size(20)
+        .build_ranged(0..10, 0..10)
+        .expect("Build chart error");
+    chart
+        .configure_mesh()
+        .x_labels(10)
+        .y_labels(10)
+        .x_label_formatter_with_index(&|i, x| {
+            if i % 2 == 0 {
+                format!("x{}", x)
+            } else {
+                String::new()
+            }
+        })
+        .y_label_formatter_with_index(&|i, y| {
+            if i % 2 == 1 {
+                format!("y{}", y)
+            } else {
+                String::new()
+            }
+        })
+        .draw()
+        .expect("Drawing Failure");
+
+    // Both axes have the ticks 0, 1, ..., 10, the X axis labels the even ones and the Y axis
+    // labels the odd ones, the other ticks get an empty label. The X label of 10 at the right
+    // end doesn't fit into the label area, so it isn't drawn.
+    let texts: Vec<_> = calls.texts().into_iter().map(|(_, t, _)| t).collect();
+    assert_eq!(texts.len(), 21);
+    let labels: Vec<_> = texts.iter().filter(|t| !t.is_empty()).collect();
+    assert_eq!(
+        labels,
+        vec!["y1", "y3", "y5", "y7", "y9", "x0", "x2", "x4", "x6", "x8"]
+    );
+}
@@ -16,10 +16,14 @@ mod builder;
 mod context;
 mod dual_coord;
 mod mesh;
+mod render_plan;
 mod series;
+mod sparkline;
 
-pub use builder::{ChartBuilder, LabelAreaPosition};
-pub use context::{ChartContext, SeriesAnno};
+pub use builder::{AxisScale, ChartBuilder, LabelAreaPosition};
+pub use context::{ChartContext, MarkerLabelPosition, SeriesAnno, SeriesClip, SharedX};
 pub use dual_coord::DualCoordChartContext;
-pub use mesh::MeshStyle;
+pub use mesh::{AxisDescAlignment, LabelOverflow, MeshStyle};
+pub use render_plan::{RenderLayer, RenderPlan};
 pub use series::{SeriesLabelPosition, SeriesLabelStyle};
+pub use sparkline::Sparkline;
@@ -0,0 +1,126 @@
+use super::ChartContext;
+use crate::coord::CoordTranslate;
+use crate::drawing::{DrawingAreaErrorKind, DrawingBackend};
+
+/// The common layers of a chart, which give the default orders for `RenderPlan::layer`. The
+/// orders are 100 apart, so that custom layers can be put in between with a plain number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RenderLayer {
+    /// The grid lines, drawn first by default
+    Mesh,
+    /// The data series
+    Series,
+    /// The axes and the labels
+    Axes,
+    /// The annotations on top of everything, e.g. the markers and the legend
+    Annotations,
+}
+
+impl From<RenderLayer> for i32 {
+    fn from(layer: RenderLayer) -> i32 {
+        match layer {
+            RenderLayer::Mesh => 100,
+            RenderLayer::Series => 200,
+            RenderLayer::Axes => 300,
+            RenderLayer::Annotations => 400,
+        }
+    }
+}
+
+type LayerFn<'a, 'b, DB, CT> = Box<
+    dyn FnOnce(
+            &mut ChartContext<'a, DB, CT>,
+        ) -> Result<(), DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>
+        + 'b,
+>;
+
+/// The plan of the layers of a chart, which draws the registered layers from the lowest order
+/// to the highest when it's rendered, rather than in the order of the calls. Layers with the
+/// same order are drawn in the order they are registered.
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0.0..10.0, 0.0..10.0)?;
+/// let data = (0..10).map(|x| (x as f64, x as f64));
+/// // The background is registered after the series, but it's drawn below them
+/// chart
+///     .render_plan()
+///     .layer(RenderLayer::Series, |c| c.draw_series(LineSeries::new(data, &RED)).map(|_| ()))
+///     .layer(150, |c| c.plotting_area().fill(&WHITE))
+///     .render()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RenderPlan<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
+    target: &'b mut ChartContext<'a, DB, CT>,
+    layers: Vec<(i32, LayerFn<'a, 'b, DB, CT>)>,
+}
+
+impl<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> RenderPlan<'a, 'b, DB, CT> {
+    pub(super) fn new(target: &'b mut ChartContext<'a, DB, CT>) -> Self {
+        Self {
+            target,
+            layers: vec![],
+        }
+    }
+
+    /// Register a layer of the chart
+    /// - `order`: The order of the layer, either a `RenderLayer` or a number, the layers with
+    ///   the lower orders are drawn below the ones with the higher orders
+    /// - `draw`: The function drawing the layer on the chart
+    pub fn layer<O, F>(&mut self, order: O, draw: F) -> &mut Self
+    where
+        O: Into<i32>,
+        F: FnOnce(&mut ChartContext<'a, DB, CT>) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+            + 'b,
+    {
+        self.layers.push((order.into(), Box::new(draw)));
+        self
+    }
+
+    /// Draw all the registered layers by their orders, the plan is empty afterwards
+    pub fn render(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut layers = std::mem::take(&mut self.layers);
+        layers.sort_by_key(|(order, _)| *order);
+        for (_, draw) in layers {
+            draw(&mut *self.target)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_render_plan() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .expect("Build chart error");
+
+    // Each layer draws a circle of its own radius, so the order of the layers can be told from
+    // the recorded calls
+    let marker = |radius: u32| {
+        move |chart: &mut ChartContext<_, _>| {
+            chart.draw_series(std::iter::once(Circle::new((5.0, 5.0), radius, &RED)))?;
+            Ok(())
+        }
+    };
+    chart
+        .render_plan()
+        .layer(RenderLayer::Annotations, marker(4))
+        .layer(RenderLayer::Series, marker(3))
+        .layer(150, marker(2))
+        .layer(RenderLayer::Mesh, marker(1))
+        .render()
+        .expect("Drawing Failure");
+
+    let radii: Vec<_> = calls.circles().into_iter().map(|(_, _, _, r)| r).collect();
+    assert_eq!(radii, vec![1, 2, 3, 4]);
+}
@@ -51,6 +51,40 @@ pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    text_gap: u32,
+    line_height: f64,
+    value_gap: Option<u32>,
+}
+
+/// Measure the width of a column of texts, which is the width of the widest text
+/// - `measure`: The function measuring the size of a text
+/// - `texts`: The texts of the column, the empty ones are skipped
+fn column_width<E, M: Fn(&str) -> Result<(u32, u32), E>>(
+    measure: &M,
+    texts: &[&str],
+) -> Result<i32, E> {
+    let mut width = 0;
+    for text in texts.iter().filter(|t| !t.is_empty()) {
+        width = width.max(measure(text)?.0 as i32);
+    }
+    Ok(width)
+}
+
+/// Align the texts of a column to the right edge of the column
+/// - `measure`: The function measuring the size of a text
+/// - `texts`: The texts of the column
+/// - `right`: The X coordinate of the right edge of the column
+/// - **returns**: The X coordinate where each text starts
+fn align_right<E, M: Fn(&str) -> Result<(u32, u32), E>>(
+    measure: &M,
+    texts: &[&str],
+    right: i32,
+) -> Result<Vec<i32>, E> {
+    let mut ret = vec![];
+    for text in texts {
+        ret.push(right - column_width(measure, &[*text])?);
+    }
+    Ok(ret)
 }
 
 impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
@@ -63,6 +97,9 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            text_gap: 0,
+            line_height: 1.25,
+            value_gap: None,
         }
     }
 
@@ -85,6 +122,29 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /// Set the gap between the legend area, where the legend elements are drawn, and the text of
+    /// the labels
+    /// - `gap`: The gap in pixel, 0 by default
+    pub fn text_gap(&mut self, gap: u32) -> &mut Self {
+        self.text_gap = gap;
+        self
+    }
+
+    /// Set the vertical spacing of the entries
+    /// - `value`: The height of an entry relative to the height of the font, 1.25 by default
+    pub fn line_height(&mut self, value: f64) -> &mut Self {
+        self.line_height = value;
+        self
+    }
+
+    /// Show the values of the series in a second column of the legend, which are right aligned,
+    /// e.g. `CPU      avg 42%`. The values are set by `SeriesAnno::value`.
+    /// - `gap`: The minimal gap between the labels and the values in pixel
+    pub fn value_column(&mut self, gap: u32) -> &mut Self {
+        self.value_gap = Some(gap);
+        self
+    }
+
     /// Set the style of the label series area
     /// `style` - The style of the border
     pub fn border_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self {
@@ -119,7 +179,9 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         };
 
         let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+        label_element.set_line_height(self.line_height);
         let mut funcs = vec![];
+        let mut values = vec![];
 
         for anno in self.target.series_anno.iter() {
             let label_text = anno.get_label();
@@ -133,25 +195,32 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
                 draw_func.unwrap_or_else(|| &|p: BackendCoord| EmptyElement::at(p).into_dyn()),
             );
             label_element.push_line(label_text);
+            values.push(anno.get_value());
         }
 
         let (mut w, mut h) = label_element
             .estimate_dimension()
             .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?;
 
+        let measure = |text: &str| font.font.box_size(text);
+        let show_values = self.value_gap.is_some() && values.iter().any(|v| !v.is_empty());
+        if show_values {
+            let value_width = column_width(&measure, &values)
+                .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?;
+            w += self.value_gap.unwrap_or(0) as i32 + value_width;
+        }
+
         let margin = self.margin as i32;
+        let text_offset = (self.legend_area_size + self.text_gap) as i32;
 
-        w += self.legend_area_size as i32 + margin * 2;
+        w += text_offset + margin * 2;
         h += margin * 2;
 
         let (area_w, area_h) = drawing_area.dim_in_pixel();
 
         let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
 
-        label_element.relocate((
-            label_x + self.legend_area_size as i32 + margin,
-            label_y + margin,
-        ));
+        label_element.relocate((label_x + text_offset + margin, label_y + margin));
 
         drawing_area.draw(&Rectangle::new(
             [(label_x, label_y), (label_x + w, label_y + h)],
@@ -163,16 +232,89 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         ))?;
         drawing_area.draw(&label_element)?;
 
-        for (((_, y0), (_, y1)), make_elem) in label_element
+        let value_x = if show_values {
+            align_right(&measure, &values, label_x + w - margin)
+                .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?
+        } else {
+            vec![]
+        };
+        for (idx, (((_, y0), (_, y1)), make_elem)) in label_element
             .compute_line_layout()
             .map_err(|e| DrawingAreaErrorKind::BackendError(DrawingErrorKind::FontError(e)))?
             .into_iter()
-            .zip(funcs.into_iter())
+            .zip(funcs)
+            .enumerate()
         {
             let legend_element = make_elem((label_x + margin, (y0 + y1) / 2));
             drawing_area.draw(&legend_element)?;
+
+            if show_values && !values[idx].is_empty() {
+                drawing_area.draw_text(values[idx], &font, (value_x[idx], y0))?;
+            }
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_column_width() {
+    // Every character is 10 pixels wide, so the test doesn't depend on the installed fonts
+    let measure = |t: &str| Ok::<_, ()>((t.chars().count() as u32 * 10, 12));
+    assert_eq!(column_width(&measure, &["1%", "avg 42%", ""]), Ok(70));
+    assert_eq!(column_width(&measure, &[]), Ok(0));
+    assert_eq!(
+        align_right(&measure, &["1%", "avg 42%", ""], 100),
+        Ok(vec![80, 30, 100])
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_legend_spacing() {
+    use crate::prelude::*;
+
+    let draw = |text_gap: u32, line_height: f64| {
+        let mut backend = MockedBackend::new(200, 200);
+        let calls = backend.record_calls();
+        let drawing_area = backend.into_drawing_area();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_ranged(0..10, 0..10)
+            .expect("Build chart error");
+        for label in ["A", "B"].iter() {
+            chart
+                .draw_series(std::iter::empty::<Circle<_>>())
+                .expect("Drawing Failure")
+                .label(*label)
+                .legend(|p| Circle::new(p, 3, RED.filled()));
+        }
+        calls.clear();
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperLeft)
+            .text_gap(text_gap)
+            .line_height(line_height)
+            .draw()
+            .expect("Drawing Failure");
+        let texts: Vec<_> = calls.texts().into_iter().map(|(_, _, p)| p).collect();
+        let circles: Vec<_> = calls.circles().into_iter().map(|(_, _, p, _)| p).collect();
+        assert_eq!((texts.len(), circles.len()), (2, 2));
+        (texts, circles)
+    };
+
+    // The text starts right after the legend area by default, and the entries are 1.25 times
+    // the height of the 12px font apart
+    let (texts, circles) = draw(0, 1.25);
+    assert_eq!(texts[0].0 - circles[0].0, 30);
+    assert_eq!(texts[1].1 - texts[0].1, 15);
+    assert_eq!(circles[1].1 - circles[0].1, 15);
+
+    // The gap moves the text away from the legend elements, and the line height spaces out
+    // both the texts and the legend elements
+    let (spaced_texts, spaced_circles) = draw(8, 2.0);
+    assert_eq!(spaced_circles[0], circles[0]);
+    assert_eq!(spaced_texts[0].0 - spaced_circles[0].0, 38);
+    assert_eq!(spaced_texts[1].1 - spaced_texts[0].1, 24);
+    assert_eq!(spaced_circles[1].1 - spaced_circles[0].1, 24);
+}
@@ -0,0 +1,118 @@
+use crate::coord::{RangedCoord, RangedCoordf64, Shift};
+use crate::drawing::backend::DrawingBackend;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::{Circle, Path};
+use crate::style::{ShapeStyle, BLACK};
+
+/// The miniature line chart without any axis, label or margin, which is scaled to fill the
+/// drawing area, e.g. a trend indicator in a table cell.
+pub struct Sparkline<'a> {
+    data: &'a [f64],
+    style: ShapeStyle,
+    markers: Option<(ShapeStyle, u32)>,
+}
+
+impl<'a> Sparkline<'a> {
+    /// Create a new sparkline
+    /// - `data`: The values of the line, which are placed evenly from the left to the right
+    pub fn new(data: &'a [f64]) -> Self {
+        Self {
+            data,
+            style: (&BLACK).into(),
+            markers: None,
+        }
+    }
+
+    /// Set the style of the line
+    /// - `style`: The style of the line
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Mark the smallest and the largest values with circles
+    /// - `style`: The style of the markers
+    /// - `size`: The radius of the markers
+    pub fn min_max_markers<S: Into<ShapeStyle>>(mut self, style: S, size: u32) -> Self {
+        self.markers = Some((style.into(), size));
+        self
+    }
+
+    /// Draw the sparkline on the drawing area, the range of the values is fitted to the area
+    /// - `area`: The drawing area to draw on
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let points: Vec<(f64, f64)> = self
+            .data
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_finite())
+            .map(|(idx, v)| (idx as f64, *v))
+            .collect();
+
+        if points.is_empty() {
+            return Ok(());
+        }
+
+        let (min, max) = points.iter().fold(
+            ((0.0, std::f64::INFINITY), (0.0, std::f64::NEG_INFINITY)),
+            |(min, max), p| {
+                (
+                    if p.1 < min.1 { *p } else { min },
+                    if p.1 > max.1 { *p } else { max },
+                )
+            },
+        );
+
+        // A flat line is drawn at the middle of the area
+        let y_range = if max.1 > min.1 {
+            min.1..max.1
+        } else {
+            min.1 - 1.0..max.1 + 1.0
+        };
+        let x_range = 0.0..((self.data.len() - 1) as f64).max(1.0);
+
+        // Leave the room for the markers so that they aren't cut at the edges
+        let inset = self.markers.as_ref().map_or(0, |(_, size)| *size as i32);
+        let (x_pixels, y_pixels) = area.get_pixel_range();
+        let coord: RangedCoord<RangedCoordf64, RangedCoordf64> = RangedCoord::new(
+            x_range,
+            y_range,
+            (
+                x_pixels.start + inset..x_pixels.end - 1 - inset,
+                y_pixels.end - 1 - inset..y_pixels.start + inset,
+            ),
+        );
+        let area = area.apply_coord_spec(coord);
+
+        area.draw(&Path::new(points, self.style.clone()))?;
+
+        if let Some((ref style, size)) = self.markers {
+            area.draw(&Circle::new(min, size, style.clone()))?;
+            area.draw(&Circle::new(max, size, style.clone()))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_sparkline() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(101, 51, |m| {
+        m.check_draw_path(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path, vec![(0, 50), (50, 0), (100, 25)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    Sparkline::new(&[1.0, 3.0, 2.0])
+        .style(&RED)
+        .draw(&da)
+        .expect("Drawing Failure");
+}
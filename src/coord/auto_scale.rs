@@ -0,0 +1,189 @@
+use super::{AsRangedCoord, LogCoord, LogRange, Ranged, RangedCoordf64, ReversableRanged};
+use std::ops::Range;
+
+/// The scale of an axis chosen by `AutoScaleRange`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScaleKind {
+    /// The linear scale
+    Linear,
+    /// The logarithmic scale
+    Log,
+}
+
+/// The range of an axis which chooses the scale from the data: the axis is logarithmic if the
+/// range is strictly positive and spans more than the threshold of orders of magnitude, which
+/// is 3 by default, otherwise it's linear. This is useful for the data-driven charts, where the
+/// data isn't known in advance:
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let data = vec![(1.0, 0.5), (2.0, 30.0), (3.0, 7000.0)];
+/// let y_range = AutoScaleRange::from_data(data.iter().map(|(_, y)| *y));
+/// assert_eq!(y_range.scale(), ScaleKind::Log);
+/// let chart = ChartBuilder::on(&root).build_ranged(0.0..10.0, y_range)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AutoScaleRange {
+    range: Range<f64>,
+    threshold: f64,
+}
+
+impl AutoScaleRange {
+    /// Create the range of the axis
+    /// - `range`: The range of the axis
+    pub fn new(range: Range<f64>) -> Self {
+        Self {
+            range,
+            threshold: 3.0,
+        }
+    }
+
+    /// Create the range of the axis which covers the data, the non-finite values are ignored.
+    /// If all the values are the same, the range is padded by 0.5 at both sides, so that the
+    /// axis isn't empty.
+    /// - `data`: The values on the axis
+    pub fn from_data<I: IntoIterator<Item = f64>>(data: I) -> Self {
+        let (min, max) = data.into_iter().filter(|v| v.is_finite()).fold(
+            (std::f64::INFINITY, std::f64::NEG_INFINITY),
+            |(min, max), v| (min.min(v), max.max(v)),
+        );
+        if min < max {
+            Self::new(min..max)
+        } else if min == max {
+            Self::new(min - 0.5..max + 0.5)
+        } else {
+            Self::new(0.0..1.0)
+        }
+    }
+
+    /// Set the number of the orders of magnitude the range should exceed to be logarithmic
+    /// - `orders`: The threshold, 3 by default
+    pub fn log_threshold(mut self, orders: f64) -> Self {
+        self.threshold = orders;
+        self
+    }
+
+    /// Get the scale chosen for the axis
+    pub fn scale(&self) -> ScaleKind {
+        let (low, high) = (
+            self.range.start.min(self.range.end),
+            self.range.start.max(self.range.end),
+        );
+        if low > 0.0 && (high / low).log10() > self.threshold {
+            ScaleKind::Log
+        } else {
+            ScaleKind::Linear
+        }
+    }
+}
+
+/// The axis created from `AutoScaleRange`, which is either linear or logarithmic
+pub enum AutoScaleCoord {
+    /// The linear axis
+    Linear(RangedCoordf64),
+    /// The logarithmic axis
+    Log(LogCoord<f64>),
+}
+
+impl AutoScaleCoord {
+    /// Get the scale of the axis
+    pub fn scale(&self) -> ScaleKind {
+        match self {
+            AutoScaleCoord::Linear(_) => ScaleKind::Linear,
+            AutoScaleCoord::Log(_) => ScaleKind::Log,
+        }
+    }
+}
+
+impl From<AutoScaleRange> for AutoScaleCoord {
+    fn from(range: AutoScaleRange) -> AutoScaleCoord {
+        match range.scale() {
+            ScaleKind::Linear => AutoScaleCoord::Linear(range.range.into()),
+            ScaleKind::Log => AutoScaleCoord::Log(LogRange(range.range).into()),
+        }
+    }
+}
+
+impl AsRangedCoord for AutoScaleRange {
+    type CoordDescType = AutoScaleCoord;
+    type Value = f64;
+}
+
+impl Ranged for AutoScaleCoord {
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        match self {
+            AutoScaleCoord::Linear(coord) => coord.map(value, limit),
+            AutoScaleCoord::Log(coord) => coord.map(value, limit),
+        }
+    }
+
+    fn map_raw(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        match self {
+            AutoScaleCoord::Linear(coord) => coord.map_raw(value, limit),
+            AutoScaleCoord::Log(coord) => coord.map_raw(value, limit),
+        }
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<f64> {
+        match self {
+            AutoScaleCoord::Linear(coord) => coord.key_points(max_points),
+            AutoScaleCoord::Log(coord) => coord.key_points(max_points),
+        }
+    }
+
+    fn range(&self) -> Range<f64> {
+        match self {
+            AutoScaleCoord::Linear(coord) => coord.range(),
+            AutoScaleCoord::Log(coord) => coord.range(),
+        }
+    }
+}
+
+impl ReversableRanged for AutoScaleCoord {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        match self {
+            AutoScaleCoord::Linear(coord) => coord.unmap(input, limit),
+            AutoScaleCoord::Log(coord) => coord.unmap(input, limit),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_auto_scale_range() {
+    assert_eq!(AutoScaleRange::new(1.0..5000.0).scale(), ScaleKind::Log);
+    assert_eq!(AutoScaleRange::new(1.0..500.0).scale(), ScaleKind::Linear);
+    assert_eq!(AutoScaleRange::new(-1.0..5000.0).scale(), ScaleKind::Linear);
+    assert_eq!(
+        AutoScaleRange::new(1.0..500.0).log_threshold(2.0).scale(),
+        ScaleKind::Log
+    );
+
+    let range = AutoScaleRange::from_data(vec![0.5, std::f64::NAN, 20000.0, 3.0]);
+    assert_eq!(range.scale(), ScaleKind::Log);
+    let coord: AutoScaleCoord = range.into();
+    assert_eq!(coord.scale(), ScaleKind::Log);
+    assert_eq!(coord.range(), 0.5..20000.0);
+
+    // The middle of a log axis is the geometric mean
+    let coord: AutoScaleCoord = AutoScaleRange::new(1.0..10000.0).into();
+    assert_eq!(coord.map(&100.0, (0, 100)), 50);
+    assert_eq!(coord.unmap(50, (0, 100)).map(f64::round), Some(100.0));
+    let coord: AutoScaleCoord = AutoScaleRange::new(0.0..10.0).into();
+    assert_eq!(coord.unmap(50, (0, 100)), Some(5.0));
+
+    // A single value is padded like the range of a strip plot, rather than making an empty axis
+    let range = AutoScaleRange::from_data(vec![3.0, 3.0, std::f64::INFINITY]);
+    assert_eq!(range.scale(), ScaleKind::Linear);
+    let coord: AutoScaleCoord = range.into();
+    assert_eq!(coord.range(), 2.5..3.5);
+    assert_eq!(coord.map(&3.0, (0, 100)), 50);
+    assert_eq!(AutoScaleRange::from_data(vec![]).range, 0.0..1.0);
+}
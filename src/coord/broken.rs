@@ -0,0 +1,125 @@
+use super::{AsRangedCoord, Ranged};
+use std::ops::Range;
+
+/// The axis that is broken into several segments, so that the uninteresting ranges between
+/// the segments are skipped. Each segment takes a fraction of the axis and a zig-zag break
+/// marker is drawn on the axis between two segments.
+pub struct BrokenAxis<R: Ranged> {
+    segments: Vec<(R, f64)>,
+}
+
+impl<R: Ranged> BrokenAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    /// Create a new broken axis
+    /// - `segments`: The ranges of the segments in ascending order, each of them comes with the
+    ///   fraction of the axis it takes. The fractions are normalized, so `(0.0..10.0, 1.0)` and
+    ///   `(100.0..110.0, 1.0)` splits the axis evenly.
+    /// - **returns**: The newly created broken axis
+    pub fn new<T, I>(segments: I) -> Self
+    where
+        T: AsRangedCoord<CoordDescType = R>,
+        R: From<T>,
+        I: IntoIterator<Item = (T, f64)>,
+    {
+        let segments: Vec<_> = segments
+            .into_iter()
+            .map(|(range, fraction)| (range.into(), fraction.max(0.0)))
+            .collect();
+
+        assert!(
+            !segments.is_empty(),
+            "A broken axis needs at least one segment"
+        );
+
+        let total: f64 = segments.iter().map(|(_, fraction)| fraction).sum();
+
+        Self {
+            segments: segments
+                .into_iter()
+                .map(|(range, fraction)| (range, if total > 0.0 { fraction / total } else { 0.0 }))
+                .collect(),
+        }
+    }
+
+    /// Get the pixel limits of each segment
+    fn segment_limits(&self, limit: (i32, i32)) -> impl Iterator<Item = (&R, (i32, i32))> {
+        let size = f64::from(limit.1 - limit.0);
+        let mut acc = 0.0;
+        self.segments.iter().map(move |(segment, fraction)| {
+            let start = limit.0 + (size * acc).round() as i32;
+            acc += fraction;
+            let end = limit.0 + (size * acc).round() as i32;
+            (segment, (start, end))
+        })
+    }
+}
+
+impl<R: Ranged> Ranged for BrokenAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    type ValueType = R::ValueType;
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        let count = self.segments.len();
+        for (idx, (segment, segment_limit)) in self.segment_limits(limit).enumerate() {
+            let range = segment.range();
+            if *value < range.start && idx > 0 {
+                // The value is in the skipped range, so we put it on the break
+                return segment_limit.0;
+            }
+            if *value <= range.end || idx + 1 == count {
+                return segment.map(value, segment_limit);
+            }
+        }
+        limit.0
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        self.segments
+            .iter()
+            .flat_map(|(segment, fraction)| {
+                segment.key_points((max_points as f64 * fraction).round() as usize)
+            })
+            .collect()
+    }
+
+    fn range(&self) -> Range<Self::ValueType> {
+        let start = self.segments[0].0.range().start;
+        let end = self.segments[self.segments.len() - 1].0.range().end;
+        start..end
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.segment_limits(limit)
+            .skip(1)
+            .map(|(_, (start, _))| start)
+            .collect()
+    }
+}
+
+impl<R: Ranged> AsRangedCoord for BrokenAxis<R>
+where
+    R::ValueType: PartialOrd,
+{
+    type CoordDescType = Self;
+    type Value = R::ValueType;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::coord::*;
+
+    #[test]
+    fn test_broken_axis_map() {
+        let axis = BrokenAxis::new(vec![(0..10, 1.0), (100..110, 1.0)]);
+
+        assert_eq!(axis.map(&5, (0, 200)), 50);
+        assert_eq!(axis.map(&105, (0, 200)), 150);
+        assert_eq!(axis.map(&50, (0, 200)), 100);
+        assert_eq!(axis.axis_breaks((0, 200)), vec![100]);
+        assert_eq!(axis.range(), 0..110);
+    }
+}
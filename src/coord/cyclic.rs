@@ -0,0 +1,177 @@
+use super::ranged::map_to_pixel;
+use super::{AsRangedCoord, Ranged, ReversableRanged};
+use std::ops::Range;
+
+/// The cyclic axis, which wraps the values by a period, such as the angles of the wind
+/// direction or a phase. The values out of the range are wrapped into the range, and the key
+/// points divide the period evenly, e.g. 0, 90, 180 and 270 degrees.
+/// Use `WrappedLineSeries` to draw a line on the cyclic axis, so that the line is split where
+/// it crosses the wrap boundary.
+#[derive(Clone)]
+pub struct CyclicRange {
+    start: f64,
+    period: f64,
+}
+
+impl CyclicRange {
+    /// Create a new cyclic axis
+    /// - `start`: The smallest value of the axis
+    /// - `period`: The period of the axis, the axis covers `start..start + period`
+    pub fn new(start: f64, period: f64) -> Self {
+        assert!(period > 0.0, "The period of a cyclic axis must be positive");
+        Self { start, period }
+    }
+
+    /// Create a cyclic axis of angles in degrees, i.e. `0..360`
+    pub fn degrees() -> Self {
+        Self::new(0.0, 360.0)
+    }
+
+    /// Create a cyclic axis of angles in radians, i.e. `0..2π`
+    pub fn radians() -> Self {
+        Self::new(0.0, 2.0 * std::f64::consts::PI)
+    }
+
+    /// Get the period of the axis
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+
+    /// Wrap the value into the range of the axis
+    /// - `value`: The value to wrap
+    /// - **returns**: The value in `start..start + period`
+    pub fn wrap(&self, value: f64) -> f64 {
+        let offset = (value - self.start) % self.period;
+        if offset < 0.0 {
+            self.start + offset + self.period
+        } else {
+            self.start + offset
+        }
+    }
+
+    /// Split a path on the cyclic axis where it crosses the wrap boundary. Two adjacent points
+    /// are connected along the shorter way around the cycle, so if the shorter way crosses the
+    /// boundary, the path is split there and both parts are extended to the boundary.
+    /// - `points`: The points of the path, the first component is the value on the cyclic axis
+    /// - **returns**: The parts of the path, the cyclic values are wrapped into the range
+    pub fn split_path<I: IntoIterator<Item = (f64, f64)>>(
+        &self,
+        points: I,
+    ) -> Vec<Vec<(f64, f64)>> {
+        let end = self.start + self.period;
+        let mut parts = vec![];
+        let mut current: Vec<(f64, f64)> = vec![];
+
+        for (value, other) in points {
+            let value = self.wrap(value);
+            if let Some(&(last, last_other)) = current.last() {
+                let diff = value - last;
+                let crossing = if diff > self.period / 2.0 {
+                    // Going backward across the start of the axis
+                    Some((self.start, end, last - self.start, end - value))
+                } else if diff < -self.period / 2.0 {
+                    // Going forward across the end of the axis
+                    Some((end, self.start, end - last, value - self.start))
+                } else {
+                    None
+                };
+
+                if let Some((leave, enter, before, after)) = crossing {
+                    let t = if before + after > 0.0 {
+                        before / (before + after)
+                    } else {
+                        0.0
+                    };
+                    let boundary = last_other + (other - last_other) * t;
+                    current.push((leave, boundary));
+                    parts.push(std::mem::replace(&mut current, vec![(enter, boundary)]));
+                }
+            }
+            current.push((value, other));
+        }
+
+        if !current.is_empty() {
+            parts.push(current);
+        }
+
+        parts
+    }
+}
+
+impl Ranged for CyclicRange {
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        // The end of the period is where `split_path` leaves the axis, so it stays at the end of
+        // the axis instead of being wrapped back to the start
+        let offset = if *value == self.start + self.period {
+            self.period
+        } else {
+            self.wrap(*value) - self.start
+        };
+        map_to_pixel(offset / self.period, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<f64> {
+        let divisions = [12, 8, 4, 2, 1]
+            .iter()
+            .cloned()
+            .find(|n| *n <= max_points)
+            .unwrap_or(0);
+        (0..divisions)
+            .map(|idx| self.start + self.period * idx as f64 / divisions as f64)
+            .collect()
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.start..self.start + self.period
+    }
+}
+
+impl ReversableRanged for CyclicRange {
+    fn unmap(&self, p: i32, (min, max): (i32, i32)) -> Option<f64> {
+        if p < min.min(max) || p > max.max(min) || min == max {
+            return None;
+        }
+        Some(self.start + f64::from(p - min) / f64::from(max - min) * self.period)
+    }
+}
+
+impl AsRangedCoord for CyclicRange {
+    type CoordDescType = Self;
+    type Value = f64;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::coord::*;
+
+    #[test]
+    fn test_cyclic_range() {
+        let axis = CyclicRange::degrees();
+
+        assert_eq!(axis.map(&90.0, (0, 360)), 90);
+        assert_eq!(axis.map(&450.0, (0, 360)), 90);
+        assert_eq!(axis.map(&-90.0, (0, 360)), 270);
+        assert_eq!(axis.key_points(4), vec![0.0, 90.0, 180.0, 270.0]);
+
+        let parts = axis.split_path(vec![(340.0, 0.0), (20.0, 4.0), (30.0, 5.0), (350.0, 9.0)]);
+        assert_eq!(
+            parts,
+            vec![
+                vec![(340.0, 0.0), (360.0, 2.0)],
+                vec![(0.0, 2.0), (20.0, 4.0), (30.0, 5.0), (0.0, 8.0)],
+                vec![(360.0, 8.0), (350.0, 9.0)],
+            ]
+        );
+
+        let pixels: Vec<Vec<_>> = parts
+            .iter()
+            .map(|part| part.iter().map(|(x, _)| axis.map(x, (0, 360))).collect())
+            .collect();
+        assert_eq!(
+            pixels,
+            vec![vec![340, 360], vec![0, 20, 30, 0], vec![360, 350]]
+        );
+    }
+}
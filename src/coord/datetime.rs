@@ -2,6 +2,7 @@
 use chrono::{Date, DateTime, Duration, TimeZone};
 use std::ops::Range;
 
+use super::ranged::map_to_pixel;
 use super::Ranged;
 
 /// The ranged coordinate for date
@@ -27,7 +28,7 @@ impl<Z: TimeZone> Ranged for RangedDate<Z> {
         let total_days = (self.1.clone() - self.0.clone()).num_days() as f64;
         let value_days = (value.clone() - self.0.clone()).num_days() as f64;
 
-        (f64::from(limit.1 - limit.0) * value_days / total_days) as i32 + limit.0
+        map_to_pixel(value_days / total_days, limit)
     }
 
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
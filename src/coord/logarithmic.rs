@@ -1,4 +1,4 @@
-use super::{AsRangedCoord, Ranged, RangedCoordf64};
+use super::{AsRangedCoord, Ranged, RangedCoordf64, ReversableRanged};
 use std::marker::PhantomData;
 use std::ops::Range;
 
@@ -65,6 +65,32 @@ impl<V: LogScalable> AsRangedCoord for LogRange<V> {
     type Value = V;
 }
 
+/// Get the positions at the given multiples of each power of ten within the range
+/// - `start`: The start of the range, which should be positive
+/// - `end`: The end of the range
+/// - `steps`: The multiples of the power of ten within a decade, in ascending order
+fn sub_decade_points(start: f64, end: f64, steps: &[f64]) -> Vec<f64> {
+    let mut ret = vec![];
+    if start.is_nan() || end.is_nan() || start <= 0.0 || end < start {
+        return ret;
+    }
+    let (low, high) = (start * (1.0 - 1e-9), end * (1.0 + 1e-9));
+    let mut exp = start.log10().floor() as i32;
+    while 10f64.powi(exp) <= high {
+        for k in steps {
+            let value = k * 10f64.powi(exp);
+            if value > high {
+                break;
+            }
+            if value >= low {
+                ret.push(value);
+            }
+        }
+        exp += 1;
+    }
+    ret
+}
+
 /// A log scaled coordinate axis
 pub struct LogCoord<V: LogScalable> {
     linear: RangedCoordf64,
@@ -81,12 +107,30 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
         self.linear.map(&value, limit)
     }
 
+    fn map_raw(&self, value: &V, limit: (i32, i32)) -> i32 {
+        // Only the values without a logarithm are guarded, they are taken as the smallest
+        // positive value, which is far below any range
+        let value = value.as_f64().max(std::f64::MIN_POSITIVE).ln();
+        self.linear.map_raw(&value, limit)
+    }
+
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType> {
+        // Use the positions within each decade, i.e. 1 to 9 times the power of ten, or only
+        // 1, 2 and 5 times if there are too many, so that the fine mesh is logarithmically spaced
+        let all_steps = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        for steps in &[&all_steps[..], &[1.0, 2.0, 5.0][..]] {
+            let (start, end) = (self.logic.start.as_f64(), self.logic.end.as_f64());
+            let sub_decade = sub_decade_points(start, end, steps);
+            if !sub_decade.is_empty() && sub_decade.len() <= max_points {
+                return sub_decade.into_iter().map(V::from_f64).collect();
+            }
+        }
+
         let tier_1 = (self.logic.end.as_f64() / self.logic.start.as_f64())
             .log10()
             .abs()
             .floor() as usize;
-        let tier_2_density = if max_points < tier_1 {
+        let tier_2_density = if tier_1 == 0 || max_points < tier_1 {
             0
         } else {
             let density = 1 + (max_points - tier_1) / tier_1;
@@ -128,3 +172,65 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
         self.logic.clone()
     }
 }
+
+impl<V: LogScalable> ReversableRanged for LogCoord<V> {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<V> {
+        self.linear
+            .unmap(input, limit)
+            .map(|v| V::from_f64(v.exp()))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_log_key_points() {
+    let coord: LogCoord<f64> = LogRange(2.0..300.0).into();
+    let minor = coord.key_points(100);
+    assert_eq!(minor.len(), 20);
+    assert_eq!(&minor[..3], &[2.0, 3.0, 4.0]);
+    assert_eq!(&minor[8..11], &[10.0, 20.0, 30.0]);
+    assert_eq!(minor[19], 300.0);
+
+    assert_eq!(
+        coord.key_points(10),
+        vec![2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0]
+    );
+    assert_eq!(coord.key_points(5), vec![10.0, 100.0]);
+
+    // A narrow range still has key points when the whole decade doesn't fit
+    let coord: LogCoord<f64> = LogRange(2.0..8.0).into();
+    assert_eq!(coord.key_points(3), vec![2.0, 5.0]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_log_coord_map_raw() {
+    let coord: LogCoord<f64> = LogRange(10.0..1000.0).into();
+    assert_eq!(coord.map_raw(&100.0, (0, 100)), coord.map(&100.0, (0, 100)));
+    // The values below the start of the range go below the start of the pixel range
+    assert_eq!(coord.map(&1.0, (0, 100)), 0);
+    assert_eq!(coord.map_raw(&1.0, (0, 100)), -50);
+    assert!(coord.map_raw(&0.0, (0, 100)) < -10_000);
+
+    // Pinning a value below the range moves the range accordingly
+    use crate::coord::{CoordTranslate, RangedCoord};
+    let mut coord = RangedCoord::<LogCoord<f64>, LogCoord<f64>>::new(
+        LogRange(10.0..1000.0),
+        LogRange(10.0..1000.0),
+        (0..100, 0..100),
+    );
+    coord.pin_to_pixel(&(1.0, 1.0), (0, 0));
+    assert_eq!(coord.translate(&(10.0, 10.0)), (50, 50));
+}
+
+#[cfg(test)]
+#[test]
+fn test_log_key_points_wide_range() {
+    let coord: LogCoord<f64> = LogRange(1e-3..1e6).into();
+    let minor = coord.key_points(50);
+    // 1, 2 and 5 times of the nine decades, and the end of the range
+    assert_eq!(minor.len(), 28);
+    assert!((minor[1] - 2e-3).abs() < 1e-12);
+    assert!((minor[2] - 5e-3).abs() < 1e-12);
+    assert!((minor[27] - 1e6).abs() < 1e-3);
+}
@@ -22,16 +22,23 @@ Also, the ranged axis can be decereted, and this is required by the histogram se
 */
 use crate::drawing::backend::BackendCoord;
 
+mod auto_scale;
+mod broken;
+mod cyclic;
 #[cfg(feature = "chrono")]
 mod datetime;
 mod logarithmic;
 mod numeric;
 mod ranged;
 
+pub use auto_scale::{AutoScaleCoord, AutoScaleRange, ScaleKind};
+pub use broken::BrokenAxis;
+pub use cyclic::CyclicRange;
 #[cfg(feature = "chrono")]
 pub use datetime::{RangedDate, RangedDateTime};
 pub use numeric::{
-    RangedCoordf32, RangedCoordf64, RangedCoordi32, RangedCoordi64, RangedCoordu32, RangedCoordu64,
+    IntoKeyPointPolicy, KeyPointPolicy, RangedCoordf32, RangedCoordf64, RangedCoordi32,
+    RangedCoordi64, RangedCoordu32, RangedCoordu64, WithKeyPointPolicy,
 };
 pub use ranged::{
     AsRangedCoord, DescreteRanged, IntoCentric, IntoPartialAxis, MeshLine, Ranged, RangedCoord,
@@ -49,6 +56,13 @@ pub trait CoordTranslate {
 
     /// Translate the guest coordinate to the guest coordinate
     fn translate(&self, from: &Self::From) -> BackendCoord;
+
+    /// Translate the guest coordinate to the backend coordinate without clamping the result,
+    /// which is useful for debugging a customized coordinate. By default it's the same as
+    /// `translate`.
+    fn translate_raw(&self, from: &Self::From) -> BackendCoord {
+        self.translate(from)
+    }
 }
 
 /// The trait indicates that the coordinate system supports reverse transform
@@ -88,6 +102,12 @@ impl<T: CoordTranslate> CoordTranslate for ShiftAndTrans<T> {
         let temp = self.1.translate(from);
         self.0.translate(&temp)
     }
+
+    fn translate_raw(&self, from: &Self::From) -> BackendCoord {
+        let temp = self.1.translate_raw(from);
+        let (dx, dy) = (self.0).0;
+        (temp.0.saturating_add(dx), temp.1.saturating_add(dy))
+    }
 }
 
 impl<T: ReverseCoordTranslate> ReverseCoordTranslate for ShiftAndTrans<T> {
@@ -1,5 +1,6 @@
 use std::ops::Range;
 
+use super::ranged::{map_to_pixel, map_to_pixel_raw};
 use super::{AsRangedCoord, DescreteRanged, Ranged, ReversableRanged};
 
 macro_rules! impl_descrete_trait {
@@ -36,14 +37,21 @@ macro_rules! make_numeric_coord {
         impl Ranged for $name {
             type ValueType = $type;
             fn map(&self, v: &$type, limit: (i32, i32)) -> i32 {
-                let logic_length = (*v - self.0) as f64 / (self.1 - self.0) as f64;
+                let logic_length = (*v as f64 - self.0 as f64) / (self.1 as f64 - self.0 as f64);
                 let actual_length = limit.1 - limit.0;
 
                 if actual_length == 0 {
                     return limit.1;
                 }
 
-                return limit.0 + (actual_length as f64 * logic_length + 1e-3).floor() as i32;
+                return map_to_pixel(logic_length, limit);
+            }
+            fn map_raw(&self, v: &$type, limit: (i32, i32)) -> i32 {
+                let logic_length = (*v as f64 - self.0 as f64) / (self.1 as f64 - self.0 as f64);
+                if limit.1 == limit.0 {
+                    return limit.1;
+                }
+                map_to_pixel_raw(logic_length, limit)
             }
             fn key_points(&self, max_points: usize) -> Vec<$type> {
                 $key_points((self.0, self.1), max_points)
@@ -54,7 +62,7 @@ macro_rules! make_numeric_coord {
         }
 
         impl ReversableRanged for $name {
-            fn unmap(&self, p:i32, (min,max): (i32, i32)) -> Option<$type> {
+            fn unmap(&self, p: i32, (min, max): (i32, i32)) -> Option<$type> {
                 if p < min.min(max) || p > max.max(min) {
                     return None;
                 }
@@ -236,6 +244,134 @@ impl_ranged_type_trait!(u64, RangedCoordu64);
 impl_ranged_type_trait!(i128, RangedCoordi128);
 impl_ranged_type_trait!(u128, RangedCoordu128);
 
+/// The policy of the key points when the round steps can't give the requested number of key
+/// points, e.g. a range of `0..7` with 10 key points requested
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyPointPolicy {
+    /// Prefer the round steps, i.e. 1, 2 or 5 times a power of ten, even if there are much fewer
+    /// key points than requested. This is the default of all the numeric axes.
+    Round,
+    /// Use exactly the requested number of key points, which divide the range evenly from its
+    /// start to its end. The key points are rounded to four significant digits of the range,
+    /// e.g. `0.778` for `0..7` with 10 key points.
+    Exact,
+    /// Prefer the round percentage steps, i.e. 1, 2, 2.5 or 5 times a power of ten, so that the
+    /// quarters are the key points when there's room for them, e.g. 0%, 25%, 50%, 75% and 100%
+    /// with 5 key points.
+    Percent,
+}
+
+/// The f64 axis with a key point policy, see `IntoKeyPointPolicy`
+pub struct WithKeyPointPolicy<R: Ranged<ValueType = f64>>(R, KeyPointPolicy);
+
+/// The trait that sets the key point policy of a f64 axis, for example
+/// `(0.0..7.0).key_point_policy(KeyPointPolicy::Exact)`
+pub trait IntoKeyPointPolicy: AsRangedCoord
+where
+    Self::CoordDescType: Ranged<ValueType = f64>,
+{
+    fn key_point_policy(self, policy: KeyPointPolicy) -> WithKeyPointPolicy<Self::CoordDescType> {
+        WithKeyPointPolicy(self.into(), policy)
+    }
+}
+
+impl<R: AsRangedCoord> IntoKeyPointPolicy for R where R::CoordDescType: Ranged<ValueType = f64> {}
+
+/// Compute the requested number of key points, which divide the range evenly from its start
+/// to its end. The key points are rounded to four significant digits of the range, so that
+/// the labels stay short.
+fn compute_exact_key_points(range: (f64, f64), max_points: usize) -> Vec<f64> {
+    let range = (range.0.min(range.1), range.0.max(range.1));
+    if max_points < 2 || !(range.1 > range.0) {
+        return compute_f64_key_points(range, max_points);
+    }
+
+    let width = range.1 - range.0;
+    let unit = (10f64).powf(width.log10().floor() - 3.0);
+    (0..max_points)
+        .map(|idx| {
+            let value = range.0 + width * idx as f64 / (max_points - 1) as f64;
+            (value / unit).round() * unit
+        })
+        .collect()
+}
+
+/// Compute the key points on the round percentage steps, which are 1, 2, 2.5 or 5 times a
+/// power of ten, the smallest step which doesn't give more key points than requested is used
+fn compute_percent_key_points(range: (f64, f64), max_points: usize) -> Vec<f64> {
+    let range = (range.0.min(range.1), range.0.max(range.1));
+    if max_points == 0 || !(range.1 > range.0) {
+        return compute_f64_key_points(range, max_points);
+    }
+
+    // The steps are counted in the units of a power of ten, so the key points are computed
+    // from integers and don't accumulate the rounding errors
+    let mut exp = (range.1 - range.0).log10().floor() as i32 - 3;
+    loop {
+        let unit = (10f64).powi(exp.abs());
+        let to_value = |n: i64| {
+            if exp < 0 {
+                n as f64 / unit
+            } else {
+                n as f64 * unit
+            }
+        };
+        for step in &[10, 20, 25, 50] {
+            let first = (range.0 / to_value(*step) - 1e-6).ceil() as i64;
+            let last = (range.1 / to_value(*step) + 1e-6).floor() as i64;
+            if last - first < max_points as i64 {
+                return (first..=last).map(|idx| to_value(idx * step)).collect();
+            }
+        }
+        exp += 1;
+    }
+}
+
+impl<R: Ranged<ValueType = f64>> Ranged for WithKeyPointPolicy<R> {
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.0.map(value, limit)
+    }
+
+    fn key_points(&self, max_points: usize) -> Vec<f64> {
+        match self.1 {
+            KeyPointPolicy::Round => self.0.key_points(max_points),
+            KeyPointPolicy::Exact => {
+                let range = self.0.range();
+                compute_exact_key_points((range.start, range.end), max_points)
+            }
+            KeyPointPolicy::Percent => {
+                let range = self.0.range();
+                compute_percent_key_points((range.start, range.end), max_points)
+            }
+        }
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.0.range()
+    }
+
+    fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
+        self.0.axis_pixel_range(limit)
+    }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks(limit)
+    }
+}
+
+impl<R: Ranged<ValueType = f64> + ReversableRanged> ReversableRanged for WithKeyPointPolicy<R> {
+    fn unmap(&self, p: i32, limit: (i32, i32)) -> Option<f64> {
+        self.0.unmap(p, limit)
+    }
+}
+
+impl<R: Ranged<ValueType = f64>> AsRangedCoord for WithKeyPointPolicy<R> {
+    type CoordDescType = Self;
+    type Value = f64;
+}
+
 /*
 pub fn keypoints_i64(range:(i64,i64), n:usize) -> Vec<i64> {
     compute_i64_key_points(range,n)
@@ -271,6 +407,68 @@ mod test {
         assert_eq!(coord.map(&5.0, (0, 100)), 25);
     }
 
+    #[test]
+    fn test_linear_coord_map_reversed() {
+        let coord: RangedCoordf64 = (0.0..3.0).into();
+        assert_eq!(coord.map(&0.0, (100, 0)), 100);
+        assert_eq!(coord.map(&3.0, (100, 0)), 0);
+        for v in [0.5, 1.0, 1.7, 2.9].iter() {
+            assert_eq!(coord.map(v, (100, 0)), 100 - coord.map(v, (0, 100)));
+        }
+    }
+
+    #[test]
+    fn test_key_point_policy() {
+        let round = (0.0..7.0).key_point_policy(KeyPointPolicy::Round);
+        assert_eq!(
+            round.key_points(10),
+            RangedCoordf64::from(0.0..7.0).key_points(10)
+        );
+
+        let exact = (0.0..7.0).key_point_policy(KeyPointPolicy::Exact);
+        let kp = exact.key_points(10);
+        assert_eq!(kp.len(), 10);
+        assert_eq!(kp[0], 0.0);
+        assert_eq!(kp[1], 0.778);
+        assert_eq!(kp[9], 7.0);
+
+        let kp = (0.0..1.0)
+            .key_point_policy(KeyPointPolicy::Exact)
+            .key_points(5);
+        assert_eq!(kp, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        let percent = (0.0..100.0).key_point_policy(KeyPointPolicy::Percent);
+        assert_eq!(percent.key_points(5), vec![0.0, 25.0, 50.0, 75.0, 100.0]);
+        assert_eq!(
+            percent.key_points(10),
+            vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]
+        );
+        assert_eq!(percent.key_points(3), vec![0.0, 50.0, 100.0]);
+        let kp = (0.0..1.0)
+            .key_point_policy(KeyPointPolicy::Percent)
+            .key_points(10);
+        assert_eq!(kp, vec![0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+    }
+
+    #[test]
+    fn test_linear_coord_map_saturate() {
+        let coord: RangedCoordi32 = (-10..10).into();
+        assert!(coord.map(&std::i32::MAX, (0, 100)) > 100);
+        assert!(coord.map(&std::i32::MIN, (0, 100)) < 0);
+
+        let coord: RangedCoordf64 = (0.0..1.0).into();
+        assert!(coord.map(&1e300, (100, 0)) < 0);
+        assert!(coord.map(&-1e300, (100, 0)) > 100);
+    }
+
+    #[test]
+    fn test_linear_coord_map_raw() {
+        let coord: RangedCoordf64 = (0.0..1.0).into();
+        assert_eq!(coord.map_raw(&0.5, (0, 100)), coord.map(&0.5, (0, 100)));
+        assert_eq!(coord.map_raw(&1e6, (0, 100)), 100_000_000);
+        assert!(coord.map_raw(&1e300, (100, 0)) < -(1 << 24));
+    }
+
     #[test]
     fn test_linear_coord_system() {
         let _coord =
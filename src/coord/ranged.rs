@@ -4,6 +4,48 @@ use crate::style::ShapeStyle;
 
 use std::ops::Range;
 
+/// The largest magnitude of a mapped pixel coordinate. The mapping saturates at this limit, so
+/// an extreme value is placed far outside of the drawing area instead of overflowing `i32`,
+/// and adding the offset of a drawing area to it doesn't overflow either.
+const PIXEL_LIMIT: f64 = (1 << 24) as f64;
+
+/// Convert a pixel coordinate computed in floating point to `i32`, saturating at the pixel limit
+fn saturate_pixel(value: f64) -> i32 {
+    value.max(-PIXEL_LIMIT).min(PIXEL_LIMIT) as i32
+}
+
+/// Map a relative position on an axis to the pixel, where 0.0 is the start and 1.0 is the end
+/// of the axis. All the axes share this, so that the border, the grid lines and the axis of
+/// the chart land on the same pixels. The distance from the start of the axis is rounded down
+/// regardless of the direction of the axis, so both ends of the axis map exactly to the ends of
+/// the pixel range, and a reversed axis (such as the usual Y axis) is rounded the same way as a
+/// forward one.
+/// - `logic`: The relative position on the axis
+/// - `limit`: The pixel range of the axis
+pub(crate) fn map_to_pixel(logic: f64, limit: (i32, i32)) -> i32 {
+    let size = f64::from(limit.1 - limit.0);
+    let offset = saturate_pixel((size.abs() * logic + 1e-3).floor());
+    if size < 0.0 {
+        limit.0 - offset
+    } else {
+        limit.0 + offset
+    }
+}
+
+/// The same as `map_to_pixel`, but the pixel isn't saturated at the pixel limit, this is only
+/// used for diagnosing the coordinate mapping
+/// - `logic`: The relative position on the axis
+/// - `limit`: The pixel range of the axis
+pub(crate) fn map_to_pixel_raw(logic: f64, limit: (i32, i32)) -> i32 {
+    let size = f64::from(limit.1 - limit.0);
+    let offset = (size.abs() * logic + 1e-3).floor() as i32;
+    if size < 0.0 {
+        limit.0.saturating_sub(offset)
+    } else {
+        limit.0.saturating_add(offset)
+    }
+}
+
 /// The trait that indicates we have a ordered and ranged value
 /// Which is used to describe the axis
 pub trait Ranged {
@@ -13,6 +55,13 @@ pub trait Ranged {
     /// This function maps the value to i32, which is the drawing coordinate
     fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32;
 
+    /// Maps the value to the drawing coordinate without any clamping, this is used to diagnose
+    /// the mapping of a value which is far outside of the range. By default it's the same as
+    /// `map`.
+    fn map_raw(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        self.map(value, limit)
+    }
+
     /// This function gives the key points that we can draw a grid based on this
     fn key_points(&self, max_points: usize) -> Vec<Self::ValueType>;
 
@@ -23,6 +72,11 @@ pub trait Ranged {
     fn axis_pixel_range(&self, limit: (i32, i32)) -> Range<i32> {
         limit.0..limit.1
     }
+
+    /// This function provides the pixel positions where the axis is broken
+    fn axis_breaks(&self, _limit: (i32, i32)) -> Vec<i32> {
+        vec![]
+    }
 }
 
 /// The trait indicates the ranged value can be map reversely, which means
@@ -88,6 +142,29 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
         Ok(())
     }
 
+    /// Replace the X axis, the pixel range of the axis is kept
+    /// - `logic_x`: The new X axis
+    pub fn set_x_spec<IntoX: Into<X>>(&mut self, logic_x: IntoX) {
+        self.logic_x = logic_x.into();
+    }
+
+    /// Replace the Y axis, the pixel range of the axis is kept
+    /// - `logic_y`: The new Y axis
+    pub fn set_y_spec<IntoY: Into<Y>>(&mut self, logic_y: IntoY) {
+        self.logic_y = logic_y.into();
+    }
+
+    /// Shift the pixel ranges of the axes, so that the value is mapped exactly to the pixel,
+    /// e.g. to register the chart over an existing image. The scale of the axes is kept.
+    /// - `value`: The value to pin
+    /// - `pixel`: The backend coordinate the value is mapped to
+    pub fn pin_to_pixel(&mut self, value: &(X::ValueType, Y::ValueType), pixel: BackendCoord) {
+        let (x, y) = self.translate_raw(value);
+        let (dx, dy) = (pixel.0 - x, pixel.1 - y);
+        self.back_x = (self.back_x.0 + dx, self.back_x.1 + dx);
+        self.back_y = (self.back_y.0 + dy, self.back_y.1 + dy);
+    }
+
     /// Get the range of X axis
     pub fn get_x_range(&self) -> Range<X::ValueType> {
         self.logic_x.range()
@@ -105,6 +182,26 @@ impl<X: Ranged, Y: Ranged> RangedCoord<X, Y> {
     pub fn get_y_axis_pixel_range(&self) -> Range<i32> {
         self.logic_y.axis_pixel_range(self.back_y)
     }
+
+    /// Get the pixel positions where the X axis is broken
+    pub fn get_x_axis_breaks(&self) -> Vec<i32> {
+        self.logic_x.axis_breaks(self.back_x)
+    }
+
+    /// Get the pixel positions where the Y axis is broken
+    pub fn get_y_axis_breaks(&self) -> Vec<i32> {
+        self.logic_y.axis_breaks(self.back_y)
+    }
+
+    /// Map a X value to the X backend coordinate
+    pub fn map_x(&self, value: &X::ValueType) -> i32 {
+        self.logic_x.map(value, self.back_x)
+    }
+
+    /// Map a Y value to the Y backend coordinate
+    pub fn map_y(&self, value: &Y::ValueType) -> i32 {
+        self.logic_y.map(value, self.back_y)
+    }
 }
 
 impl<X: Ranged, Y: Ranged> CoordTranslate for RangedCoord<X, Y> {
@@ -116,6 +213,13 @@ impl<X: Ranged, Y: Ranged> CoordTranslate for RangedCoord<X, Y> {
             self.logic_y.map(&from.1, self.back_y),
         )
     }
+
+    fn translate_raw(&self, from: &Self::From) -> BackendCoord {
+        (
+            self.logic_x.map_raw(&from.0, self.back_x),
+            self.logic_y.map_raw(&from.1, self.back_y),
+        )
+    }
 }
 
 impl<X: ReversableRanged, Y: ReversableRanged> ReverseCoordTranslate for RangedCoord<X, Y> {
@@ -146,6 +250,42 @@ impl<'a, X: Ranged, Y: Ranged> MeshLine<'a, X, Y> {
         };
         backend.draw_line(left, right, &style.color)
     }
+
+    /// Draw a single mesh line onto the backend, but only the parts where the mask is
+    /// satisfied are drawn
+    /// - `mask`: The function that decides if a pixel on the line should be drawn
+    pub fn draw_masked<DB: DrawingBackend, M: FnMut(BackendCoord) -> bool>(
+        &self,
+        backend: &mut DB,
+        style: &ShapeStyle,
+        mut mask: M,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (&from, &to) = match self {
+            MeshLine::XMesh(a, b, _) => (a, b),
+            MeshLine::YMesh(a, b, _) => (a, b),
+        };
+        let (dx, dy) = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+        let len = (to.0 - from.0).abs().max((to.1 - from.1).abs());
+
+        let mut start = None;
+        for idx in 0..=len {
+            let point = (from.0 + dx * idx, from.1 + dy * idx);
+            match (mask(point), start) {
+                (true, None) => start = Some(point),
+                (false, Some(begin)) => {
+                    backend.ensure_prepared()?;
+                    backend.draw_line(begin, (point.0 - dx, point.1 - dy), &style.color)?;
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(begin) = start {
+            backend.ensure_prepared()?;
+            backend.draw_line(begin, to, &style.color)?;
+        }
+        Ok(())
+    }
 }
 
 /// The trait indicates the coordinate is descrete, so that we can draw histogram on it
@@ -215,6 +355,10 @@ where
     fn range(&self) -> Range<Self::ValueType> {
         self.0.range()
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks(limit)
+    }
 }
 
 impl<D: DescreteRanged> DescreteRanged for CentricDescreteRange<D>
@@ -275,6 +419,10 @@ where
 
         left.min(right)..left.max(right)
     }
+
+    fn axis_breaks(&self, limit: (i32, i32)) -> Vec<i32> {
+        self.0.axis_breaks(limit)
+    }
 }
 
 impl<R: DescreteRanged> DescreteRanged for PartialAxis<R>
@@ -0,0 +1,105 @@
+use std::ops::Range;
+
+/// The range of an axis computed from the data, which can be extended to include some values
+/// and clamped by hard limits, for example starting the Y axis at zero:
+///
+/// ```ignore
+/// let y_range = AutoRange::from_data(data.iter().map(|(_, y)| *y))
+///     .include(0.0)
+///     .floor(0.0)
+///     .build()
+///     .unwrap_or(0.0..1.0);
+/// let chart = ChartBuilder::on(&root).build_ranged(0.0..10.0, y_range)?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct AutoRange<T> {
+    bounds: Option<(T, T)>,
+    floor: Option<T>,
+    ceiling: Option<T>,
+}
+
+impl<T: PartialOrd + Clone> AutoRange<T> {
+    /// Compute the range from the data, the values that can't be compared, e.g. NaN, are ignored
+    /// - `data`: The values of the data
+    pub fn from_data<I: IntoIterator<Item = T>>(data: I) -> Self {
+        let mut ret = Self {
+            bounds: None,
+            floor: None,
+            ceiling: None,
+        };
+        for value in data {
+            ret = ret.include(value);
+        }
+        ret
+    }
+
+    /// Extend the range so that it includes the value
+    /// - `value`: The value to include, e.g. 0 to start the axis at zero
+    pub fn include(mut self, value: T) -> Self {
+        if value.partial_cmp(&value).is_none() {
+            return self;
+        }
+        self.bounds = match self.bounds {
+            None => Some((value.clone(), value)),
+            Some((min, max)) if value < min => Some((value, max)),
+            Some((min, max)) if value > max => Some((min, value)),
+            bounds => bounds,
+        };
+        self
+    }
+
+    /// Set the hard lower limit, the range never goes below it even if the data does
+    /// - `value`: The lower limit
+    pub fn floor(mut self, value: T) -> Self {
+        self.floor = Some(value);
+        self
+    }
+
+    /// Set the hard upper limit, the range never goes above it even if the data does
+    /// - `value`: The upper limit
+    pub fn ceiling(mut self, value: T) -> Self {
+        self.ceiling = Some(value);
+        self
+    }
+
+    /// Get the final range for `ChartBuilder::build_ranged`
+    /// - **returns**: The range, `None` if there's no data or the limits exclude all the data
+    pub fn build(self) -> Option<Range<T>> {
+        let (mut min, mut max) = self.bounds?;
+        if let Some(floor) = self.floor {
+            if min < floor {
+                min = floor;
+            }
+        }
+        if let Some(ceiling) = self.ceiling {
+            if max > ceiling {
+                max = ceiling;
+            }
+        }
+        if min < max {
+            Some(min..max)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_auto_range() {
+    let data = vec![-2.0, 3.0, std::f64::NAN, 7.5];
+    assert_eq!(AutoRange::from_data(data.clone()).build(), Some(-2.0..7.5));
+    assert_eq!(
+        AutoRange::from_data(data.clone()).floor(0.0).build(),
+        Some(0.0..7.5)
+    );
+    assert_eq!(
+        AutoRange::from_data(vec![2, 5])
+            .include(0)
+            .ceiling(4)
+            .build(),
+        Some(0..4)
+    );
+    assert_eq!(AutoRange::from_data(data).floor(8.0).build(), None);
+    assert_eq!(AutoRange::from_data(Vec::<i32>::new()).build(), None);
+}
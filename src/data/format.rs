@@ -0,0 +1,133 @@
+use std::fmt::Display;
+
+/// The number formatter with configurable decimal and digit grouping separators, which can be
+/// used as the label formatter, for example the European style:
+///
+/// ```ignore
+/// let fmt = NumberFormat::new().decimal_separator(',').group_separator('.');
+/// chart.configure_mesh().y_label_formatter(&|y| fmt.format(y)).draw()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct NumberFormat {
+    decimal_separator: char,
+    group_separator: Option<char>,
+    group_size: usize,
+    precision: Option<usize>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberFormat {
+    /// Create a new number formatter, which uses `.` as the decimal separator and groups the
+    /// digits by thousands with `,`
+    pub fn new() -> Self {
+        Self {
+            decimal_separator: '.',
+            group_separator: Some(','),
+            group_size: 3,
+            precision: None,
+        }
+    }
+
+    /// Set the decimal separator
+    /// - `sep`: The separator between the integer part and the fraction part
+    pub fn decimal_separator(mut self, sep: char) -> Self {
+        self.decimal_separator = sep;
+        self
+    }
+
+    /// Set the digit grouping separator
+    /// - `sep`: The separator between the groups of the integer part
+    pub fn group_separator(mut self, sep: char) -> Self {
+        self.group_separator = Some(sep);
+        self
+    }
+
+    /// Don't group the digits of the integer part
+    pub fn no_grouping(mut self) -> Self {
+        self.group_separator = None;
+        self
+    }
+
+    /// Set the number of digits in a group
+    /// - `size`: The number of digits, 3 by default
+    pub fn group_size(mut self, size: usize) -> Self {
+        self.group_size = size;
+        self
+    }
+
+    /// Set the number of digits after the decimal separator, by default the shortest
+    /// representation is used
+    /// - `digits`: The number of digits after the decimal separator
+    pub fn precision(mut self, digits: usize) -> Self {
+        self.precision = Some(digits);
+        self
+    }
+
+    /// Format the number
+    /// - `value`: The number to format
+    /// - **returns**: The formatted text
+    pub fn format<T: Display>(&self, value: &T) -> String {
+        let text = match self.precision {
+            Some(digits) => format!("{:.*}", digits, value),
+            None => format!("{}", value),
+        };
+
+        let (sign, body) = if text.starts_with('-') {
+            ("-", &text[1..])
+        } else {
+            ("", &text[..])
+        };
+
+        // Things like "inf" and "NaN" are kept as they are
+        if !body.chars().next().map_or(false, |c| c.is_ascii_digit()) {
+            return text;
+        }
+
+        let (int_part, frac_part) = match body.find('.') {
+            Some(pos) => (&body[..pos], Some(&body[pos + 1..])),
+            None => (body, None),
+        };
+
+        let mut result = sign.to_string();
+        for (idx, c) in int_part.chars().enumerate() {
+            let remaining = int_part.len() - idx;
+            if idx > 0 && self.group_size > 0 && remaining % self.group_size == 0 {
+                if let Some(sep) = self.group_separator {
+                    result.push(sep);
+                }
+            }
+            result.push(c);
+        }
+
+        if let Some(frac) = frac_part {
+            result.push(self.decimal_separator);
+            result.push_str(frac);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_number_format() {
+    let fmt = NumberFormat::new();
+    assert_eq!(fmt.format(&1_234_567), "1,234,567");
+    assert_eq!(fmt.format(&-1234.5), "-1,234.5");
+    assert_eq!(fmt.format(&123), "123");
+
+    let fmt = NumberFormat::new()
+        .decimal_separator(',')
+        .group_separator('.')
+        .precision(2);
+    assert_eq!(fmt.format(&1234567.891), "1.234.567,89");
+    assert_eq!(fmt.format(&std::f64::NAN), "NaN");
+
+    let fmt = NumberFormat::new().group_size(4).group_separator(' ');
+    assert_eq!(fmt.format(&123_456_789u64), "1 2345 6789");
+}
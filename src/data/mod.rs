@@ -1,7 +1,14 @@
 /*!
 The data processing module, which implmements algorithm related to visualization of data.
-Such as, downsampling, etc.
+Such as, downsampling, number formatting, etc.
 */
+
+mod auto_range;
+mod format;
+
+pub use auto_range::AutoRange;
+pub use format::NumberFormat;
+
 /*use std::marker::PhantomData;
 use crate::drawing::backend::DrawingBackend;
 use crate::drawing::coord::RangedCoord;
@@ -1,5 +1,6 @@
 /// The abstraction of a drawing area
-use super::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use super::backend::{BackendCoord, BackendMetadata, DrawingBackend, DrawingErrorKind};
+use super::{PolygonClipBackend, ReportProgress};
 use crate::coord::{CoordTranslate, MeshLine, Ranged, RangedCoord, Shift};
 use crate::element::{Drawable, PointCollection};
 use crate::style::{Color, TextStyle};
@@ -94,11 +95,16 @@ impl Rect {
             })
             .flatten()
     }
+}
 
-    /// Make the coordinate in the range of the rectangle
-    fn truncate(&self, p: (i32, i32)) -> (i32, i32) {
-        (p.0.min(self.x1).max(self.x0), p.1.min(self.y1).max(self.y0))
-    }
+/// How an image is scaled to the drawing area, see `DrawingArea::draw_background_image`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFit {
+    /// Stretch the image to fill the whole area, the aspect ratio isn't kept
+    Fill,
+    /// Scale the image to the largest size which fits in the area while keeping the aspect
+    /// ratio, the image is centered in the area
+    Fit,
 }
 
 /// The abstraction of a region
@@ -184,8 +190,10 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, RangedCoord<X, Y>
         DrawFunc: FnMut(&mut DB, MeshLine<X, Y>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
     {
         self.backend_ops(move |b| {
-            self.coord
-                .draw_mesh(y_count_max, x_count_max, |line| draw_func(b, line))
+            self.coord.draw_mesh(y_count_max, x_count_max, |line| {
+                b.ensure_prepared()?;
+                draw_func(b, line)
+            })
         })
     }
 
@@ -206,6 +214,26 @@ impl<DB: DrawingBackend, X: Ranged, Y: Ranged> DrawingArea<DB, RangedCoord<X, Y>
     pub fn get_y_axis_pixel_range(&self) -> Range<i32> {
         self.coord.get_y_axis_pixel_range()
     }
+
+    /// Get the pixel positions where the X axis is broken
+    pub fn get_x_axis_breaks(&self) -> Vec<i32> {
+        self.coord.get_x_axis_breaks()
+    }
+
+    /// Get the pixel positions where the Y axis is broken
+    pub fn get_y_axis_breaks(&self) -> Vec<i32> {
+        self.coord.get_y_axis_breaks()
+    }
+
+    /// Map a X value of the guest coordinate to the X backend coordinate
+    pub fn map_x(&self, value: &X::ValueType) -> i32 {
+        self.coord.map_x(value)
+    }
+
+    /// Map a Y value of the guest coordinate to the Y backend coordinate
+    pub fn map_y(&self, value: &Y::ValueType) -> i32 {
+        self.coord.map_y(value)
+    }
 }
 
 impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
@@ -265,6 +293,22 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         }
     }
 
+    /// Set the metadata attached to the shapes drawn on the backend after this call, e.g. the
+    /// role of the shapes in a chart. Unlike the drawing operations, this doesn't prepare the
+    /// backend for drawing.
+    /// - `metadata`: The metadata, `None` clears it
+    pub(crate) fn set_metadata(
+        &self,
+        metadata: Option<&BackendMetadata>,
+    ) -> Result<(), DrawingAreaError<DB>> {
+        if let Ok(mut db) = self.backend.try_borrow_mut() {
+            db.set_metadata(metadata)
+                .map_err(DrawingAreaErrorKind::BackendError)
+        } else {
+            Err(DrawingAreaErrorKind::SharingError)
+        }
+    }
+
     /// Fill the entire drawing area with a color
     pub fn fill<ColorType: Color>(&self, color: &ColorType) -> Result<(), DrawingAreaError<DB>> {
         self.backend_ops(|backend| {
@@ -277,6 +321,52 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         })
     }
 
+    /// Draw an image filling the drawing area, e.g. a map tile behind the data of a plot. The
+    /// image is scaled with the nearest neighbor.
+    /// - `pixels`: The pixels of the image row by row, 3 bytes per pixel in RGB order
+    /// - `size`: The dimension of the image
+    /// - `fit`: How the aspect ratio of the image is handled
+    /// - **returns**: A layout error if the number of the pixels doesn't match the dimension
+    pub fn draw_background_image(
+        &self,
+        pixels: &[u8],
+        size: (u32, u32),
+        fit: ImageFit,
+    ) -> Result<(), DrawingAreaError<DB>> {
+        if pixels.len() != size.0 as usize * size.1 as usize * 3 {
+            return Err(DrawingAreaErrorKind::LayoutError);
+        }
+
+        let width = (self.rect.x1 - self.rect.x0).max(0) as u32;
+        let height = (self.rect.y1 - self.rect.y0).max(0) as u32;
+        if width == 0 || height == 0 || size.0 == 0 || size.1 == 0 {
+            return Ok(());
+        }
+
+        let (pos, target) = match fit {
+            ImageFit::Fill => ((self.rect.x0, self.rect.y0), (width, height)),
+            ImageFit::Fit => {
+                let scale = (f64::from(width) / f64::from(size.0))
+                    .min(f64::from(height) / f64::from(size.1));
+                let w = ((f64::from(size.0) * scale).round() as u32)
+                    .max(1)
+                    .min(width);
+                let h = ((f64::from(size.1) * scale).round() as u32)
+                    .max(1)
+                    .min(height);
+                (
+                    (
+                        self.rect.x0 + ((width - w) / 2) as i32,
+                        self.rect.y0 + ((height - h) / 2) as i32,
+                    ),
+                    (w, h),
+                )
+            }
+        };
+
+        self.backend_ops(|b| b.blit_bitmap(pos, target, pixels, size))
+    }
+
     /// Draw a single pixel
     pub fn draw_pixel<ColorType: Color>(
         &self,
@@ -287,6 +377,45 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         self.backend_ops(|b| b.draw_pixel(pos, &color.to_rgba()))
     }
 
+    /// Draw a batch of pixels on the drawing area. Unlike calling `draw_pixel` for each pixel,
+    /// this only acquires the backend once, which is much faster for a large number of pixels,
+    /// e.g. a full-resolution heatmap.
+    /// - `pixels`: The iterator of the guest coordinates and colors of the pixels
+    pub fn draw_pixels<ColorType: Color, I: IntoIterator<Item = (CT::From, ColorType)>>(
+        &self,
+        pixels: I,
+    ) -> Result<(), DrawingAreaError<DB>> {
+        let coord = &self.coord;
+        self.backend_ops(|b| {
+            b.draw_pixels(
+                pixels
+                    .into_iter()
+                    .map(|(pos, color)| (coord.translate(&pos), color.to_rgba())),
+            )
+        })
+    }
+
+    /// Draw a batch of pixels on the drawing area and report the completion, e.g. to show a
+    /// progress bar while a large image is rendered. The callback is invoked whenever another
+    /// percent of the pixels is drawn, and with `1.0` at the end.
+    /// - `pixels`: The iterator of the guest coordinates and colors of the pixels
+    /// - `total`: The number of the pixels, if it's `None`, it's estimated from the size hint of
+    ///   the iterator, which is unknown for the nested loops like `flat_map`
+    /// - `progress`: The callback receiving the completed fraction
+    pub fn draw_pixels_with_progress<ColorType, I, F>(
+        &self,
+        pixels: I,
+        total: Option<usize>,
+        progress: F,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        ColorType: Color,
+        I: IntoIterator<Item = (CT::From, ColorType)>,
+        F: FnMut(f64),
+    {
+        self.draw_pixels(ReportProgress::new(pixels.into_iter(), total, progress))
+    }
+
     /// Present all the pending changes to the backend
     pub fn present(&self) -> Result<(), DrawingAreaError<DB>> {
         self.backend_ops(|b| b.present())
@@ -298,17 +427,164 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         &'a E: PointCollection<'a, CT::From>,
         E: Drawable<DB>,
     {
+        self.draw_inspected(element, |_| ())
+    }
+
+    /// Draw an high-level element, and inspect the backend coordinates of its points while the
+    /// element is drawn, so that they aren't mapped once again
+    /// - `element`: The element to draw
+    /// - `inspect`: Called with each backend coordinate the element takes for drawing
+    pub(crate) fn draw_inspected<'a, E, F>(
+        &self,
+        element: &'a E,
+        mut inspect: F,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, CT::From>,
+        E: Drawable<DB>,
+        F: FnMut(BackendCoord),
+    {
+        let backend_coords = element
+            .point_iter()
+            .into_iter()
+            .map(|p| self.coord.translate(p.borrow()))
+            .inspect(move |p| inspect(*p));
+        let clip = (
+            self.rect.x0..self.rect.x1 + 1,
+            self.rect.y0..self.rect.y1 + 1,
+        );
+        self.backend_ops(move |b| element.draw_clipped(backend_coords, clip, b))
+    }
+
+    /// Draw an high-level element whose points are confined to a sub-rectangle of the area
+    /// rather than the whole area, e.g. a series highlighting a sub-region of a chart
+    /// - `element`: The element to draw
+    /// - `clip`: The X and Y pixel ranges of the rectangle in the backend, which is intersected
+    ///   with the area
+    pub fn draw_clipped<'a, E>(
+        &self,
+        element: &'a E,
+        clip: (Range<i32>, Range<i32>),
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, CT::From>,
+        E: Drawable<DB>,
+    {
+        let rect = Rect {
+            x0: clip.0.start.max(self.rect.x0),
+            y0: clip.1.start.max(self.rect.y0),
+            x1: clip.0.end.min(self.rect.x1),
+            y1: clip.1.end.min(self.rect.y1),
+        };
+        if rect.x0 > rect.x1 || rect.y0 > rect.y1 {
+            return Ok(());
+        }
+        let backend_coords = element
+            .point_iter()
+            .into_iter()
+            .map(|p| self.coord.translate(p.borrow()));
+        let clip = (rect.x0..rect.x1 + 1, rect.y0..rect.y1 + 1);
+        self.backend_ops(move |b| element.draw_clipped(backend_coords, clip, b))
+    }
+
+    /// Draw an high-level element whose points out of the area are pinned to the boundary of
+    /// the area, so the shapes of the element stay visible at the edge rather than being clipped
+    /// - `element`: The element to draw
+    pub fn draw_clamped<'a, E>(&self, element: &'a E) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, CT::From>,
+        E: Drawable<DB>,
+    {
+        let rect = &self.rect;
         let backend_coords = element.point_iter().into_iter().map(|p| {
-            let b = p.borrow();
-            self.rect.truncate(self.coord.translate(b))
+            let (x, y) = self.coord.translate(p.borrow());
+            (x.max(rect.x0).min(rect.x1), y.max(rect.y0).min(rect.y1))
         });
-        self.backend_ops(move |b| element.draw(backend_coords, b))
+        let clip = (rect.x0..rect.x1 + 1, rect.y0..rect.y1 + 1);
+        self.backend_ops(move |b| element.draw_clipped(backend_coords, clip, b))
+    }
+
+    /// Draw an high-level element masked to the inside of a polygon, e.g. a heatmap cell clipped
+    /// to the boundary of a country. The vector backends, e.g. SVG, clip the shapes by
+    /// themselves. Otherwise the shapes are clipped during the rasterization, which is much
+    /// slower than the rectangle clipping, see `PolygonClipBackend`.
+    /// - `element`: The element to draw
+    /// - `polygon`: The vertices of the clip polygon in the backend coordinate
+    pub fn draw_clipped_to_polygon<'a, E>(
+        &self,
+        element: &'a E,
+        polygon: &[BackendCoord],
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        &'a E: PointCollection<'a, CT::From>,
+        E: Drawable<DB> + for<'b> Drawable<PolygonClipBackend<'b, DB>>,
+    {
+        let backend_coords = element
+            .point_iter()
+            .into_iter()
+            .map(|p| self.coord.translate(p.borrow()));
+        let clip = (
+            self.rect.x0..self.rect.x1 + 1,
+            self.rect.y0..self.rect.y1 + 1,
+        );
+        self.backend_ops(move |b| {
+            if b.set_clip_polygon(Some(polygon))? {
+                let result = element.draw_clipped(backend_coords, clip, b);
+                b.set_clip_polygon(None)?;
+                return result;
+            }
+            element.draw_clipped(
+                backend_coords,
+                clip,
+                &mut PolygonClipBackend::new(b, polygon),
+            )
+        })
     }
 
     /// Map coordinate to the backend coordinate
     pub fn map_coordinate(&self, coord: &CT::From) -> BackendCoord {
         self.coord.translate(coord)
     }
+
+    /// Map coordinate to the backend coordinate without clamping the pixel, so a value far
+    /// outside of the range shows where the coordinate mapping actually sends it. This is only
+    /// meant for debugging a customized `Ranged` implementation, use `map_coordinate` for drawing.
+    pub fn map_coordinate_raw(&self, coord: &CT::From) -> BackendCoord {
+        self.coord.translate_raw(coord)
+    }
+
+    /// Find the data point that is nearest to the given backend coordinate, this is typically
+    /// used to handle the click event of an interactive chart.
+    /// - `data`: The data points to search
+    /// - `pos`: The backend coordinate, for example, the position of the mouse click
+    /// - `radius`: The max distance in pixel between the backend coordinate and the data point
+    /// - **returns**: The index and the value of the nearest point, or `None` if there's no data point
+    ///   in the radius
+    pub fn find_nearest_point<P: Borrow<CT::From>, I: IntoIterator<Item = P>>(
+        &self,
+        data: I,
+        pos: BackendCoord,
+        radius: u32,
+    ) -> Option<(usize, P)> {
+        let max_dist = i64::from(radius) * i64::from(radius);
+        let mut nearest: Option<(i64, usize, P)> = None;
+
+        for (idx, point) in data.into_iter().enumerate() {
+            let (x, y) = self.coord.translate(point.borrow());
+            let (dx, dy) = (i64::from(x - pos.0), i64::from(y - pos.1));
+            let dist = dx * dx + dy * dy;
+
+            if dist > max_dist {
+                continue;
+            }
+
+            if nearest.as_ref().map_or(true, |(d, _, _)| *d > dist) {
+                nearest = Some((dist, idx, point));
+            }
+        }
+
+        nearest.map(|(_, idx, point)| (idx, point))
+    }
 }
 
 impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
@@ -477,10 +753,149 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             )
         })
     }
+
+    /// Draw text on the drawing area at a sub-pixel position, this avoids the jitter when the
+    /// text is moving in an animation
+    pub fn draw_text_subpixel(
+        &self,
+        text: &str,
+        style: &TextStyle,
+        pos: (f32, f32),
+    ) -> Result<(), DrawingAreaError<DB>> {
+        self.backend_ops(|b| {
+            b.draw_text_subpixel(
+                text,
+                &style.font,
+                (pos.0 + self.rect.x0 as f32, pos.1 + self.rect.y0 as f32),
+                &style.color,
+            )
+        })
+    }
 }
 
 impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     pub fn into_coord_spec(self) -> CT {
         self.coord
     }
+
+    /// Get a reference to the coordinate specification of the drawing area
+    pub fn as_coord_spec(&self) -> &CT {
+        &self.coord
+    }
+
+    /// Get a mutable reference to the coordinate specification of the drawing area
+    pub(crate) fn as_coord_spec_mut(&mut self) -> &mut CT {
+        &mut self.coord
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_find_nearest_point() {
+        let area =
+            crate::create_mocked_drawing_area(100, 100, |_| {}).apply_coord_spec(RangedCoord::<
+                RangedCoordi32,
+                RangedCoordi32,
+            >::new(
+                0..10,
+                0..10,
+                (0..100, 100..0),
+            ));
+
+        let data = vec![(1, 1), (5, 5), (6, 5)];
+
+        assert_eq!(
+            area.find_nearest_point(data.iter(), (52, 48), 5),
+            Some((1, &(5, 5)))
+        );
+        assert_eq!(area.find_nearest_point(data.iter(), (30, 30), 5), None);
+    }
+
+    #[test]
+    fn test_draw_pixels() {
+        let mut backend = MockedBackend::new(100, 100);
+        let calls = backend.record_calls();
+        backend.drop_check(|b| {
+            // The whole batch is drawn with a single backend access
+            assert_eq!(b.draw_count, 1);
+        });
+        let area = backend.into_drawing_area().margin(0, 0, 50, 0);
+
+        area.draw_pixels((0..10).map(|i| ((i, i), RED)))
+            .expect("Drawing Failure");
+        assert_eq!(
+            calls.pixels(),
+            (0..10)
+                .map(|i| (RED.to_rgba(), (i + 50, i)))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_draw_background_image() {
+        let mut backend = MockedBackend::new(4, 4);
+        let calls = backend.record_calls();
+        backend.drop_check(|b| {
+            assert_eq!(b.draw_count, 1);
+        });
+        let area = backend.into_drawing_area();
+
+        let image = [255, 0, 0, 0, 0, 255];
+        assert!(area
+            .draw_background_image(&image[..3], (2, 1), ImageFit::Fit)
+            .is_err());
+        area.draw_background_image(&image, (2, 1), ImageFit::Fit)
+            .expect("Drawing Failure");
+
+        // The image is scaled to fit the width and centered vertically
+        let mut expected = vec![];
+        for y in 1..3 {
+            for x in 0..4 {
+                let color = if x < 2 { RED } else { BLUE };
+                expected.push((color.to_rgba(), (x, y)));
+            }
+        }
+        let mut pixels = calls.pixels();
+        pixels.sort_by_key(|(_, (x, y))| (*y, *x));
+        assert_eq!(pixels, expected);
+    }
+
+    #[test]
+    fn test_draw_text_subpixel() {
+        // The horizontal center of the coverage of the text drawn on a bitmap
+        let center = |x: f32| {
+            let mut buffer = vec![];
+            {
+                let area = BitMapBackend::with_buffer(&mut buffer, (60, 20))
+                    .into_drawing_area()
+                    .margin(0, 0, 20, 0);
+                area.draw_text_subpixel(
+                    "l",
+                    &FontDesc::from(("sans-serif", 20)).color(&WHITE),
+                    (x, 0.0),
+                )
+                .expect("Drawing Failure");
+                // The image is written to the buffer once the backend is dropped
+            }
+            let (mut sum, mut weight) = (0.0, 0.0);
+            for (idx, pixel) in buffer.chunks(3).enumerate() {
+                let v = f64::from(pixel[0]);
+                sum += (idx % 60) as f64 * v;
+                weight += v;
+            }
+            assert!(weight > 0.0);
+            sum / weight
+        };
+
+        // The area is shifted by the margin, and the glyph coverage moves with the fractional
+        // part of the position rather than snapping to the pixel grid
+        let base = center(10.0);
+        assert!(base > 30.0 && base < 40.0);
+        let half = center(10.5) - base;
+        assert!(half > 0.3 && half < 0.7);
+        assert!((center(11.0) - base - 1.0).abs() < 0.05);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::style::{Color, FontDesc, FontError, RGBAColor};
+use crate::style::{Color, FontDesc, FontError, FontTransform, RGBAColor, RGBColor};
 use std::error::Error;
 
 /// A coordiante in the image
@@ -41,6 +41,129 @@ impl<T: Color> BackendStyle for T {
     }
 }
 
+/// The rule deciding which parts of a self-intersecting polygon are inside of it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the outline an odd number of times
+    EvenOdd,
+    /// A point is inside if the outline winds around it a nonzero number of times
+    NonZero,
+}
+
+/// Get the ratio of the alpha at the center of the pixel row in a vertical gradient, which
+/// fades out from the `from` row to the `to` row. Past the `to` row the gradient fades in again
+/// at the same rate, and it's clamped to the full alpha.
+pub(crate) fn vertical_gradient_ratio(y: i32, (from, to): (i32, i32)) -> f64 {
+    let height = f64::from(to - from).abs();
+    if height > 0.0 {
+        ((f64::from(y) + 0.5 - f64::from(to)).abs() / height).min(1.0)
+    } else {
+        1.0
+    }
+}
+
+/// Normalize a rectangle with rounded top corners, the radius is clamped to the half of the
+/// width and to the height of the rectangle
+/// - **returns**: The upper left and bottom right corners and the clamped radius
+pub(crate) fn top_rounded_rect(
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+    radius: u32,
+) -> (BackendCoord, BackendCoord, i32) {
+    let (x0, y0) = (
+        upper_left.0.min(bottom_right.0),
+        upper_left.1.min(bottom_right.1),
+    );
+    let (x1, y1) = (
+        upper_left.0.max(bottom_right.0),
+        upper_left.1.max(bottom_right.1),
+    );
+    let radius = (radius as i32).min((x1 - x0) / 2).min(y1 - y0);
+    ((x0, y0), (x1, y1), radius)
+}
+
+/// Get the spans inside of the polygon along a horizontal line. Each crossing point carries
+/// the direction of its edge, so the spans can be found by either rule
+/// - `vert`: The vertices of the polygon
+/// - `fy`: The Y coordinate of the line
+/// - `rule`: The fill rule
+/// - **returns**: The left and right ends of the spans
+pub(crate) fn polygon_spans_at(vert: &[BackendCoord], fy: f64, rule: FillRule) -> Vec<(f64, f64)> {
+    let mut crossing = vec![];
+    for (idx, &(x0, y0)) in vert.iter().enumerate() {
+        let (x1, y1) = vert[(idx + 1) % vert.len()];
+        let (fy0, fy1) = (f64::from(y0), f64::from(y1));
+        if (fy0 <= fy) != (fy1 <= fy) {
+            let x = f64::from(x0) + (fy - fy0) * f64::from(x1 - x0) / (fy1 - fy0);
+            crossing.push((x, if y1 > y0 { 1 } else { -1 }));
+        }
+    }
+    crossing.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let mut spans = vec![];
+    let mut winding = 0;
+    for (idx, &(x, dir)) in crossing.iter().enumerate() {
+        winding += dir;
+        let inside = match rule {
+            FillRule::EvenOdd => idx % 2 == 0,
+            FillRule::NonZero => winding != 0,
+        };
+        if let (true, Some(&(next, _))) = (inside, crossing.get(idx + 1)) {
+            spans.push((x, next));
+        }
+    }
+    spans
+}
+
+/// Scan the polygon line by line at the center of the pixel rows and call the function with
+/// the row and the first and last pixel of each span inside of the polygon
+pub(crate) fn for_each_polygon_span<E, F: FnMut(i32, i32, i32) -> Result<(), E>>(
+    vert: &[BackendCoord],
+    rule: FillRule,
+    mut span: F,
+) -> Result<(), E> {
+    if vert.len() < 3 {
+        return Ok(());
+    }
+
+    let min_y = vert.iter().map(|p| p.1).min().unwrap();
+    let max_y = vert.iter().map(|p| p.1).max().unwrap();
+
+    for y in min_y..max_y {
+        for (x, next) in polygon_spans_at(vert, f64::from(y) + 0.5, rule) {
+            let (left, right) = (x.round() as i32, next.round() as i32 - 1);
+            if left <= right {
+                span(y, left, right)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The metadata attached to the shapes drawn on the backend, for example the data value
+/// of the element. Backends that are not able to carry any metadata simply ignore it.
+#[derive(Clone, Debug, Default)]
+pub struct BackendMetadata {
+    /// The custom key-value attributes, which the SVG backend emits as element attributes
+    pub attributes: Vec<(String, String)>,
+    /// The tooltip text, which the SVG backend emits as a `<title>` child of the shapes
+    pub tooltip: Option<String>,
+    /// The CSS class names, which the SVG backend appends to the `class` attribute of the shapes
+    pub classes: Vec<String>,
+}
+
+impl BackendMetadata {
+    /// Create the metadata with a single CSS class name
+    /// - `class`: The class name, e.g. `plotters-axis`
+    pub fn with_class<S: Into<String>>(class: S) -> Self {
+        Self {
+            classes: vec![class.into()],
+            ..Self::default()
+        }
+    }
+}
+
 ///  The drawing backend trait, which implemenets the low-level drawing APIs.
 ///  This trait has a set of default implementation. And the minimal requirement of
 ///  implementing a drawing backend is implementing the `draw_pixel` function.
@@ -65,6 +188,37 @@ pub trait DrawingBackend {
     /// pending changes on the screen.
     fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
 
+    /// Set the metadata that is attached to all the shapes drawn after this call.
+    /// Passing `None` clears the metadata. The default implementation ignores the metadata.
+    /// - `metadata`: The metadata to attach
+    fn set_metadata(
+        &mut self,
+        _metadata: Option<&BackendMetadata>,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    /// Blur all the shapes drawn after this call, which is used to draw soft drop shadows.
+    /// Passing 0 turns the blur off. The default implementation can't blur, so the caller
+    /// falls back to approximating the blur with several translucent copies.
+    /// - `radius`: The blur radius in pixels
+    /// - **returns**: If the backend blurs the following shapes by itself
+    fn set_blur(&mut self, _radius: u32) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        Ok(false)
+    }
+
+    /// Mask all the shapes drawn after this call to the inside of a polygon, which is used by
+    /// `DrawingArea::draw_clipped_to_polygon`. Passing `None` turns the mask off. The default
+    /// implementation can't clip, so the caller falls back to `PolygonClipBackend`.
+    /// - `polygon`: The vertices of the clip polygon
+    /// - **returns**: If the backend clips the following shapes by itself
+    fn set_clip_polygon(
+        &mut self,
+        _polygon: Option<&[BackendCoord]>,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        Ok(false)
+    }
+
     /// Draw a pixel on the drawing backend
     /// - `point`: The backend pixel-based coordinate to draw
     /// - `color`: The color of the pixel
@@ -74,6 +228,45 @@ pub trait DrawingBackend {
         color: &RGBAColor,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>>;
 
+    /// Draw a batch of pixels on the drawing backend. The default implementation draws the
+    /// pixels one by one, backends which are able to write the buffer directly may override it.
+    /// - `pixels`: The iterator of the pixel coordinates and colors
+    fn draw_pixels<I: IntoIterator<Item = (BackendCoord, RGBAColor)>>(
+        &mut self,
+        pixels: I,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        for (point, color) in pixels {
+            self.draw_pixel(point, &color)?;
+        }
+        Ok(())
+    }
+
+    /// Draw a RGB image scaled to a rectangle on the drawing backend, each pixel of the
+    /// rectangle takes the nearest pixel of the image. The default implementation draws the
+    /// pixels with `draw_pixels`.
+    /// - `pos`: The upper left corner of the rectangle
+    /// - `size`: The size of the rectangle in pixels
+    /// - `src`: The pixels of the image row by row, 3 bytes per pixel in RGB order
+    /// - `src_size`: The dimension of the image
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+        src_size: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if src_size.0 == 0 || src_size.1 == 0 {
+            return Ok(());
+        }
+        self.draw_pixels((0..size.1).flat_map(move |y| {
+            (0..size.0).map(move |x| {
+                let idx = bitmap_source_offset((x, y), size, src_size);
+                let color = RGBColor(src[idx], src[idx + 1], src[idx + 2]).to_rgba();
+                ((pos.0 + x as i32, pos.1 + y as i32), color)
+            })
+        }))
+    }
+
     /// Draw a line on the drawing backend
     /// - `from`: The start point of the line
     /// - `to`: The end point of the line
@@ -184,6 +377,106 @@ pub trait DrawingBackend {
         Ok(())
     }
 
+    /// Draw a rectangle with rounded corners, the radius is clamped to the half of the shorter
+    /// side of the rectangle.
+    /// The default implementation approximates the corners with polylines.
+    /// - `upper_left`: The upper left corner of the rectangle
+    /// - `bottom_right`: The bottom right corner of the rectangle
+    /// - `radius`: The radius of the corners
+    /// - `style`: The style of the rectangle
+    /// - `fill`: If the rectangle should be filled
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let (x0, y0) = (
+            upper_left.0.min(bottom_right.0),
+            upper_left.1.min(bottom_right.1),
+        );
+        let (x1, y1) = (
+            upper_left.0.max(bottom_right.0),
+            upper_left.1.max(bottom_right.1),
+        );
+        let radius = (radius as i32).min((x1 - x0).min(y1 - y0) / 2);
+        if radius <= 0 {
+            return self.draw_rect((x0, y0), (x1, y1), style, fill);
+        }
+
+        // Each corner is an arc of a quarter circle, going clockwise from the top-right one
+        let steps = (radius / 2).max(2).min(16);
+        let r = f64::from(radius);
+        let corners = [
+            (x1 - radius, y0 + radius, -90.0f64),
+            (x1 - radius, y1 - radius, 0.0),
+            (x0 + radius, y1 - radius, 90.0),
+            (x0 + radius, y0 + radius, 180.0),
+        ];
+        let mut vert = vec![];
+        for (cx, cy, start) in corners.iter() {
+            for step in 0..=steps {
+                let angle = (start + 90.0 * f64::from(step) / f64::from(steps)).to_radians();
+                vert.push((
+                    cx + (r * angle.cos()).round() as i32,
+                    cy + (r * angle.sin()).round() as i32,
+                ));
+            }
+        }
+
+        if fill {
+            self.fill_polygon(vert, style)
+        } else {
+            vert.push(vert[0]);
+            self.draw_path(vert, style)
+        }
+    }
+
+    /// Fill a rectangle with the top corners rounded, e.g. a bar with a rounded cap. The radius
+    /// is clamped to the half of the width and to the height of the rectangle.
+    /// The default implementation fills a single polygon, whose top corners are approximated
+    /// with polylines, so a translucent style is blended only once.
+    /// - `upper_left`: The upper left corner of the rectangle
+    /// - `bottom_right`: The bottom right corner of the rectangle
+    /// - `radius`: The radius of the top corners
+    /// - `style`: The style of the rectangle
+    fn fill_top_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let ((x0, y0), (x1, y1), radius) = top_rounded_rect(upper_left, bottom_right, radius);
+        if radius <= 0 {
+            return self.draw_rect((x0, y0), (x1, y1), style, true);
+        }
+
+        // The outline goes up the left side, over the two rounded corners and down the right
+        let steps = (radius / 2).max(2).min(16);
+        let r = f64::from(radius);
+        let mut vert = vec![(x0, y1)];
+        for (cx, start) in [(x0 + radius, 180.0f64), (x1 - radius, 270.0)].iter() {
+            for step in 0..=steps {
+                let angle = (start + 90.0 * f64::from(step) / f64::from(steps)).to_radians();
+                vert.push((
+                    cx + (r * angle.cos()).round() as i32,
+                    y0 + radius + (r * angle.sin()).round() as i32,
+                ));
+            }
+        }
+        vert.push((x1, y1));
+        self.fill_polygon(vert, style)
+    }
+
     /// Draw a path on the drawing backend
     /// - `path`: The iterator of key points of the path
     /// - `style`: The style of the path
@@ -206,6 +499,99 @@ pub trait DrawingBackend {
         Ok(())
     }
 
+    /// Fill a polygon on the drawing backend
+    /// - `vert`: The iterator of the vertices of the polygon
+    /// - `style`: The style of the polygon
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.fill_polygon_with_rule(vert, style, FillRule::EvenOdd)
+    }
+
+    /// Fill a polygon on the drawing backend with the given fill rule, which matters only when
+    /// the outline of the polygon intersects itself, e.g. a star
+    /// - `vert`: The iterator of the vertices of the polygon
+    /// - `style`: The style of the polygon
+    /// - `rule`: The fill rule
+    fn fill_polygon_with_rule<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+        rule: FillRule,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+
+        let vert: Vec<_> = vert.into_iter().collect();
+        for_each_polygon_span(&vert, rule, |y, left, right| {
+            self.draw_line((left, y), (right, y), style)
+        })
+    }
+
+    /// Fill a polygon with a vertical linear gradient, the alpha of the style is used at the
+    /// first row of the gradient and fades out linearly to transparent at the second row, which
+    /// may be above the first one. The rows on the other side of the second row fade in again
+    /// at the same rate, e.g. an area on both sides of its baseline. The default implementation
+    /// fills the polygon row by row with the interpolated alpha.
+    /// - `vert`: The iterator of the vertices of the polygon
+    /// - `style`: The style of the polygon
+    /// - `rows`: The opaque and the transparent rows of the gradient, the alpha is clamped to the
+    ///   alpha of the style beyond the opaque row
+    fn fill_polygon_with_vertical_gradient<
+        S: BackendStyle,
+        I: IntoIterator<Item = BackendCoord>,
+    >(
+        &mut self,
+        vert: I,
+        style: &S,
+        rows: (i32, i32),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = style.as_color();
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+
+        let vert: Vec<_> = vert.into_iter().collect();
+        for_each_polygon_span(&vert, FillRule::EvenOdd, |y, left, right| {
+            let ratio = vertical_gradient_ratio(y, rows);
+            if ratio > 0.0 {
+                self.draw_line((left, y), (right, y), &color.mix(ratio))?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Draw an arc, which is a part of the outline of a circle, on the drawing backend. The
+    /// default implementation approximates the arc with a path, one point for each degree.
+    /// - `center`: The center coordinate of the circle
+    /// - `radius`: The radius of the circle
+    /// - `angles`: The start and end angle in radian, measured clockwise from 12 o'clock, the
+    ///   end angle is not less than the start angle
+    /// - `style`: The style of the arc
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        (start, end): (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let steps = ((end - start).abs().to_degrees().ceil() as usize).max(1);
+        let radius = f64::from(radius);
+        self.draw_path(
+            (0..=steps).map(|idx| {
+                let angle = start + (end - start) * idx as f64 / steps as f64;
+                (
+                    center.0 + (radius * angle.sin()).round() as i32,
+                    center.1 - (radius * angle.cos()).round() as i32,
+                )
+            }),
+            style,
+        )
+    }
+
     /// Draw a circle on the drawing backend
     /// - `center`: The center coordinate of the circle
     /// - `radius`: The radius of the circle
@@ -293,4 +679,255 @@ pub trait DrawingBackend {
             Err(font_error) => Err(DrawingErrorKind::FontError(font_error)),
         }
     }
+
+    /// Draw a text at a sub-pixel position, which avoids the jitter of a moving text caused by
+    /// snapping it to the pixel grid.
+    /// The default implementation rasterizes the glyphs with the fractional offset.
+    /// - `text`: The text to draw
+    /// - `font`: The description of the font
+    /// - `pos` : The position of the text, in pixels but not necessarily integers
+    /// - `color`: The color of the text
+    fn draw_text_subpixel<'a>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        pos: (f32, f32),
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+
+        match font.draw_subpixel(text, pos, |x, y, v| {
+            self.draw_pixel((x, y), &color.mix(f64::from(v)))
+        }) {
+            Ok(drawing_result) => drawing_result,
+            Err(font_error) => Err(DrawingErrorKind::FontError(font_error)),
+        }
+    }
+
+    /// Draw a text along a path, each glyph sits on the path and is rotated to the local
+    /// direction of the path. The glyphs that don't fit on the path are dropped.
+    /// The default implementation rasterizes each glyph and rotates it pixel by pixel.
+    /// - `text`: The text to draw
+    /// - `font`: The description of the font
+    /// - `path`: The polyline the text follows
+    /// - `color`: The color of the text
+    fn draw_text_on_path<'a>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        path: &[BackendCoord],
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if color.alpha() == 0.0 || path.len() < 2 {
+            return Ok(());
+        }
+
+        let font = font.transform(FontTransform::None);
+        let mut offset = 0.0;
+
+        for glyph in text.chars().map(|c| c.to_string()) {
+            let (w, h) = font.box_size(&glyph).map_err(DrawingErrorKind::FontError)?;
+            let (w, h) = (w as i32, h as i32);
+            let distance = offset + f64::from(w) / 2.0;
+            offset += f64::from(w);
+
+            let ((cx, cy), (dx, dy)) = match point_on_path(path, distance) {
+                Some(what) => what,
+                None => break,
+            };
+
+            // Rasterize the glyph, then map each target pixel back to the glyph, whose
+            // bottom center is placed on the path
+            let mut raster = vec![0f32; (w * h).max(0) as usize];
+            font.draw(&glyph, (0, 0), |x, y, v| {
+                if x >= 0 && x < w && y >= 0 && y < h {
+                    raster[(y * w + x) as usize] = v;
+                }
+                Ok::<(), DrawingErrorKind<Self::ErrorType>>(())
+            })
+            .map_err(DrawingErrorKind::FontError)??;
+
+            let radius = (f64::from(w * w) / 4.0 + f64::from(h * h)).sqrt().ceil() as i32;
+            let (px, py) = (cx.round() as i32, cy.round() as i32);
+            for y in py - radius..=py + radius {
+                for x in px - radius..=px + radius {
+                    let (rx, ry) = (f64::from(x) - cx, f64::from(y) - cy);
+                    let gx = (rx * dx + ry * dy + f64::from(w) / 2.0).floor() as i32;
+                    let gy = (ry * dx - rx * dy + f64::from(h)).floor() as i32;
+                    if gx < 0 || gx >= w || gy < 0 || gy >= h {
+                        continue;
+                    }
+                    let v = raster[(gy * w + gx) as usize];
+                    if v > 0.0 {
+                        self.draw_pixel((x, y), &color.mix(f64::from(v)))?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Find the point on a polyline at the given distance from the start of it
+/// - `path`: The polyline
+/// - `distance`: The distance along the polyline
+/// - **returns**: The point and the unit direction of the polyline at that point, `None` if
+///   the polyline is shorter than the distance
+pub(crate) fn point_on_path(
+    path: &[BackendCoord],
+    distance: f64,
+) -> Option<((f64, f64), (f64, f64))> {
+    let mut acc = 0.0;
+    for segment in path.windows(2) {
+        let (x0, y0) = (f64::from(segment[0].0), f64::from(segment[0].1));
+        let (x1, y1) = (f64::from(segment[1].0), f64::from(segment[1].1));
+        let len = ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt();
+        if len > 0.0 && distance <= acc + len {
+            let t = (distance - acc) / len;
+            let dir = ((x1 - x0) / len, (y1 - y0) / len);
+            return Some(((x0 + (x1 - x0) * t, y0 + (y1 - y0) * t), dir));
+        }
+        acc += len;
+    }
+    None
+}
+
+/// Find the pixel of a scaled image in the source buffer
+/// - `pos`: The pixel position in the scaled image
+/// - `size`: The size of the scaled image
+/// - `src_size`: The dimension of the source image
+/// - **returns**: The offset of the nearest source pixel in a RGB buffer
+pub(crate) fn bitmap_source_offset(
+    pos: (u32, u32),
+    size: (u32, u32),
+    src_size: (u32, u32),
+) -> usize {
+    let x = u64::from(pos.0) * u64::from(src_size.0) / u64::from(size.0.max(1));
+    let y = u64::from(pos.1) * u64::from(src_size.1) / u64::from(size.1.max(1));
+    ((y * u64::from(src_size.0) + x) * 3) as usize
+}
+
+#[cfg(test)]
+#[test]
+fn test_top_rounded_rect() {
+    assert_eq!(
+        top_rounded_rect((10, 50), (30, 0), 4),
+        ((10, 0), (30, 50), 4)
+    );
+    assert_eq!(
+        top_rounded_rect((0, 0), (20, 50), 100),
+        ((0, 0), (20, 50), 10)
+    );
+    assert_eq!(top_rounded_rect((0, 0), (20, 3), 100), ((0, 0), (20, 3), 3));
+}
+
+#[cfg(test)]
+#[test]
+fn test_fill_top_rounded_rect() {
+    use crate::drawing::MockedBackend;
+    use crate::style::RED;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    backend.ensure_prepared().unwrap();
+    backend
+        .fill_top_rounded_rect((10, 50), (30, 0), 4, &RED.mix(0.5))
+        .unwrap();
+
+    // The whole shape is a single polygon, so nothing is painted twice
+    assert_eq!(calls.all().len(), 1);
+    let polygons = calls.polygons();
+    let vert = &polygons[0].1;
+    assert_eq!(vert[0], (10, 50));
+    assert_eq!(vert[1], (10, 4));
+    assert!(vert.contains(&(14, 0)) && vert.contains(&(26, 0)));
+    assert_eq!(vert[vert.len() - 2], (30, 4));
+    assert_eq!(vert[vert.len() - 1], (30, 50));
+    assert!(vert
+        .iter()
+        .all(|&(x, y)| x >= 10 && x <= 30 && y >= 0 && y <= 50));
+}
+
+/// A backend which only paints pixels, so that the default fillers are used, the painted
+/// pixels are recorded with their colors
+#[cfg(test)]
+struct PixelRecorder(Vec<(BackendCoord, RGBAColor)>);
+
+#[cfg(test)]
+impl DrawingBackend for PixelRecorder {
+    type ErrorType = std::fmt::Error;
+    fn get_size(&self) -> (u32, u32) {
+        (100, 100)
+    }
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if color.alpha() > 0.0 {
+            self.0.push((point, color.clone()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_fill_polygon_with_rule() {
+    use crate::style::RED;
+
+    let star = vec![(50, 0), (80, 90), (0, 35), (100, 35), (20, 90)];
+    for &(rule, center_filled) in &[(FillRule::EvenOdd, false), (FillRule::NonZero, true)] {
+        let mut backend = PixelRecorder(vec![]);
+        backend
+            .fill_polygon_with_rule(star.clone(), &RED, rule)
+            .unwrap();
+        let filled = |p: BackendCoord| backend.0.iter().any(|(q, _)| *q == p);
+        // The pentagon in the middle is wound twice, the tips of the star once
+        assert_eq!(filled((50, 50)), center_filled);
+        assert!(filled((50, 20)));
+        assert!(filled((15, 40)));
+        assert!(!filled((5, 80)));
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_fill_polygon_with_vertical_gradient() {
+    use crate::style::RED;
+
+    // An area from the row 0 down to the row 40, with the baseline at the row 30
+    let mut backend = PixelRecorder(vec![]);
+    backend
+        .fill_polygon_with_vertical_gradient(
+            vec![(0, 0), (10, 0), (10, 40), (0, 40)],
+            &RED.mix(0.8),
+            (0, 30),
+        )
+        .unwrap();
+    let alpha = |y: i32| {
+        let row: Vec<_> = backend
+            .0
+            .iter()
+            .filter(|(p, _)| p.1 == y)
+            .map(|(_, c)| c.alpha())
+            .collect();
+        assert_eq!(row.len(), 10);
+        assert!(row.iter().all(|a| *a == row[0]));
+        row[0]
+    };
+    assert!((alpha(0) - 0.8 * 29.5 / 30.0).abs() < 1e-6);
+    assert!((0..29).all(|y| alpha(y) > alpha(y + 1)));
+    // The rows below the baseline fade in again at the same rate
+    assert!((0..10).all(|k| (alpha(29 - k) - alpha(30 + k)).abs() < 1e-6));
+    assert!((alpha(39) - 0.8 * 9.5 / 30.0).abs() < 1e-6);
 }
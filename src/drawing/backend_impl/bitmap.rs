@@ -1,14 +1,22 @@
-use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    bitmap_source_offset, for_each_polygon_span, polygon_spans_at, vertical_gradient_ratio,
+    BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, FillRule,
+};
 use crate::style::{Color, RGBAColor};
-use image::{ImageError, Rgb, RgbImage};
+use image::png::PNGEncoder;
+use image::{ColorType, ImageError, Rgb, RgbImage};
 
 use std::path::Path;
 
 enum Target<'a> {
     File(&'a Path),
     Buffer(&'a mut Vec<u8>),
+    Memory,
 }
 
+/// The number of the scanlines sampled in each pixel row of an anti-aliased fill
+const AA_SCANLINES: u32 = 4;
+
 /// The backend that drawing a bitmap
 pub struct BitMapBackend<'a> {
     /// The path to the image
@@ -17,6 +25,8 @@ pub struct BitMapBackend<'a> {
     img: RgbImage,
     /// Flag indicates if the bitmap has been saved
     saved: bool,
+    /// Flag indicates if the edges of the filled polygons are anti-aliased
+    antialias: bool,
 }
 
 impl<'a> BitMapBackend<'a> {
@@ -26,6 +36,7 @@ impl<'a> BitMapBackend<'a> {
             target: Target::File(path.as_ref()),
             img: RgbImage::new(dimension.0, dimension.1),
             saved: false,
+            antialias: false,
         }
     }
 
@@ -35,7 +46,96 @@ impl<'a> BitMapBackend<'a> {
             target: Target::Buffer(buf),
             img: RgbImage::new(dimension.0, dimension.1),
             saved: false,
+            antialias: false,
+        }
+    }
+
+    /// Create a new bitmap backend which only keeps the image in memory,
+    /// use `encode_png` to get the image once the drawing is done
+    pub fn in_memory(dimension: (u32, u32)) -> Self {
+        Self {
+            target: Target::Memory,
+            img: RgbImage::new(dimension.0, dimension.1),
+            saved: false,
+            antialias: false,
+        }
+    }
+
+    /// Anti-alias the edges of the filled polygons, e.g. the areas and the pie slices, by
+    /// blending the edge pixels with the fraction of them covered by the polygon
+    /// - `enabled`: If the fills are anti-aliased, it's disabled by default
+    pub fn antialias(mut self, enabled: bool) -> Self {
+        self.antialias = enabled;
+        self
+    }
+
+    /// Fill the polygon with the coverage of each pixel, which is sampled on a few scanlines
+    /// per row and computed exactly along each scanline
+    /// - `vert`: The vertices of the polygon
+    /// - `color`: The color of the fill
+    /// - `rule`: The fill rule
+    /// - `row_alpha`: The ratio of the alpha of each row
+    fn fill_polygon_antialiased<F: Fn(i32) -> f64>(
+        &mut self,
+        vert: &[BackendCoord],
+        color: &RGBAColor,
+        rule: FillRule,
+        row_alpha: F,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        if vert.len() < 3 || color.alpha() == 0.0 {
+            return Ok(());
+        }
+        let (width, height) = (self.img.width() as i32, self.img.height() as i32);
+        let left = vert.iter().map(|p| p.0).min().unwrap().max(0);
+        let right = vert.iter().map(|p| p.0).max().unwrap().min(width);
+        let top = vert.iter().map(|p| p.1).min().unwrap().max(0);
+        let bottom = vert.iter().map(|p| p.1).max().unwrap().min(height);
+        if left >= right {
+            return Ok(());
         }
+
+        let weight = 1.0 / f64::from(AA_SCANLINES);
+        let mut coverage = vec![0.0; (right - left) as usize];
+        for y in top..bottom {
+            coverage.iter_mut().for_each(|c| *c = 0.0);
+            for k in 0..AA_SCANLINES {
+                let fy = f64::from(y) + (f64::from(k) + 0.5) * weight;
+                for (x0, x1) in polygon_spans_at(vert, fy, rule) {
+                    let (x0, x1) = (x0.max(f64::from(left)), x1.min(f64::from(right)));
+                    let mut px = x0.floor();
+                    while px < x1 {
+                        let overlap = x1.min(px + 1.0) - x0.max(px);
+                        coverage[(px as i32 - left) as usize] += overlap * weight;
+                        px += 1.0;
+                    }
+                }
+            }
+
+            let alpha = row_alpha(y);
+            for (idx, c) in coverage.iter().enumerate() {
+                if *c > 0.0 && alpha > 0.0 {
+                    let pixel_color = color.mix(c.min(1.0) * alpha);
+                    self.draw_pixel((left + idx as i32, y), &pixel_color)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Finish the drawing and encode the image as PNG, the image isn't written to the target
+    /// of the backend
+    pub fn encode_png(mut self) -> Result<Vec<u8>, ImageError> {
+        self.saved = true;
+        let mut buffer = vec![];
+        PNGEncoder::new(&mut buffer)
+            .encode(
+                &self.img,
+                self.img.width(),
+                self.img.height(),
+                ColorType::RGB(8),
+            )
+            .map_err(ImageError::IoError)?;
+        Ok(buffer)
     }
 }
 
@@ -66,6 +166,7 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
                 target.append(&mut actual_img.into_raw());
                 Ok(())
             }
+            Target::Memory => Ok(()),
         }
     }
 
@@ -109,6 +210,112 @@ impl<'a> DrawingBackend for BitMapBackend<'a> {
         }
         Ok(())
     }
+
+    fn draw_pixels<I: IntoIterator<Item = (BackendCoord, RGBAColor)>>(
+        &mut self,
+        pixels: I,
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        // The pixels are blended into the raw buffer, without looking up the image per pixel
+        let (width, height) = (self.img.width() as i32, self.img.height() as i32);
+        let buffer: &mut [u8] = &mut self.img;
+        for ((x, y), color) in pixels {
+            if x < 0 || x >= width || y < 0 || y >= height {
+                continue;
+            }
+            let offset = (y as usize * width as usize + x as usize) * 3;
+            let alpha = color.alpha();
+            let rgb = color.rgb();
+            let new_color = [rgb.0, rgb.1, rgb.2];
+            for (old, new) in buffer[offset..offset + 3].iter_mut().zip(&new_color) {
+                *old = if alpha >= 1.0 {
+                    *new
+                } else {
+                    (f64::from(*old) * (1.0 - alpha) + f64::from(*new) * alpha).min(255.0) as u8
+                };
+            }
+        }
+        Ok(())
+    }
+
+    fn fill_polygon_with_rule<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+        rule: FillRule,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        if self.antialias {
+            return self.fill_polygon_antialiased(&vert, &style.as_color(), rule, |_| 1.0);
+        }
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        for_each_polygon_span(&vert, rule, |y, left, right| {
+            self.draw_line((left, y), (right, y), style)
+        })
+    }
+
+    fn fill_polygon_with_vertical_gradient<
+        S: BackendStyle,
+        I: IntoIterator<Item = BackendCoord>,
+    >(
+        &mut self,
+        vert: I,
+        style: &S,
+        rows: (i32, i32),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().collect();
+        let color = style.as_color();
+        if self.antialias {
+            return self.fill_polygon_antialiased(&vert, &color, FillRule::EvenOdd, |y| {
+                vertical_gradient_ratio(y, rows)
+            });
+        }
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        for_each_polygon_span(&vert, FillRule::EvenOdd, |y, left, right| {
+            let ratio = vertical_gradient_ratio(y, rows);
+            if ratio > 0.0 {
+                self.draw_line((left, y), (right, y), &color.mix(ratio))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+        src_size: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<ImageError>> {
+        if src_size.0 == 0 || src_size.1 == 0 {
+            return Ok(());
+        }
+        let (width, height) = (self.img.width() as i32, self.img.height() as i32);
+        for y in 0..size.1 {
+            let dy = pos.1 + y as i32;
+            if dy < 0 || dy >= height {
+                continue;
+            }
+            for x in 0..size.0 {
+                let dx = pos.0 + x as i32;
+                if dx < 0 || dx >= width {
+                    continue;
+                }
+                let idx = bitmap_source_offset((x, y), size, src_size);
+                self.img.put_pixel(
+                    dx as u32,
+                    dy as u32,
+                    Rgb {
+                        data: [src[idx], src[idx + 1], src[idx + 2]],
+                    },
+                );
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Drop for BitMapBackend<'_> {
@@ -118,3 +325,78 @@ impl Drop for BitMapBackend<'_> {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_antialiased_fill() {
+    use crate::style::WHITE;
+    let triangle = vec![(0, 0), (8, 0), (0, 8)];
+
+    let mut backend = BitMapBackend::in_memory((10, 10));
+    backend
+        .fill_polygon(triangle.clone(), &WHITE)
+        .expect("Drawing Failure");
+    assert!([0, 255].contains(&backend.img.get_pixel(3, 4).data[0]));
+
+    let mut backend = BitMapBackend::in_memory((10, 10)).antialias(true);
+    backend
+        .fill_polygon(triangle, &WHITE)
+        .expect("Drawing Failure");
+    assert_eq!(backend.img.get_pixel(1, 1).data[0], 255);
+    assert_eq!(backend.img.get_pixel(6, 6).data[0], 0);
+    // The pixel cut in half by the edge is half covered
+    let edge = backend.img.get_pixel(3, 4).data[0];
+    assert!(edge > 120 && edge < 135);
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_draw_pixels() {
+    use crate::style::{BLUE, RED};
+    let pixels = vec![
+        ((1, 2), RED.to_rgba()),
+        ((3, 4), BLUE.mix(0.5)),
+        ((3, 4), RED.mix(0.25)),
+        ((-1, 2), RED.to_rgba()),
+        ((10, 2), RED.to_rgba()),
+    ];
+
+    // The batch blends the same way as drawing the pixels one by one
+    let mut batched = BitMapBackend::in_memory((10, 10));
+    batched
+        .draw_pixels(pixels.clone())
+        .expect("Drawing Failure");
+    let mut single = BitMapBackend::in_memory((10, 10));
+    for (point, color) in pixels {
+        single.draw_pixel(point, &color).expect("Drawing Failure");
+    }
+
+    assert_eq!(batched.img.get_pixel(1, 2).data, [255, 0, 0]);
+    assert_eq!(
+        batched.img.get_pixel(3, 4).data,
+        single.img.get_pixel(3, 4).data
+    );
+    assert_eq!(&batched.img as &[u8], &single.img as &[u8]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_bitmap_encode_png() {
+    use crate::style::RED;
+    use image::ImageFormat;
+
+    let mut backend = BitMapBackend::in_memory((10, 8));
+    backend
+        .draw_pixel((3, 5), &RED.to_rgba())
+        .expect("Drawing Failure");
+    let png = backend.encode_png().expect("Encoding Failure");
+    assert!(png.starts_with(b"\x89PNG"));
+
+    // The encoded image decodes back to the drawn pixels
+    let img = image::load_from_memory_with_format(&png, ImageFormat::PNG)
+        .expect("Decoding Failure")
+        .to_rgb();
+    assert_eq!(img.dimensions(), (10, 8));
+    assert_eq!(img.get_pixel(3, 5).data, [255, 0, 0]);
+    assert_eq!(img.get_pixel(4, 5).data, [0, 0, 0]);
+}
@@ -1,8 +1,10 @@
 use js_sys::JSON;
 use wasm_bindgen::{JsCast, JsValue};
-use web_sys::{window, CanvasRenderingContext2d, HtmlCanvasElement};
+use web_sys::{window, CanvasRenderingContext2d, CanvasWindingRule, HtmlCanvasElement};
 
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, FillRule,
+};
 use crate::style::{Color, FontDesc, FontTransform, RGBAColor};
 
 /// The backend that is drawing on the HTML canvas
@@ -161,6 +163,33 @@ impl DrawingBackend for CanvasBackend {
         Ok(())
     }
 
+    fn fill_polygon_with_rule<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+        rule: FillRule,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let mut vert = vert.into_iter();
+        self.context.begin_path();
+        if let Some(start) = vert.next() {
+            self.context
+                .set_fill_style(&make_canvas_color(style.as_color()));
+            self.context.move_to(f64::from(start.0), f64::from(start.1));
+            for next in vert {
+                self.context.line_to(f64::from(next.0), f64::from(next.1));
+            }
+            self.context.close_path();
+        }
+        self.context.fill_with_canvas_winding_rule(match rule {
+            FillRule::EvenOdd => CanvasWindingRule::Evenodd,
+            FillRule::NonZero => CanvasWindingRule::Nonzero,
+        });
+        Ok(())
+    }
+
     fn draw_circle<S: BackendStyle>(
         &mut self,
         center: BackendCoord,
@@ -200,18 +229,38 @@ impl DrawingBackend for CanvasBackend {
         font: &FontDesc<'b>,
         pos: BackendCoord,
         color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_text_subpixel(text, font, (pos.0 as f32, pos.1 as f32), color)
+    }
+
+    fn draw_text_subpixel<'b>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'b>,
+        pos: (f32, f32),
+        color: &RGBAColor,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         if color.alpha() == 0.0 {
             return Ok(());
         }
 
-        let (mut x, mut y) = (pos.0, pos.1);
+        if let FontTransform::Vertical = font.get_transform() {
+            let glyph_font = font.transform(FontTransform::None);
+            for ((dx, dy), glyph) in font.vertical_glyph_positions(text) {
+                let glyph_pos = (pos.0 + dx as f32, pos.1 + dy as f32);
+                self.draw_text_subpixel(glyph, &glyph_font, glyph_pos, color)?;
+            }
+            return Ok(());
+        }
+
+        let (mut x, mut y) = (f64::from(pos.0), f64::from(pos.1));
 
         let degree = match font.get_transform() {
             FontTransform::None => 0.0,
             FontTransform::Rotate90 => 90.0,
             FontTransform::Rotate180 => 180.0,
             FontTransform::Rotate270 => 270.0,
+            FontTransform::Vertical => 0.0,
         } / 180.0
             * std::f64::consts::PI;
 
@@ -220,10 +269,10 @@ impl DrawingBackend for CanvasBackend {
             let layout = font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
             let offset = font.get_transform().offset(layout);
             self.context
-                .translate(f64::from(x + offset.0), f64::from(y + offset.1))?;
+                .translate(x + f64::from(offset.0), y + f64::from(offset.1))?;
             self.context.rotate(degree)?;
-            x = 0;
-            y = 0;
+            x = 0.0;
+            y = 0.0;
         }
 
         self.context.set_text_baseline("bottom");
@@ -231,8 +280,7 @@ impl DrawingBackend for CanvasBackend {
             .set_fill_style(&make_canvas_color(color.clone()));
         self.context
             .set_font(&format!("{}px {}", font.get_size(), font.get_name()));
-        self.context
-            .fill_text(text, f64::from(x), f64::from(y) + font.get_size())?;
+        self.context.fill_text(text, x, y + font.get_size())?;
 
         if degree != 0.0 {
             self.context.restore();
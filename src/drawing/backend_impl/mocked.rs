@@ -1,13 +1,80 @@
 use crate::coord::Shift;
 use crate::drawing::area::IntoDrawingArea;
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, FillRule,
+};
 use crate::drawing::DrawingArea;
 use crate::style::{Color, FontDesc, RGBAColor};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A drawing call received by the mocked backend, the colors are converted to RGBA
+#[derive(Clone, Debug, PartialEq)]
+pub enum MockedCall {
+    /// A pixel: the color and the position
+    Pixel(RGBAColor, BackendCoord),
+    /// A line: the color, the start point and the end point
+    Line(RGBAColor, BackendCoord, BackendCoord),
+    /// A rectangle: the color, if it's filled, the upper left and the bottom right corners
+    Rect(RGBAColor, bool, BackendCoord, BackendCoord),
+    /// A path: the color and the points
+    Path(RGBAColor, Vec<BackendCoord>),
+    /// A filled polygon, with any fill rule or gradient: the color and the vertices
+    FillPolygon(RGBAColor, Vec<BackendCoord>),
+    /// A circle: the color, if it's filled, the center and the radius
+    Circle(RGBAColor, bool, BackendCoord, u32),
+    /// A text: the color, the text and the position
+    Text(RGBAColor, String, BackendCoord),
+}
+
+/// The calls recorded by a mocked backend, which are still readable after the backend is
+/// moved into a drawing area
+#[derive(Clone, Default)]
+pub struct MockedCalls(Rc<RefCell<Vec<MockedCall>>>);
+
+macro_rules! def_call_filter {
+    ($name:ident, $variant:ident, $($field:ident: $type:ty),*) => {
+        pub fn $name(&self) -> Vec<($($type,)*)> {
+            self.0
+                .borrow()
+                .iter()
+                .filter_map(|call| match call {
+                    MockedCall::$variant($($field),*) => Some(($($field.clone(),)*)),
+                    _ => None,
+                })
+                .collect()
+        }
+    };
+}
+
+impl MockedCalls {
+    /// Get all the recorded calls in the order they are made
+    pub fn all(&self) -> Vec<MockedCall> {
+        self.0.borrow().clone()
+    }
+
+    /// Forget the calls recorded so far
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear();
+    }
+
+    fn push(&self, call: MockedCall) {
+        self.0.borrow_mut().push(call);
+    }
+
+    def_call_filter!(pixels, Pixel, c: RGBAColor, p: BackendCoord);
+    def_call_filter!(lines, Line, c: RGBAColor, from: BackendCoord, to: BackendCoord);
+    def_call_filter!(rects, Rect, c: RGBAColor, f: bool, u: BackendCoord, d: BackendCoord);
+    def_call_filter!(paths, Path, c: RGBAColor, path: Vec<BackendCoord>);
+    def_call_filter!(polygons, FillPolygon, c: RGBAColor, vert: Vec<BackendCoord>);
+    def_call_filter!(circles, Circle, c: RGBAColor, f: bool, center: BackendCoord, r: u32);
+    def_call_filter!(texts, Text, c: RGBAColor, text: String, pos: BackendCoord);
+}
 
 pub struct MockedBackend {
     height: u32,
     width: u32,
-    init_count: u32,
+    prepared: bool,
     pub draw_count: u32,
     pub num_draw_pixel_call: u32,
     pub num_draw_line_call: u32,
@@ -15,10 +82,15 @@ pub struct MockedBackend {
     pub num_draw_circle_call: u32,
     pub num_draw_text_call: u32,
     pub num_draw_path_call: u32,
+    pub num_fill_polygon_call: u32,
+    pub last_fill_rule: Option<FillRule>,
+    pub last_gradient_rows: Option<(i32, i32)>,
+    calls: Option<MockedCalls>,
     check_draw_pixel: Option<Box<dyn FnMut(RGBAColor, BackendCoord)>>,
     check_draw_line: Option<Box<dyn FnMut(RGBAColor, BackendCoord, BackendCoord)>>,
     check_draw_rect: Option<Box<dyn FnMut(RGBAColor, bool, BackendCoord, BackendCoord)>>,
     check_draw_path: Option<Box<dyn FnMut(RGBAColor, Vec<BackendCoord>)>>,
+    check_fill_polygon: Option<Box<dyn FnMut(RGBAColor, Vec<BackendCoord>)>>,
     check_draw_circle: Option<Box<dyn FnMut(RGBAColor, bool, BackendCoord, u32)>>,
     check_draw_text: Option<Box<dyn FnMut(RGBAColor, &str, f64, BackendCoord, &str)>>,
     drop_check: Option<Box<dyn FnMut(&Self)>>,
@@ -38,7 +110,7 @@ impl MockedBackend {
         MockedBackend {
             height,
             width,
-            init_count: 0,
+            prepared: false,
             draw_count: 0,
             num_draw_pixel_call: 0,
             num_draw_line_call: 0,
@@ -46,10 +118,15 @@ impl MockedBackend {
             num_draw_circle_call: 0,
             num_draw_text_call: 0,
             num_draw_path_call: 0,
+            num_fill_polygon_call: 0,
+            last_fill_rule: None,
+            last_gradient_rows: None,
+            calls: None,
             check_draw_pixel: None,
             check_draw_line: None,
             check_draw_rect: None,
             check_draw_path: None,
+            check_fill_polygon: None,
             check_draw_circle: None,
             check_draw_text: None,
             drop_check: None,
@@ -60,13 +137,28 @@ impl MockedBackend {
     def_set_checker_func!(check_draw_line, RGBAColor, BackendCoord, BackendCoord);
     def_set_checker_func!(check_draw_rect, RGBAColor, bool, BackendCoord, BackendCoord);
     def_set_checker_func!(check_draw_path, RGBAColor, Vec<BackendCoord>);
+    def_set_checker_func!(check_fill_polygon, RGBAColor, Vec<BackendCoord>);
     def_set_checker_func!(check_draw_circle, RGBAColor, bool, BackendCoord, u32);
     def_set_checker_func!(check_draw_text, RGBAColor, &str, f64, BackendCoord, &str);
     def_set_checker_func!(drop_check, &Self);
 
+    /// Record every drawing call, so the test can check them after the drawing is done
+    pub fn record_calls(&mut self) -> MockedCalls {
+        self.calls.get_or_insert_with(MockedCalls::default).clone()
+    }
+
     fn check_before_draw(&mut self) {
         self.draw_count += 1;
-        assert_eq!(self.init_count, self.draw_count);
+        // Every drawing call needs the backend prepared, an element drawing several shapes
+        // prepares the backend for each of them, but preparing without drawing is allowed
+        assert!(self.prepared, "Every drawing call must be prepared");
+        self.prepared = false;
+    }
+
+    fn record(&self, call: MockedCall) {
+        if let Some(ref calls) = self.calls {
+            calls.push(call);
+        }
     }
 }
 
@@ -89,12 +181,12 @@ impl DrawingBackend for MockedBackend {
     }
 
     fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<MockedError>> {
-        self.init_count += 1;
+        self.prepared = true;
         Ok(())
     }
 
     fn present(&mut self) -> Result<(), DrawingErrorKind<MockedError>> {
-        self.init_count = 0;
+        self.prepared = false;
         self.draw_count = 0;
         Ok(())
     }
@@ -107,12 +199,28 @@ impl DrawingBackend for MockedBackend {
         self.check_before_draw();
         self.num_draw_pixel_call += 1;
         let color = color.to_rgba();
+        self.record(MockedCall::Pixel(color.clone(), point));
         if let Some(ref mut checker) = self.check_draw_pixel {
             checker(color, point);
         }
         Ok(())
     }
 
+    fn draw_pixels<I: IntoIterator<Item = (BackendCoord, RGBAColor)>>(
+        &mut self,
+        pixels: I,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.check_before_draw();
+        for (point, color) in pixels {
+            self.num_draw_pixel_call += 1;
+            self.record(MockedCall::Pixel(color.clone(), point));
+            if let Some(ref mut checker) = self.check_draw_pixel {
+                checker(color, point);
+            }
+        }
+        Ok(())
+    }
+
     fn draw_line<S: BackendStyle>(
         &mut self,
         from: BackendCoord,
@@ -122,6 +230,7 @@ impl DrawingBackend for MockedBackend {
         self.check_before_draw();
         self.num_draw_line_call += 1;
         let color = style.as_color().to_rgba();
+        self.record(MockedCall::Line(color.clone(), from, to));
         if let Some(ref mut checker) = self.check_draw_line {
             checker(color, from, to);
         }
@@ -138,6 +247,12 @@ impl DrawingBackend for MockedBackend {
         self.check_before_draw();
         self.num_draw_rect_call += 1;
         let color = style.as_color().to_rgba();
+        self.record(MockedCall::Rect(
+            color.clone(),
+            fill,
+            upper_left,
+            bottom_right,
+        ));
         if let Some(ref mut checker) = self.check_draw_rect {
             checker(color, fill, upper_left, bottom_right);
         }
@@ -152,12 +267,55 @@ impl DrawingBackend for MockedBackend {
         self.check_before_draw();
         self.num_draw_path_call += 1;
         let color = style.as_color().to_rgba();
+        let path: Vec<_> = path.into_iter().collect();
+        self.record(MockedCall::Path(color.clone(), path.clone()));
         if let Some(ref mut checker) = self.check_draw_path {
-            checker(color, path.into_iter().collect());
+            checker(color, path);
+        }
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.check_before_draw();
+        self.num_fill_polygon_call += 1;
+        let color = style.as_color().to_rgba();
+        let vert: Vec<_> = vert.into_iter().collect();
+        self.record(MockedCall::FillPolygon(color.clone(), vert.clone()));
+        if let Some(ref mut checker) = self.check_fill_polygon {
+            checker(color, vert);
         }
         Ok(())
     }
 
+    fn fill_polygon_with_rule<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+        rule: FillRule,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.fill_polygon(vert, style)?;
+        self.last_fill_rule = Some(rule);
+        Ok(())
+    }
+
+    fn fill_polygon_with_vertical_gradient<
+        S: BackendStyle,
+        I: IntoIterator<Item = BackendCoord>,
+    >(
+        &mut self,
+        vert: I,
+        style: &S,
+        rows: (i32, i32),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.fill_polygon(vert, style)?;
+        self.last_gradient_rows = Some(rows);
+        Ok(())
+    }
+
     fn draw_circle<S: BackendStyle>(
         &mut self,
         center: BackendCoord,
@@ -168,6 +326,7 @@ impl DrawingBackend for MockedBackend {
         self.check_before_draw();
         self.num_draw_circle_call += 1;
         let color = style.as_color().to_rgba();
+        self.record(MockedCall::Circle(color.clone(), fill, center, radius));
         if let Some(ref mut checker) = self.check_draw_circle {
             checker(color, fill, center, radius);
         }
@@ -184,11 +343,27 @@ impl DrawingBackend for MockedBackend {
         self.check_before_draw();
         self.num_draw_text_call += 1;
         let color = color.to_rgba();
+        self.record(MockedCall::Text(color.clone(), text.to_string(), pos));
         if let Some(ref mut checker) = self.check_draw_text {
             checker(color, font.get_name(), font.get_size(), pos, text);
         }
         Ok(())
     }
+
+    fn draw_text_subpixel<'a>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        pos: (f32, f32),
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_text(
+            text,
+            font,
+            (pos.0.round() as i32, pos.1.round() as i32),
+            color,
+        )
+    }
 }
 
 impl Drop for MockedBackend {
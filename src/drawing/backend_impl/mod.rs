@@ -8,6 +8,20 @@ mod bitmap;
 #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
 pub use bitmap::BitMapBackend;
 
+mod text;
+pub use text::TextDrawingBackend;
+
+mod rotated;
+pub use rotated::{RotatedBackend, Rotation};
+
+mod polygon_clip;
+pub use polygon_clip::PolygonClipBackend;
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "pdf"))]
+mod pdf;
+#[cfg(all(not(target_arch = "wasm32"), feature = "pdf"))]
+pub use pdf::{PDFBackend, PDFError};
+
 #[cfg(target_arch = "wasm32")]
 mod canvas;
 #[cfg(target_arch = "wasm32")]
@@ -16,7 +30,7 @@ pub use canvas::CanvasBackend;
 #[cfg(test)]
 mod mocked;
 #[cfg(test)]
-pub use mocked::{create_mocked_drawing_area, MockedBackend};
+pub use mocked::{create_mocked_drawing_area, MockedBackend, MockedCall, MockedCalls};
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "piston"))]
 mod piston;
@@ -0,0 +1,414 @@
+/*!
+The PDF drawing backend, which produces vector graphics for print.
+*/
+use printpdf::lopdf::content::Operation;
+use printpdf::{
+    BuiltinFont, Color as PdfColor, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference,
+    PdfLayerReference, Point, Pt, Rgb, TextMatrix,
+};
+
+use font_loader::system_fonts;
+
+use crate::drawing::backend::{
+    BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind, FillRule,
+};
+use crate::style::{Color, FontDesc, FontTransform, RGBAColor};
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Cursor};
+use std::path::Path;
+
+/// The error reported by the PDF backend
+#[derive(Debug)]
+pub enum PDFError {
+    /// The error caused by the file system
+    IoError(std::io::Error),
+    /// The error reported by the PDF library
+    DocumentError(String),
+}
+
+impl std::fmt::Display for PDFError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            PDFError::IoError(e) => write!(fmt, "IO error: {}", e),
+            PDFError::DocumentError(e) => write!(fmt, "PDF error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PDFError {}
+
+/// Convert the size in pixels to millimeters, one pixel is mapped to one point
+fn px_to_mm(px: f64) -> Mm {
+    Mm(px * 25.4 / 72.0)
+}
+
+fn make_pdf_color<C: Color>(color: &C) -> PdfColor {
+    let (r, g, b) = color.rgb();
+    PdfColor::Rgb(Rgb::new(
+        f64::from(r) / 255.0,
+        f64::from(g) / 255.0,
+        f64::from(b) / 255.0,
+        None,
+    ))
+}
+
+/// The place where the PDF document is saved
+enum Target<'a> {
+    File(&'a Path),
+    Buffer(&'a mut Vec<u8>),
+}
+
+/// The PDF drawing backend. A pixel is mapped to a point (1/72 inch) in the document, and the
+/// Y axis is flipped, since the origin of PDF is the bottom-left corner of the page.
+/// The fonts are embedded into the document, the builtin Helvetica is used if a font isn't
+/// installed.
+pub struct PDFBackend<'a> {
+    target: Target<'a>,
+    size: (u32, u32),
+    document: Option<PdfDocumentReference>,
+    layer: PdfLayerReference,
+    fonts: HashMap<String, IndirectFontRef>,
+    saved: bool,
+}
+
+impl<'a> PDFBackend<'a> {
+    /// Create a new PDF drawing backend
+    /// - `path`: The path of the PDF file
+    /// - `size`: The size of the page in pixels, which are mapped to points
+    pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
+        Self::with_target(Target::File(path.as_ref()), size)
+    }
+
+    /// Create a new PDF drawing backend which saves the document to a buffer
+    /// - `buf`: The buffer the PDF document is written to
+    /// - `size`: The size of the page in pixels, which are mapped to points
+    pub fn with_buffer(buf: &'a mut Vec<u8>, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Buffer(buf), size)
+    }
+
+    fn with_target(target: Target<'a>, size: (u32, u32)) -> Self {
+        let (document, page, layer) = PdfDocument::new(
+            "Plotters",
+            px_to_mm(f64::from(size.0)),
+            px_to_mm(f64::from(size.1)),
+            "Layer 1",
+        );
+        let layer = document.get_page(page).get_layer(layer);
+        Self {
+            target,
+            size,
+            document: Some(document),
+            layer,
+            fonts: HashMap::new(),
+            saved: false,
+        }
+    }
+
+    /// Get the position on the page of the backend coordinate, the Y axis goes up from the
+    /// bottom edge of the page
+    fn page_position(&self, (x, y): BackendCoord) -> (Mm, Mm) {
+        (
+            px_to_mm(f64::from(x)),
+            px_to_mm(f64::from(self.size.1 as i32 - y)),
+        )
+    }
+
+    /// Convert the backend coordinate to the point on the page
+    fn to_point(&self, pos: BackendCoord) -> Point {
+        let (x, y) = self.page_position(pos);
+        Point::new(x, y)
+    }
+
+    /// Add a shape to the page
+    fn add_shape<C: Color, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        points: I,
+        color: &C,
+        closed: bool,
+        fill: bool,
+    ) {
+        let points: Vec<_> = points
+            .into_iter()
+            .map(|p| (self.to_point(p), false))
+            .collect();
+        if fill {
+            self.layer.set_fill_color(make_pdf_color(color));
+        } else {
+            self.layer.set_outline_color(make_pdf_color(color));
+        }
+        self.layer.add_shape(Line {
+            points,
+            is_closed: closed,
+            has_fill: fill,
+            has_stroke: !fill,
+            is_clipping_path: false,
+        });
+    }
+
+    /// Get the font embedded into the document, the font is loaded when it's used first time
+    fn get_font(&mut self, name: &str) -> Result<IndirectFontRef, DrawingErrorKind<PDFError>> {
+        if let Some(font) = self.fonts.get(name) {
+            return Ok(font.clone());
+        }
+        let document = self.document.as_ref().unwrap();
+        let query = system_fonts::FontPropertyBuilder::new()
+            .family(name)
+            .build();
+        let font = match system_fonts::get(&query) {
+            Some((data, _)) => document.add_external_font(Cursor::new(data)),
+            None => document.add_builtin_font(BuiltinFont::Helvetica),
+        };
+        let font = font.map_err(|e| {
+            DrawingErrorKind::DrawingError(PDFError::DocumentError(format!("{:?}", e)))
+        })?;
+        self.fonts.insert(name.to_string(), font.clone());
+        Ok(font)
+    }
+}
+
+impl<'a> DrawingBackend for PDFBackend<'a> {
+    type ErrorType = PDFError;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<PDFError>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<PDFError>> {
+        if !self.saved {
+            // Saving consumes the document, so there is nothing left to save after a failed
+            // save, e.g. when the backend is dropped once the error is returned
+            let document = match self.document.take() {
+                Some(document) => document,
+                None => return Ok(()),
+            };
+            let result = match self.target {
+                Target::File(path) => {
+                    let file = File::create(path)
+                        .map_err(|e| DrawingErrorKind::DrawingError(PDFError::IoError(e)))?;
+                    document.save(&mut BufWriter::new(file))
+                }
+                Target::Buffer(ref mut buf) => {
+                    document.save(&mut BufWriter::new(Cursor::new(&mut **buf)))
+                }
+            };
+            result.map_err(|e| {
+                DrawingErrorKind::DrawingError(PDFError::DocumentError(format!("{:?}", e)))
+            })?;
+            self.saved = true;
+        }
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<PDFError>> {
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        let (x, y) = point;
+        self.add_shape(
+            vec![(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)],
+            color,
+            true,
+            true,
+        );
+        Ok(())
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        self.add_shape(vec![from, to], &style.as_color(), false, false);
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let (x0, y0, x1, y1) = (upper_left.0, upper_left.1, bottom_right.0, bottom_right.1);
+        self.add_shape(
+            vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1)],
+            &style.as_color(),
+            true,
+            fill,
+        );
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        self.add_shape(path, &style.as_color(), false, false);
+        Ok(())
+    }
+
+    fn fill_polygon_with_rule<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+        rule: FillRule,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        if rule == FillRule::NonZero {
+            self.add_shape(vert, &style.as_color(), true, true);
+            return Ok(());
+        }
+
+        // The shapes of printpdf are always filled by the nonzero rule, so the even-odd fill
+        // is written with the path operators directly
+        self.layer.set_fill_color(make_pdf_color(&style.as_color()));
+        for (idx, pos) in vert.into_iter().enumerate() {
+            let (x, y) = self.page_position(pos);
+            let (x, y) = (Pt::from(x), Pt::from(y));
+            let operator = if idx == 0 { "m" } else { "l" };
+            self.layer
+                .internal_add_operation(Operation::new(operator, vec![x.0.into(), y.0.into()]));
+        }
+        self.layer
+            .internal_add_operation(Operation::new("h", vec![]));
+        self.layer
+            .internal_add_operation(Operation::new("f*", vec![]));
+        Ok(())
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        // Approximate the circle with a polygon, one vertex for every 4 degrees
+        let radius = f64::from(radius);
+        let vert = (0..90).map(|idx| {
+            let angle = f64::from(idx) * std::f64::consts::PI / 45.0;
+            (
+                center.0 + (radius * angle.cos()).round() as i32,
+                center.1 + (radius * angle.sin()).round() as i32,
+            )
+        });
+        self.add_shape(vert, &style.as_color(), true, fill);
+        Ok(())
+    }
+
+    fn draw_text<'b>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'b>,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        if let FontTransform::Vertical = font.get_transform() {
+            let glyph_font = font.transform(FontTransform::None);
+            for ((dx, dy), glyph) in font.vertical_glyph_positions(text) {
+                self.draw_text(glyph, &glyph_font, (pos.0 + dx, pos.1 + dy), color)?;
+            }
+            return Ok(());
+        }
+
+        let layout = font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
+        let trans = font.get_transform();
+        let offset = trans.offset(layout);
+        // The text is positioned by its baseline, which is below the top of the text
+        let baseline = trans.transform(0, -(layout.0).1);
+        let point = self.to_point((pos.0 + offset.0 + baseline.0, pos.1 + offset.1 + baseline.1));
+
+        // The rotation is counter-clockwise, since the Y axis is flipped
+        let degree = match trans {
+            FontTransform::Rotate90 => -90.0,
+            FontTransform::Rotate180 => 180.0,
+            FontTransform::Rotate270 => 90.0,
+            _ => 0.0,
+        };
+
+        let pdf_font = self.get_font(font.get_name())?;
+        self.layer.begin_text_section();
+        self.layer.set_fill_color(make_pdf_color(color));
+        self.layer
+            .set_font(&pdf_font, font.get_size().round() as i64);
+        self.layer.set_text_matrix(TextMatrix::TranslateRotate(
+            Pt::from(point.x),
+            Pt::from(point.y),
+            degree,
+        ));
+        self.layer.write_text(text, &pdf_font);
+        self.layer.end_text_section();
+
+        Ok(())
+    }
+}
+
+impl Drop for PDFBackend<'_> {
+    fn drop(&mut self) {
+        if !self.saved {
+            self.present().expect("Unable to save the PDF document");
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_pdf_backend() {
+    use crate::style::RED;
+
+    let mut buffer = vec![];
+    {
+        let mut backend = PDFBackend::with_buffer(&mut buffer, (100, 50));
+
+        // The Y axis is flipped, so the top edge of the chart is the top edge of the page
+        let (x, y) = backend.page_position((10, 0));
+        assert!((x.0 - 10.0 * 25.4 / 72.0).abs() < 1e-9);
+        assert!((y.0 - 50.0 * 25.4 / 72.0).abs() < 1e-9);
+        let (_, y) = backend.page_position((10, 50));
+        assert!(y.0.abs() < 1e-9);
+
+        // A font is embedded once, and the builtin font is used if it isn't installed
+        backend.get_font("plotters-missing-font").unwrap();
+        backend.get_font("plotters-missing-font").unwrap();
+        assert_eq!(backend.fonts.len(), 1);
+
+        backend.draw_rect((10, 10), (20, 20), &RED, true).unwrap();
+        let star = vec![(50, 0), (80, 40), (0, 15), (100, 15), (20, 40)];
+        for rule in &[FillRule::EvenOdd, FillRule::NonZero] {
+            backend
+                .fill_polygon_with_rule(star.clone(), &RED, *rule)
+                .unwrap();
+        }
+        backend.present().unwrap();
+    }
+    assert!(buffer.starts_with(b"%PDF-"));
+}
@@ -0,0 +1,165 @@
+use crate::drawing::backend::{
+    for_each_polygon_span, polygon_spans_at, BackendCoord, BackendMetadata, BackendStyle,
+    DrawingBackend, DrawingErrorKind, FillRule,
+};
+use crate::style::{Color, RGBAColor};
+
+/// Check if the center of the pixel is inside of the polygon
+fn pixel_in_polygon(polygon: &[BackendCoord], (x, y): BackendCoord) -> bool {
+    let fx = f64::from(x) + 0.5;
+    polygon_spans_at(polygon, f64::from(y) + 0.5, FillRule::EvenOdd)
+        .iter()
+        .any(|&(left, right)| left <= fx && fx < right)
+}
+
+/// Clip a horizontal run of pixels to the polygon, the pixels are picked the same way as the
+/// polygon filling does, so a run clipped to a polygon matches the fill of the polygon
+/// - **returns**: The first and last pixel of each part of the run inside of the polygon
+fn clip_run_to_polygon(polygon: &[BackendCoord], y: i32, left: i32, right: i32) -> Vec<(i32, i32)> {
+    polygon_spans_at(polygon, f64::from(y) + 0.5, FillRule::EvenOdd)
+        .into_iter()
+        .map(|(x, next)| {
+            (
+                left.max(x.round() as i32),
+                right.min(next.round() as i32 - 1),
+            )
+        })
+        .filter(|(l, r)| l <= r)
+        .collect()
+}
+
+/// The backend adapter which masks the drawing to the inside of a polygon, e.g. a heatmap
+/// clipped to the boundary of a country. The fills are clipped row by row against the spans
+/// of the polygon, everything else is tested pixel by pixel, which is slow but exact.
+/// See `DrawingArea::draw_clipped_to_polygon`.
+pub struct PolygonClipBackend<'b, DB: DrawingBackend> {
+    inner: &'b mut DB,
+    polygon: &'b [BackendCoord],
+}
+
+impl<'b, DB: DrawingBackend> PolygonClipBackend<'b, DB> {
+    /// Create a new polygon clipping adapter
+    /// - `inner`: The backend to draw on
+    /// - `polygon`: The vertices of the clip polygon in the backend coordinate
+    pub fn new(inner: &'b mut DB, polygon: &'b [BackendCoord]) -> Self {
+        Self { inner, polygon }
+    }
+
+    fn fill_run<S: BackendStyle>(
+        &mut self,
+        y: i32,
+        left: i32,
+        right: i32,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        for (l, r) in clip_run_to_polygon(self.polygon, y, left, right) {
+            self.inner.ensure_prepared()?;
+            self.inner.draw_line((l, y), (r, y), style)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'b, DB: DrawingBackend> DrawingBackend for PolygonClipBackend<'b, DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn set_metadata(
+        &mut self,
+        metadata: Option<&BackendMetadata>,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.set_metadata(metadata)
+    }
+
+    fn set_blur(&mut self, radius: u32) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.set_blur(radius)
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if pixel_in_polygon(self.polygon, point) {
+            self.inner.draw_pixel(point, color)?;
+        }
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let (left, right) = (
+            upper_left.0.min(bottom_right.0),
+            upper_left.0.max(bottom_right.0),
+        );
+        let (top, bottom) = (
+            upper_left.1.min(bottom_right.1),
+            upper_left.1.max(bottom_right.1),
+        );
+        if fill {
+            for y in top..=bottom {
+                self.fill_run(y, left, right, style)?;
+            }
+            Ok(())
+        } else {
+            self.draw_path(
+                vec![
+                    (left, top),
+                    (right, top),
+                    (right, bottom),
+                    (left, bottom),
+                    (left, top),
+                ],
+                style,
+            )
+        }
+    }
+
+    fn fill_polygon_with_rule<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+        rule: FillRule,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let vert: Vec<_> = vert.into_iter().collect();
+        for_each_polygon_span(&vert, rule, |y, left, right| {
+            self.fill_run(y, left, right, style)
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_polygon_clip() {
+    let triangle = [(0, 0), (20, 0), (0, 20)];
+    assert!(pixel_in_polygon(&triangle, (2, 2)));
+    assert!(!pixel_in_polygon(&triangle, (15, 15)));
+    assert!(!pixel_in_polygon(&triangle, (-1, 5)));
+
+    assert_eq!(clip_run_to_polygon(&triangle, 5, -10, 30), vec![(0, 14)]);
+    assert_eq!(clip_run_to_polygon(&triangle, 5, 3, 8), vec![(3, 8)]);
+    assert_eq!(clip_run_to_polygon(&triangle, 5, 16, 30), vec![]);
+    assert_eq!(clip_run_to_polygon(&triangle, 25, 0, 30), vec![]);
+}
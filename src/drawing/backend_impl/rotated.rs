@@ -0,0 +1,438 @@
+use crate::drawing::backend::{
+    for_each_polygon_span, top_rounded_rect, vertical_gradient_ratio, BackendCoord,
+    BackendMetadata, BackendStyle, DrawingBackend, DrawingErrorKind, FillRule,
+};
+use crate::style::{Color, FontDesc, FontTransform, RGBAColor};
+
+/// The clockwise rotation of a `RotatedBackend`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rotation {
+    /// Rotate by 90 degrees, the top of the drawing is on the right side of the backend
+    Rotate90,
+    /// Rotate by 180 degrees, the drawing is upside down
+    Rotate180,
+    /// Rotate by 270 degrees, the top of the drawing is on the left side of the backend
+    Rotate270,
+}
+
+impl Rotation {
+    /// Get the rotation as an angle in radian
+    fn angle(self) -> f64 {
+        match self {
+            Rotation::Rotate90 => std::f64::consts::FRAC_PI_2,
+            Rotation::Rotate180 => std::f64::consts::PI,
+            Rotation::Rotate270 => 3.0 * std::f64::consts::FRAC_PI_2,
+        }
+    }
+
+    /// Get the number of the quarter turns
+    fn quarters(self) -> u32 {
+        match self {
+            Rotation::Rotate90 => 1,
+            Rotation::Rotate180 => 2,
+            Rotation::Rotate270 => 3,
+        }
+    }
+}
+
+/// Combine the rotation of a text with the rotation of the backend. The vertical layout isn't
+/// able to be rotated, so it's simply replaced by the rotation of the backend
+fn rotate_font_transform(trans: FontTransform, rotation: Rotation) -> FontTransform {
+    let quarters = match trans {
+        FontTransform::Rotate90 => 1,
+        FontTransform::Rotate180 => 2,
+        FontTransform::Rotate270 => 3,
+        FontTransform::None | FontTransform::Vertical => 0,
+    };
+    match (quarters + rotation.quarters()) % 4 {
+        1 => FontTransform::Rotate90,
+        2 => FontTransform::Rotate180,
+        3 => FontTransform::Rotate270,
+        _ => FontTransform::None,
+    }
+}
+
+/// The backend wrapper that rotates the whole drawing by a multiple of 90 degrees clockwise,
+/// e.g. to put a landscape figure on a portrait page. Every coordinate is rotated before it
+/// reaches the underlying backend, so a vector backend keeps the output crisp. The size of the
+/// wrapper is the size of the drawing before the rotation:
+///
+/// ```
+/// use plotters::prelude::*;
+/// # let mut buffer = vec![];
+/// let page = SVGBackend::with_buffer(&mut buffer, (600, 800));
+/// let backend = RotatedBackend::new(page, Rotation::Rotate90);
+/// // The figure is drawn as 800x600 and rotated onto the 600x800 page
+/// assert_eq!(backend.get_size(), (800, 600));
+/// let root = backend.into_drawing_area();
+/// ```
+pub struct RotatedBackend<DB: DrawingBackend> {
+    inner: DB,
+    rotation: Rotation,
+}
+
+impl<DB: DrawingBackend> RotatedBackend<DB> {
+    /// Create a new rotated backend
+    /// - `inner`: The underlying backend
+    /// - `rotation`: The clockwise rotation of the drawing
+    pub fn new(inner: DB, rotation: Rotation) -> Self {
+        Self { inner, rotation }
+    }
+
+    /// Get the underlying backend back
+    pub fn into_inner(self) -> DB {
+        self.inner
+    }
+
+    /// Rotate a point of the drawing to the underlying backend
+    fn rotate(&self, (x, y): BackendCoord) -> BackendCoord {
+        let (w, h) = self.inner.get_size();
+        let (w, h) = (w as i32, h as i32);
+        match self.rotation {
+            Rotation::Rotate90 => (w - 1 - y, x),
+            Rotation::Rotate180 => (w - 1 - x, h - 1 - y),
+            Rotation::Rotate270 => (y, h - 1 - x),
+        }
+    }
+
+    /// Rotate a point at the sub-pixel position to the underlying backend
+    fn rotate_f32(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let (w, h) = self.inner.get_size();
+        let (w, h) = (w as f32, h as f32);
+        match self.rotation {
+            Rotation::Rotate90 => (w - 1.0 - y, x),
+            Rotation::Rotate180 => (w - 1.0 - x, h - 1.0 - y),
+            Rotation::Rotate270 => (y, h - 1.0 - x),
+        }
+    }
+
+    /// Rotate a rectangle to the underlying backend
+    fn rotate_rect(&self, a: BackendCoord, b: BackendCoord) -> (BackendCoord, BackendCoord) {
+        let (a, b) = (self.rotate(a), self.rotate(b));
+        ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)))
+    }
+
+    /// Rotate a RGB image, 3 bytes per pixel, the same way as the drawing
+    /// - **returns**: The pixels and the dimension of the rotated image
+    fn rotate_bitmap(&self, src: &[u8], (w, h): (u32, u32)) -> (Vec<u8>, (u32, u32)) {
+        let size = match self.rotation {
+            Rotation::Rotate180 => (w, h),
+            _ => (h, w),
+        };
+        let mut ret = Vec::with_capacity((w * h * 3) as usize);
+        for y in 0..size.1 {
+            for x in 0..size.0 {
+                let (sx, sy) = match self.rotation {
+                    Rotation::Rotate90 => (y, h - 1 - x),
+                    Rotation::Rotate180 => (w - 1 - x, h - 1 - y),
+                    Rotation::Rotate270 => (w - 1 - y, x),
+                };
+                let idx = ((sy * w + sx) * 3) as usize;
+                ret.extend_from_slice(&src[idx..idx + 3]);
+            }
+        }
+        (ret, size)
+    }
+}
+
+impl<DB: DrawingBackend> DrawingBackend for RotatedBackend<DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        let (w, h) = self.inner.get_size();
+        match self.rotation {
+            Rotation::Rotate180 => (w, h),
+            _ => (h, w),
+        }
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn set_metadata(
+        &mut self,
+        metadata: Option<&BackendMetadata>,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.set_metadata(metadata)
+    }
+
+    fn set_blur(&mut self, radius: u32) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.set_blur(radius)
+    }
+
+    fn set_clip_polygon(
+        &mut self,
+        polygon: Option<&[BackendCoord]>,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        let polygon: Option<Vec<_>> =
+            polygon.map(|vert| vert.iter().map(|p| self.rotate(*p)).collect());
+        self.inner
+            .set_clip_polygon(polygon.as_ref().map(|vert| &vert[..]))
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let point = self.rotate(point);
+        self.inner.draw_pixel(point, color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (from, to) = (self.rotate(from), self.rotate(to));
+        self.inner.draw_line(from, to, style)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (upper_left, bottom_right) = self.rotate_rect(upper_left, bottom_right);
+        self.inner.draw_rect(upper_left, bottom_right, style, fill)
+    }
+
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (upper_left, bottom_right) = self.rotate_rect(upper_left, bottom_right);
+        self.inner
+            .draw_rounded_rect(upper_left, bottom_right, radius, style, fill)
+    }
+
+    fn fill_top_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        // The rounded side doesn't face the top of the underlying backend after the rotation,
+        // so the shape is put together from the rotated rectangles and corner circles
+        let ((x0, y0), (x1, y1), radius) = top_rounded_rect(upper_left, bottom_right, radius);
+        let mut rects = vec![((x0, y0 + radius.max(0)), (x1, y1))];
+        if radius > 0 && x1 - x0 > 2 * radius {
+            rects.push(((x0 + radius, y0), (x1 - radius, y0 + radius)));
+        }
+        for (a, b) in rects {
+            let (a, b) = self.rotate_rect(a, b);
+            self.inner.draw_rect(a, b, style, true)?;
+        }
+        if radius > 0 {
+            for center in [(x0 + radius, y0 + radius), (x1 - radius, y0 + radius)].iter() {
+                let center = self.rotate(*center);
+                self.inner.draw_circle(center, radius as u32, style, true)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let path: Vec<_> = path.into_iter().map(|p| self.rotate(p)).collect();
+        self.inner.draw_path(path, style)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().map(|p| self.rotate(p)).collect();
+        self.inner.fill_polygon(vert, style)
+    }
+
+    fn fill_polygon_with_rule<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+        rule: FillRule,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let vert: Vec<_> = vert.into_iter().map(|p| self.rotate(p)).collect();
+        self.inner.fill_polygon_with_rule(vert, style, rule)
+    }
+
+    fn fill_polygon_with_vertical_gradient<
+        S: BackendStyle,
+        I: IntoIterator<Item = BackendCoord>,
+    >(
+        &mut self,
+        vert: I,
+        style: &S,
+        rows: (i32, i32),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = style.as_color();
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        // The gradient runs sideways or upwards on the underlying backend, which its vertical
+        // gradient can't express, so each row of the drawing is filled with a rotated line
+        let vert: Vec<_> = vert.into_iter().collect();
+        for_each_polygon_span(&vert, FillRule::EvenOdd, |y, left, right| {
+            let ratio = vertical_gradient_ratio(y, rows);
+            if ratio > 0.0 {
+                let (from, to) = (self.rotate((left, y)), self.rotate((right, y)));
+                self.inner.draw_line(from, to, &color.mix(ratio))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        (start, end): (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (center, angle) = (self.rotate(center), self.rotation.angle());
+        self.inner
+            .draw_arc(center, radius, (start + angle, end + angle), style)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let center = self.rotate(center);
+        self.inner.draw_circle(center, radius, style, fill)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+        src_size: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if size.0 == 0 || size.1 == 0 || src_size.0 == 0 || src_size.1 == 0 {
+            return Ok(());
+        }
+        let corner = (pos.0 + size.0 as i32 - 1, pos.1 + size.1 as i32 - 1);
+        let (pos, _) = self.rotate_rect(pos, corner);
+        let size = match self.rotation {
+            Rotation::Rotate180 => size,
+            _ => (size.1, size.0),
+        };
+        let (src, src_size) = self.rotate_bitmap(src, src_size);
+        self.inner.blit_bitmap(pos, size, &src, src_size)
+    }
+
+    fn draw_text<'a>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let font = font.transform(rotate_font_transform(font.get_transform(), self.rotation));
+        let pos = self.rotate(pos);
+        self.inner.draw_text(text, &font, pos, color)
+    }
+
+    fn draw_text_subpixel<'a>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        pos: (f32, f32),
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let font = font.transform(rotate_font_transform(font.get_transform(), self.rotation));
+        let pos = self.rotate_f32(pos);
+        self.inner.draw_text_subpixel(text, &font, pos, color)
+    }
+
+    fn draw_text_on_path<'a>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        path: &[BackendCoord],
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let path: Vec<_> = path.iter().map(|p| self.rotate(*p)).collect();
+        self.inner.draw_text_on_path(text, font, &path, color)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rotated_backend() {
+    use super::MockedBackend;
+    use crate::drawing::IntoDrawingArea;
+    use crate::element::{Path, Rectangle};
+    use crate::style::RED;
+
+    let mut inner = MockedBackend::new(100, 50);
+    inner.check_draw_path(|_, path| {
+        assert_eq!(path, vec![(99, 0), (99, 10), (89, 10)]);
+    });
+    inner.check_draw_rect(|_, _, u, d| {
+        assert_eq!((u, d), ((79, 20), (89, 40)));
+    });
+    let backend = RotatedBackend::new(inner, Rotation::Rotate90);
+    assert_eq!(backend.get_size(), (50, 100));
+
+    let root = backend.into_drawing_area();
+    root.draw(&Path::new(vec![(0, 0), (10, 0), (10, 10)], &RED))
+        .expect("Drawing Failure");
+    root.draw(&Rectangle::new([(20, 10), (40, 20)], &RED))
+        .expect("Drawing Failure");
+
+    match rotate_font_transform(FontTransform::Rotate270, Rotation::Rotate180) {
+        FontTransform::Rotate90 => {}
+        _ => panic!("The text should be rotated by 90 degrees"),
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_rotated_bitmap() {
+    use super::MockedBackend;
+    use crate::style::{BLUE, RED};
+
+    let mut inner = MockedBackend::new(3, 2);
+    let calls = inner.record_calls();
+    let mut backend = RotatedBackend::new(inner, Rotation::Rotate90);
+
+    // A red pixel on top of a blue one, which are side by side after the rotation
+    backend.ensure_prepared().unwrap();
+    backend
+        .blit_bitmap((0, 0), (1, 2), &[255, 0, 0, 0, 0, 255], (1, 2))
+        .expect("Drawing Failure");
+    assert_eq!(
+        calls.pixels(),
+        vec![(BLUE.to_rgba(), (1, 0)), (RED.to_rgba(), (2, 0))]
+    );
+
+    let backend = RotatedBackend::new(MockedBackend::new(3, 2), Rotation::Rotate270);
+    let (src, size) = backend.rotate_bitmap(&[1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4], (2, 2));
+    assert_eq!(size, (2, 2));
+    assert_eq!(src, vec![2, 2, 2, 4, 4, 4, 1, 1, 1, 3, 3, 3]);
+}
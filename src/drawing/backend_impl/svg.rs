@@ -2,10 +2,16 @@
 The SVG image drawing backend
 */
 
-use svg::node::element::{Circle, Line, Polyline, Rectangle, Text};
-use svg::Document;
+use svg::node::element::{
+    Circle, Definitions, Element, Image, Line, LinearGradient, Path as SvgPath, Polygon, Polyline,
+    Rectangle, Stop, Text, TextPath, Title,
+};
+use svg::{Document, Node};
 
-use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::drawing::backend::{
+    top_rounded_rect, BackendCoord, BackendMetadata, BackendStyle, DrawingBackend,
+    DrawingErrorKind, FillRule,
+};
 use crate::style::{Color, FontDesc, FontTransform, RGBAColor};
 
 use std::io::{Cursor, Error};
@@ -20,9 +26,81 @@ fn make_svg_opacity<C: Color>(color: &C) -> String {
     return format!("{}", color.alpha());
 }
 
+fn make_svg_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+    vert: I,
+    style: &S,
+) -> Polygon {
+    Polygon::new()
+        .set("fill", make_svg_color(&style.as_color()))
+        .set("opacity", make_svg_opacity(&style.as_color()))
+        .set(
+            "points",
+            vert.into_iter().fold(String::new(), |mut s, (x, y)| {
+                s.push_str(&format!("{},{} ", x, y));
+                s
+            }),
+        )
+}
+
+/// Encode a RGB image as an uncompressed 24-bit BMP data URI, which can be embedded in the
+/// document without any image encoding dependency
+fn make_bmp_data_uri(src: &[u8], size: (u32, u32)) -> String {
+    let row_len = (size.0 as usize * 3 + 3) / 4 * 4;
+    let data_len = row_len * size.1 as usize;
+    let mut bmp = Vec::with_capacity(54 + data_len);
+
+    bmp.extend_from_slice(b"BM");
+    bmp.extend_from_slice(&(54 + data_len as u32).to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+    bmp.extend_from_slice(&54u32.to_le_bytes());
+    bmp.extend_from_slice(&40u32.to_le_bytes());
+    bmp.extend_from_slice(&(size.0 as i32).to_le_bytes());
+    // A negative height means the rows are stored from the top to the bottom
+    bmp.extend_from_slice(&(-(size.1 as i32)).to_le_bytes());
+    bmp.extend_from_slice(&1u16.to_le_bytes());
+    bmp.extend_from_slice(&24u16.to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+    bmp.extend_from_slice(&(data_len as u32).to_le_bytes());
+    bmp.extend_from_slice(&2835u32.to_le_bytes());
+    bmp.extend_from_slice(&2835u32.to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+    bmp.extend_from_slice(&0u32.to_le_bytes());
+
+    for row in src.chunks(size.0 as usize * 3) {
+        for pixel in row.chunks(3) {
+            bmp.extend_from_slice(&[pixel[2], pixel[1], pixel[0]]);
+        }
+        bmp.resize(bmp.len() + row_len - row.len(), 0);
+    }
+
+    format!("data:image/bmp;base64,{}", encode_base64(&bmp))
+}
+
+fn encode_base64(data: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut ret = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).cloned().unwrap_or(0),
+            chunk.get(2).cloned().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                ret.push(TABLE[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                ret.push('=');
+            }
+        }
+    }
+    ret
+}
+
 enum Target<'a> {
     File(&'a Path),
     Buffer(Cursor<&'a mut Vec<u8>>),
+    Memory,
 }
 
 /// The SVG image drawing backend
@@ -31,6 +109,13 @@ pub struct SVGBackend<'a> {
     size: (u32, u32),
     document: Option<Document>,
     saved: bool,
+    metadata: Option<BackendMetadata>,
+    text_paths: usize,
+    gradients: usize,
+    filters: usize,
+    blur: Option<String>,
+    clip_paths: usize,
+    clip: Option<String>,
 }
 
 impl<'a> SVGBackend<'a> {
@@ -40,25 +125,76 @@ impl<'a> SVGBackend<'a> {
         self.document = Some(op(temp.unwrap()));
     }
 
+    /// Attach the CSS classes and the current metadata to the node and append it to the
+    /// document. Each node has the class `plotters-<kind>`, followed by the classes from the
+    /// metadata, so the output can be themed with an external stylesheet. The attributes from
+    /// the metadata are set first, so they can't override the class, the filter or the clip path.
+    fn add_node<N: Node>(&mut self, mut node: N, kind: &str) {
+        let mut class = format!("plotters-{}", kind);
+        if let Some(ref metadata) = self.metadata {
+            for (key, value) in metadata.attributes.iter() {
+                node.assign(key.as_str(), value.as_str());
+            }
+            if let Some(ref tooltip) = metadata.tooltip {
+                node.append(Title::new().add(svg::node::Text::new(tooltip.as_str())));
+            }
+            for name in metadata.classes.iter() {
+                class.push(' ');
+                class.push_str(name);
+            }
+        }
+        node.assign("class", class);
+        if let Some(ref filter) = self.blur {
+            node.assign("filter", format!("url(#{})", filter));
+        }
+        if let Some(ref clip) = self.clip {
+            node.assign("clip-path", format!("url(#{})", clip));
+        }
+        self.update_document(|d| d.add(node));
+    }
+
     /// Create a new SVG drawing backend
     pub fn new<T: AsRef<Path> + ?Sized>(path: &'a T, size: (u32, u32)) -> Self {
-        Self {
-            target: Target::File(path.as_ref()),
-            size,
-            document: Some(Document::new().set("viewBox", (0, 0, size.0, size.1))),
-            saved: false,
-        }
+        Self::with_target(Target::File(path.as_ref()), size)
     }
 
     /// Create a new SVG drawing backend and store the document into a u8 buffer
     pub fn with_buffer(buf: &'a mut Vec<u8>, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Buffer(Cursor::new(buf)), size)
+    }
+
+    /// Create a new SVG drawing backend which only keeps the document in memory,
+    /// use `into_string` to get the document once the drawing is done
+    pub fn in_memory(size: (u32, u32)) -> Self {
+        Self::with_target(Target::Memory, size)
+    }
+
+    fn with_target(target: Target<'a>, size: (u32, u32)) -> Self {
         Self {
-            target: Target::Buffer(Cursor::new(buf)),
+            target,
             size,
-            document: Some(Document::new().set("viewBox", (0, 0, size.0, size.1))),
+            document: Some(
+                Document::new()
+                    .set("viewBox", (0, 0, size.0, size.1))
+                    .set("xmlns:xlink", "http://www.w3.org/1999/xlink"),
+            ),
             saved: false,
+            metadata: None,
+            text_paths: 0,
+            gradients: 0,
+            filters: 0,
+            blur: None,
+            clip_paths: 0,
+            clip: None,
         }
     }
+
+    /// Finish the drawing and get the SVG document as a string. The document is written to
+    /// the file or the buffer of the backend first, if it has one.
+    pub fn into_string(mut self) -> Result<String, DrawingErrorKind<Error>> {
+        self.present()?;
+        Ok(self.document.take().unwrap().to_string())
+    }
 }
 
 impl<'a> DrawingBackend for SVGBackend<'a> {
@@ -79,12 +215,71 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     .map_err(DrawingErrorKind::DrawingError)?,
                 Target::Buffer(ref mut w) => svg::write(w, self.document.as_ref().unwrap())
                     .map_err(DrawingErrorKind::DrawingError)?,
+                Target::Memory => {}
             }
             self.saved = true;
         }
         Ok(())
     }
 
+    fn set_metadata(
+        &mut self,
+        metadata: Option<&BackendMetadata>,
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        self.metadata = metadata.cloned();
+        Ok(())
+    }
+
+    fn set_blur(&mut self, radius: u32) -> Result<bool, DrawingErrorKind<Error>> {
+        if radius == 0 {
+            self.blur = None;
+            return Ok(true);
+        }
+        let id = format!("plotters-blur-{}", self.filters);
+        self.filters += 1;
+        // The standard deviation of a box blur with the same radius. The filter region is the
+        // whole image, since the bounding box of a straight line has no height or width.
+        let radius = f64::from(radius);
+        let mut blur = Element::new("feGaussianBlur");
+        blur.assign("stdDeviation", (radius * (radius + 1.0) / 3.0).sqrt());
+        let mut filter = Element::new("filter");
+        filter.assign("id", id.as_str());
+        filter.assign("filterUnits", "userSpaceOnUse");
+        filter.assign("x", 0);
+        filter.assign("y", 0);
+        filter.assign("width", self.size.0);
+        filter.assign("height", self.size.1);
+        filter.append(blur);
+        self.update_document(|d| d.add(Definitions::new().add(filter)));
+        self.blur = Some(id);
+        Ok(true)
+    }
+
+    fn set_clip_polygon(
+        &mut self,
+        polygon: Option<&[BackendCoord]>,
+    ) -> Result<bool, DrawingErrorKind<Error>> {
+        let polygon = match polygon {
+            Some(polygon) => polygon,
+            None => {
+                self.clip = None;
+                return Ok(true);
+            }
+        };
+        let id = format!("plotters-clip-{}", self.clip_paths);
+        self.clip_paths += 1;
+        let points = polygon.iter().fold(String::new(), |mut s, (x, y)| {
+            s.push_str(&format!("{},{} ", x, y));
+            s
+        });
+        let mut clip = Element::new("clipPath");
+        clip.assign("id", id.as_str());
+        clip.append(Polygon::new().set("points", points));
+        self.update_document(|d| d.add(Definitions::new().add(clip)));
+        self.clip = Some(id);
+        Ok(true)
+    }
+
     fn draw_pixel(
         &mut self,
         point: BackendCoord,
@@ -101,7 +296,31 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("stroke", "none")
             .set("opacity", make_svg_opacity(color))
             .set("fill", make_svg_color(color));
-        self.update_document(|d| d.add(node));
+        self.add_node(node, "pixel");
+        Ok(())
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+        src_size: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<Error>> {
+        if src_size.0 == 0 || src_size.1 == 0 {
+            return Ok(());
+        }
+        let data_uri = make_bmp_data_uri(src, src_size);
+        let node = Image::new()
+            .set("x", pos.0)
+            .set("y", pos.1)
+            .set("width", size.0)
+            .set("height", size.1)
+            .set("preserveAspectRatio", "none")
+            .set("image-rendering", "pixelated")
+            .set("href", data_uri.as_str())
+            .set("xlink:href", data_uri);
+        self.add_node(node, "image");
         Ok(())
     }
 
@@ -121,7 +340,7 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
             .set("y2", to.1)
             .set("opacity", make_svg_opacity(&style.as_color()))
             .set("stroke", make_svg_color(&style.as_color()));
-        self.update_document(|d| d.add(node));
+        self.add_node(node, "line");
         Ok(())
     }
 
@@ -153,7 +372,83 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .set("stroke", "none");
         }
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node, "rect");
+        Ok(())
+    }
+
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let (w, h) = (bottom_right.0 - upper_left.0, bottom_right.1 - upper_left.1);
+        let radius = (radius as i32).min(w.abs().min(h.abs()) / 2);
+        let mut node = Rectangle::new()
+            .set("x", upper_left.0.min(bottom_right.0))
+            .set("y", upper_left.1.min(bottom_right.1))
+            .set("width", w.abs())
+            .set("height", h.abs())
+            .set("rx", radius)
+            .set("ry", radius)
+            .set("opacity", make_svg_opacity(&style.as_color()));
+
+        if !fill {
+            node = node
+                .set("stroke", make_svg_color(&style.as_color()))
+                .set("fill", "none");
+        } else {
+            node = node
+                .set("fill", make_svg_color(&style.as_color()))
+                .set("stroke", "none");
+        }
+
+        self.add_node(node, "rect");
+        Ok(())
+    }
+
+    fn fill_top_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let ((x0, y0), (x1, y1), r) = top_rounded_rect(upper_left, bottom_right, radius);
+        let r = r.max(0);
+        let d = format!(
+            "M{},{} L{},{} A{},{} 0 0,1 {},{} L{},{} A{},{} 0 0,1 {},{} L{},{} Z",
+            x0,
+            y1,
+            x0,
+            y0 + r,
+            r,
+            r,
+            x0 + r,
+            y0,
+            x1 - r,
+            y0,
+            r,
+            r,
+            x1,
+            y0 + r,
+            x1,
+            y1
+        );
+        let node = SvgPath::new()
+            .set("fill", make_svg_color(&style.as_color()))
+            .set("opacity", make_svg_opacity(&style.as_color()))
+            .set("stroke", "none")
+            .set("d", d);
+        self.add_node(node, "rect");
         Ok(())
     }
 
@@ -176,7 +471,87 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                     s
                 }),
             );
-        self.update_document(|d| d.add(node));
+        self.add_node(node, "line");
+        Ok(())
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let node = make_svg_polygon(vert, style);
+        self.add_node(node, "polygon");
+        Ok(())
+    }
+
+    fn fill_polygon_with_rule<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+        rule: FillRule,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let rule = match rule {
+            FillRule::EvenOdd => "evenodd",
+            FillRule::NonZero => "nonzero",
+        };
+        let node = make_svg_polygon(vert, style).set("fill-rule", rule);
+        self.add_node(node, "polygon");
+        Ok(())
+    }
+
+    fn fill_polygon_with_vertical_gradient<
+        S: BackendStyle,
+        I: IntoIterator<Item = BackendCoord>,
+    >(
+        &mut self,
+        vert: I,
+        style: &S,
+        (from, to): (i32, i32),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = style.as_color();
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+
+        let id = format!("plotters-gradient-{}", self.gradients);
+        self.gradients += 1;
+
+        let stop = |offset: f64, opacity: String| {
+            Stop::new()
+                .set("offset", offset)
+                .set("stop-color", make_svg_color(&color))
+                .set("stop-opacity", opacity)
+        };
+        let gradient = LinearGradient::new()
+            .set("id", id.as_str())
+            .set("gradientUnits", "userSpaceOnUse")
+            .set("x1", 0)
+            .set("y1", from)
+            .set("x2", 0)
+            .set("y2", 2 * to - from)
+            .add(stop(0.0, make_svg_opacity(&color)))
+            .add(stop(0.5, "0".to_string()))
+            .add(stop(1.0, make_svg_opacity(&color)));
+        self.update_document(|d| d.add(Definitions::new().add(gradient)));
+
+        let node = Polygon::new()
+            .set("fill", format!("url(#{})", id))
+            .set("fill-rule", "evenodd")
+            .set(
+                "points",
+                vert.into_iter().fold(String::new(), |mut s, (x, y)| {
+                    s.push_str(&format!("{},{} ", x, y));
+                    s
+                }),
+            );
+        self.add_node(node, "polygon");
         Ok(())
     }
 
@@ -207,30 +582,81 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
                 .set("stroke", "none");
         }
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node, "circle");
         Ok(())
     }
+
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        (start, end): (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if style.as_color().alpha() == 0.0 {
+            return Ok(());
+        }
+        let point = |angle: f64| {
+            (
+                center.0 + (f64::from(radius) * angle.sin()).round() as i32,
+                center.1 - (f64::from(radius) * angle.cos()).round() as i32,
+            )
+        };
+        // A single arc command can't draw a full circle, so the arc is split into two halves,
+        // neither of which is a large arc
+        let (x0, y0) = point(start);
+        let mut d = format!("M{},{}", x0, y0);
+        for angle in [(start + end) / 2.0, end].iter() {
+            let (x, y) = point(*angle);
+            d.push_str(&format!(" A{},{} 0 0,1 {},{}", radius, radius, x, y));
+        }
+        let node = SvgPath::new()
+            .set("fill", "none")
+            .set("opacity", make_svg_opacity(&style.as_color()))
+            .set("stroke", make_svg_color(&style.as_color()))
+            .set("d", d);
+        self.add_node(node, "arc");
+        Ok(())
+    }
+
     fn draw_text<'b>(
         &mut self,
         text: &str,
         font: &FontDesc<'b>,
         pos: BackendCoord,
         color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.draw_text_subpixel(text, font, (pos.0 as f32, pos.1 as f32), color)
+    }
+    fn draw_text_subpixel<'b>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'b>,
+        pos: (f32, f32),
+        color: &RGBAColor,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         if color.alpha() == 0.0 {
             return Ok(());
         }
+        if let FontTransform::Vertical = font.get_transform() {
+            let glyph_font = font.transform(FontTransform::None);
+            for ((dx, dy), glyph) in font.vertical_glyph_positions(text) {
+                let glyph_pos = (pos.0 + dx as f32, pos.1 + dy as f32);
+                self.draw_text_subpixel(glyph, &glyph_font, glyph_pos, color)?;
+            }
+            return Ok(());
+        }
         let context = svg::node::Text::new(text);
         let layout = font.layout_box(text).map_err(DrawingErrorKind::FontError)?;
 
         let trans = font.get_transform();
         let offset = trans.offset(layout);
-        let x0 = pos.0 + offset.0;
-        let y0 = pos.1 + offset.1;
+        let x0 = pos.0 + offset.0 as f32;
+        let y0 = pos.1 + offset.1 as f32;
 
         let node = Text::new()
             .set("x", x0)
-            .set("y", y0 - (layout.0).1)
+            .set("y", y0 - (layout.0).1 as f32)
             .set("font-family", font.get_name())
             .set("font-size", font.get_size())
             .set("opacity", make_svg_opacity(color))
@@ -248,7 +674,48 @@ impl<'a> DrawingBackend for SVGBackend<'a> {
         }
         .add(context);
 
-        self.update_document(|d| d.add(node));
+        self.add_node(node, "text");
+
+        Ok(())
+    }
+
+    fn draw_text_on_path<'b>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'b>,
+        path: &[BackendCoord],
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        if color.alpha() == 0.0 || path.len() < 2 {
+            return Ok(());
+        }
+
+        let id = format!("plotters-text-path-{}", self.text_paths);
+        self.text_paths += 1;
+
+        let data = path
+            .iter()
+            .enumerate()
+            .map(|(idx, (x, y))| format!("{}{},{}", if idx == 0 { "M" } else { "L" }, x, y))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let defs = Definitions::new().add(SvgPath::new().set("id", id.as_str()).set("d", data));
+        self.update_document(|d| d.add(defs));
+
+        let node = Text::new()
+            .set("font-family", font.get_name())
+            .set("font-size", font.get_size())
+            .set("opacity", make_svg_opacity(color))
+            .set("fill", make_svg_color(color))
+            .add(
+                // The SVG 1.1 renderers only know the `xlink:href` attribute
+                TextPath::new()
+                    .set("href", format!("#{}", id))
+                    .set("xlink:href", format!("#{}", id))
+                    .add(svg::node::Text::new(text)),
+            );
+
+        self.add_node(node, "text");
 
         Ok(())
     }
@@ -261,3 +728,303 @@ impl Drop for SVGBackend<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::coord::Shift;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_svg_metadata_attributes() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            root.draw(&Decorated::new(Circle::new((50, 50), 5, &RED)).data("x", 1))
+                .expect("Drawing Failure");
+            root.draw(&Circle::new((20, 20), 5, &RED))
+                .expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(content.matches("data-x=\"1\"").count(), 1);
+    }
+
+    #[test]
+    fn test_svg_metadata_attributes_dont_override_class() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            root.draw(&Decorated::new(Circle::new((50, 50), 5, &RED)).attribute("class", "x"))
+                .expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert!(content.contains("class=\"plotters-circle\""));
+        assert!(!content.contains("class=\"x\""));
+    }
+
+    #[test]
+    fn test_svg_classes() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            root.draw(&Path::new(vec![(0, 0), (50, 50)], &RED))
+                .expect("Drawing Failure");
+            root.draw(&Decorated::new(Circle::new((50, 50), 5, &RED)).class("highlight"))
+                .expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(content.matches("class=\"plotters-line\"").count(), 1);
+        assert_eq!(
+            content
+                .matches("class=\"plotters-circle highlight\"")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_svg_tooltip() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            root.draw(&Decorated::new(Circle::new((50, 50), 5, &RED)).tooltip("x = 1"))
+                .expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(content.matches("<title>").count(), 1);
+        assert!(content.contains("x = 1"));
+    }
+
+    #[test]
+    fn test_svg_text_on_path() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            root.draw(&TextOnPath::new(
+                "label",
+                vec![(10, 50), (50, 30), (90, 50)],
+                ("Arial", 10).into_font().color(&BLACK),
+            ))
+            .expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert!(content.contains("d=\"M10,50 L50,30 L90,50\""));
+        assert_eq!(content.matches("<textPath").count(), 1);
+        assert!(content.contains("xmlns:xlink=\"http://www.w3.org/1999/xlink\""));
+        assert!(content.contains(" href=\"#plotters-text-path-"));
+        assert!(content.contains("xlink:href=\"#plotters-text-path-"));
+        assert!(content.contains("label"));
+    }
+
+    #[test]
+    fn test_svg_background_image() {
+        assert_eq!(super::encode_base64(b"Man"), "TWFu");
+        assert_eq!(super::encode_base64(b"Ma"), "TWE=");
+        assert_eq!(super::encode_base64(b"M"), "TQ==");
+
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 50)).into_drawing_area();
+            root.draw_background_image(&[255, 0, 0], (1, 1), ImageFit::Fill)
+                .expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(content.matches("<image").count(), 1);
+        assert!(content.contains("width=\"100\""));
+        assert!(content.contains(" href=\"data:image/bmp;base64,Qk0"));
+        assert!(content.contains("xlink:href=\"data:image/bmp;base64,Qk0"));
+    }
+
+    #[test]
+    fn test_svg_arc() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (300, 300)).into_drawing_area();
+            root.draw(&Arc::new(
+                (150, 150),
+                100,
+                (1.5 * std::f64::consts::PI, 0.5 * std::f64::consts::PI),
+                &RED,
+            ))
+            .expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert!(content.contains("d=\"M50,150 A100,100 0 0,1 150,50 A100,100 0 0,1 250,150\""));
+    }
+
+    #[test]
+    fn test_svg_fill_rule() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let mut star = Polygon::new(
+                vec![(50, 0), (80, 90), (0, 35), (100, 35), (20, 90)],
+                RED.filled(),
+            );
+            root.draw(&star).expect("Drawing Failure");
+            star.set_fill_rule(FillRule::NonZero);
+            root.draw(&star).expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(content.matches("<polygon").count(), 2);
+        assert_eq!(content.matches("fill-rule=\"nonzero\"").count(), 1);
+    }
+
+    #[test]
+    fn test_svg_vertical_gradient() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let mut area = Polygon::new(vec![(0, 10), (100, 40), (100, 90), (0, 90)], RED.filled());
+            area.set_fade(true);
+            root.draw(&area).expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert_eq!(content.matches("<linearGradient").count(), 1);
+        assert!(content.contains("y1=\"10\""));
+        // The gradient is mirrored at the transparent row, for the rows past it
+        assert!(content.contains("y2=\"170\""));
+        assert!(content.contains("offset=\"0.5\" stop-color=\"#FF0000\" stop-opacity=\"0\""));
+        assert!(content.contains("fill=\"url(#plotters-gradient-0)\""));
+    }
+
+    #[test]
+    fn test_svg_top_rounded_rect() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let mut bar = Rectangle::new([(10, 50), (30, 0)], RED.filled());
+            bar.set_top_corner_radius(4);
+            root.draw(&bar).expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+        assert!(
+            content.contains("d=\"M10,50 L10,4 A4,4 0 0,1 14,0 L26,0 A4,4 0 0,1 30,4 L30,50 Z\"")
+        );
+    }
+
+    #[test]
+    fn test_svg_into_string() {
+        let backend = Rc::new(RefCell::new(SVGBackend::in_memory((100, 100))));
+        {
+            let root = DrawingArea::<_, Shift>::from(&backend);
+            root.draw(&Circle::new((50, 50), 10, &RED))
+                .expect("Drawing Failure");
+        }
+        let content = Rc::try_unwrap(backend)
+            .ok()
+            .unwrap()
+            .into_inner()
+            .into_string()
+            .expect("Drawing Failure");
+
+        assert!(content.starts_with("<svg"));
+        assert_eq!(content.matches("<circle").count(), 1);
+    }
+
+    #[test]
+    fn test_svg_into_string_writes_buffer() {
+        let mut buffer = vec![];
+        let content = {
+            let backend = Rc::new(RefCell::new(SVGBackend::with_buffer(
+                &mut buffer,
+                (100, 100),
+            )));
+            {
+                let root = DrawingArea::<_, Shift>::from(&backend);
+                root.draw(&Circle::new((50, 50), 10, &RED))
+                    .expect("Drawing Failure");
+            }
+            Rc::try_unwrap(backend)
+                .ok()
+                .unwrap()
+                .into_inner()
+                .into_string()
+                .expect("Drawing Failure")
+        };
+
+        assert_eq!(content.matches("<circle").count(), 1);
+        assert_eq!(String::from_utf8(buffer).unwrap().trim(), content.trim());
+    }
+
+    #[test]
+    fn test_svg_blurred_shadow() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let rect = Rectangle::new([(10, 10), (20, 20)], RED.filled());
+            root.draw(&Shadow::new(rect, (3, 4), &BLACK.mix(0.5)).blur(2))
+                .expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+
+        // The shadow is a single blurred copy, and the element itself isn't blurred
+        assert!(content.contains("<filter"));
+        assert!(content.contains("id=\"plotters-blur-0\""));
+        assert!(content.contains("<feGaussianBlur stdDeviation=\"1.414"));
+        assert_eq!(content.matches("<rect").count(), 2);
+        assert_eq!(
+            content.matches("filter=\"url(#plotters-blur-0)\"").count(),
+            1
+        );
+        assert!(content.contains("x=\"13\" y=\"14\""));
+    }
+
+    #[test]
+    fn test_svg_clip_to_polygon() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let rect = Rectangle::new([(10, 10), (90, 90)], RED.filled());
+            root.draw_clipped_to_polygon(&rect, &[(0, 0), (100, 0), (0, 100)])
+                .expect("Drawing Failure");
+            root.draw(&rect).expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+
+        // The rectangle is masked by a clip path rather than split into the runs, and the clip
+        // path is turned off after the clipped element
+        assert!(content.contains("<clipPath id=\"plotters-clip-0\">"));
+        assert!(content.contains("points=\"0,0 100,0 0,100 \""));
+        assert_eq!(content.matches("<rect").count(), 2);
+        assert_eq!(
+            content
+                .matches("clip-path=\"url(#plotters-clip-0)\"")
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_svg_vertical_text() {
+        let mut buffer = vec![];
+        {
+            let root = SVGBackend::with_buffer(&mut buffer, (100, 100)).into_drawing_area();
+            let style = TextStyle::from(("sans-serif", 12).into_font()).vertical();
+            root.draw_text("日本語", &style, (40, 20))
+                .expect("Drawing Failure");
+        }
+        let content = String::from_utf8(buffer).unwrap();
+
+        // Each glyph is a text element of its own, stacked from top to bottom in one column
+        let ys: Vec<i32> = content
+            .split("<text")
+            .skip(1)
+            .map(|t| {
+                assert!(t.contains("x=\"40\""));
+                let y = &t[t.find(" y=\"").unwrap() + 4..];
+                y[..y.find('"').unwrap()].parse().unwrap()
+            })
+            .collect();
+        assert_eq!(ys.len(), 3);
+        assert_eq!((ys[1] - ys[0], ys[2] - ys[1]), (12, 12));
+        assert!(ys[0] >= 20);
+
+        // The box of the vertical text is one glyph wide and one font size per glyph high
+        let style = TextStyle::from(("sans-serif", 12).into_font()).vertical();
+        let (w, h) = style.font.box_size("日本語").unwrap();
+        assert_eq!(h, 36);
+        assert!(w > 0 && w <= 12);
+    }
+}
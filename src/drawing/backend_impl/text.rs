@@ -0,0 +1,270 @@
+use crate::drawing::backend::{BackendCoord, BackendStyle, DrawingBackend, DrawingErrorKind};
+use crate::style::{Color, FontDesc, RGBAColor};
+
+use std::io::Write;
+
+/// The braille dot bits of the pixels in a 2x4 character cell, indexed by `[y][x]`
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// A character cell of the terminal, which either has some braille dots or a single glyph,
+/// i.e. a shading block or a character of a text
+#[derive(Clone, Copy)]
+struct Cell {
+    dots: u8,
+    glyph: Option<char>,
+    color: Option<(u8, u8, u8)>,
+}
+
+impl Cell {
+    const EMPTY: Cell = Cell {
+        dots: 0,
+        glyph: None,
+        color: None,
+    };
+
+    fn to_char(self) -> char {
+        match self.glyph {
+            Some(c) => c,
+            None if self.dots == 0 => ' ',
+            None => std::char::from_u32(0x2800 + u32::from(self.dots)).unwrap_or(' '),
+        }
+    }
+}
+
+/// Pick the shading block for a fill with the given opacity
+fn shading_block(alpha: f64) -> char {
+    if alpha < 0.3 {
+        '░'
+    } else if alpha < 0.6 {
+        '▒'
+    } else if alpha < 0.9 {
+        '▓'
+    } else {
+        '█'
+    }
+}
+
+enum Target<'a> {
+    Stdout,
+    Buffer(&'a mut String),
+}
+
+/// The backend that rasterizes into a grid of characters, which can be printed to a terminal.
+/// Each character cell is 2x4 pixels drawn as braille dots, so the pixel size of the backend is
+/// twice the number of columns by four times the number of rows. The filled rectangles use
+/// shading blocks on the cells they fully cover, and the text is written as characters.
+pub struct TextDrawingBackend<'a> {
+    target: Target<'a>,
+    size: (u32, u32),
+    cells: Vec<Cell>,
+    saved: bool,
+}
+
+impl<'a> TextDrawingBackend<'a> {
+    /// Create a new text backend which prints the chart with ANSI colors to the standard output
+    /// - `size`: The number of columns and rows of characters
+    pub fn new(size: (u32, u32)) -> Self {
+        Self::with_target(Target::Stdout, size)
+    }
+
+    /// Create a new text backend which writes the chart as plain text into a string
+    /// - `buf`: The string buffer
+    /// - `size`: The number of columns and rows of characters
+    pub fn with_buffer(buf: &'a mut String, size: (u32, u32)) -> Self {
+        Self::with_target(Target::Buffer(buf), size)
+    }
+
+    fn with_target(target: Target<'a>, size: (u32, u32)) -> Self {
+        Self {
+            target,
+            size,
+            cells: vec![Cell::EMPTY; (size.0 * size.1) as usize],
+            saved: false,
+        }
+    }
+
+    fn cell_mut(&mut self, (x, y): BackendCoord) -> Option<&mut Cell> {
+        if x < 0 || y < 0 || x as u32 >= self.size.0 * 2 || y as u32 >= self.size.1 * 4 {
+            return None;
+        }
+        let idx = (y as u32 / 4 * self.size.0 + x as u32 / 2) as usize;
+        self.cells.get_mut(idx)
+    }
+
+    fn render(&self, ansi: bool) -> String {
+        let mut ret = String::new();
+        for row in self.cells.chunks(self.size.0 as usize) {
+            for cell in row {
+                match (ansi, cell.color) {
+                    (true, Some((r, g, b))) => ret.push_str(&format!(
+                        "\x1b[38;2;{};{};{}m{}\x1b[0m",
+                        r,
+                        g,
+                        b,
+                        cell.to_char()
+                    )),
+                    _ => ret.push(cell.to_char()),
+                }
+            }
+            ret.push('\n');
+        }
+        ret
+    }
+}
+
+impl<'a> DrawingBackend for TextDrawingBackend<'a> {
+    type ErrorType = std::io::Error;
+
+    fn get_size(&self) -> (u32, u32) {
+        (self.size.0 * 2, self.size.1 * 4)
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<std::io::Error>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<std::io::Error>> {
+        self.saved = true;
+        let ansi = match self.target {
+            Target::Stdout => true,
+            Target::Buffer(_) => false,
+        };
+        let text = self.render(ansi);
+        match &mut self.target {
+            Target::Stdout => std::io::stdout()
+                .write_all(text.as_bytes())
+                .map_err(DrawingErrorKind::DrawingError),
+            Target::Buffer(buf) => {
+                **buf = text;
+                Ok(())
+            }
+        }
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<std::io::Error>> {
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        let dot = BRAILLE_DOTS[point.1.rem_euclid(4) as usize][point.0.rem_euclid(2) as usize];
+        if let Some(cell) = self.cell_mut(point) {
+            if cell.glyph.is_some() {
+                *cell = Cell::EMPTY;
+            }
+            cell.dots |= dot;
+            cell.color = Some(color.rgb());
+        }
+        Ok(())
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<std::io::Error>> {
+        let color = style.as_color();
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        if !fill {
+            return self.draw_path(
+                vec![
+                    upper_left,
+                    (bottom_right.0, upper_left.1),
+                    bottom_right,
+                    (upper_left.0, bottom_right.1),
+                    upper_left,
+                ],
+                style,
+            );
+        }
+        let (x0, x1) = (
+            upper_left.0.min(bottom_right.0),
+            upper_left.0.max(bottom_right.0),
+        );
+        let (y0, y1) = (
+            upper_left.1.min(bottom_right.1),
+            upper_left.1.max(bottom_right.1),
+        );
+        let block = shading_block(color.alpha());
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                // The cells fully covered by the rectangle are shaded, the partially covered
+                // cells on the border get the braille dots
+                let (cx, cy) = (x - x.rem_euclid(2), y - y.rem_euclid(4));
+                if cx >= x0 && cx < x1 && cy >= y0 && cy + 3 <= y1 {
+                    if let Some(cell) = self.cell_mut((x, y)) {
+                        *cell = Cell {
+                            dots: 0,
+                            glyph: Some(block),
+                            color: Some(color.rgb()),
+                        };
+                    }
+                } else {
+                    self.draw_pixel((x, y), &color)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_text<'b>(
+        &mut self,
+        text: &str,
+        _font: &FontDesc<'b>,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<std::io::Error>> {
+        if color.alpha() == 0.0 {
+            return Ok(());
+        }
+        for (idx, c) in text.chars().enumerate() {
+            if let Some(cell) = self.cell_mut((pos.0 + idx as i32 * 2, pos.1)) {
+                *cell = Cell {
+                    dots: 0,
+                    glyph: Some(c),
+                    color: Some(color.rgb()),
+                };
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TextDrawingBackend<'_> {
+    fn drop(&mut self) {
+        if !self.saved {
+            self.present().expect("Unable to print the text chart");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_text_backend() {
+        let mut buffer = String::new();
+        {
+            let root = TextDrawingBackend::with_buffer(&mut buffer, (4, 2)).into_drawing_area();
+            root.draw(&Pixel::new((0, 0), &RED))
+                .expect("Drawing Failure");
+            root.draw(&Pixel::new((1, 3), &RED))
+                .expect("Drawing Failure");
+            root.draw(&Rectangle::new([(4, 0), (7, 7)], BLUE.filled()))
+                .expect("Drawing Failure");
+            root.draw(&Rectangle::new([(0, 4), (3, 7)], BLUE.mix(0.5).filled()))
+                .expect("Drawing Failure");
+            root.draw_text("x", &("Arial", 10).into_font().color(&BLACK), (2, 0))
+                .expect("Drawing Failure");
+        }
+        assert_eq!(buffer, "⢁x██\n▒▒██\n");
+    }
+}
@@ -13,15 +13,28 @@ Currently we have two backend implemented:
 
 - `BitMapBackend`: The backend that creates bitmap, this is based on `image` crate
 - `SVGBackend`: The backend that creates SVG image, based on `svg` crate.
+- `PDFBackend`: The backend that creates vector PDF document, based on `printpdf` crate.
+  This requires the `pdf` feature.
+- `TextDrawingBackend`: The backend that draws with braille dots and shading blocks in a grid of
+  characters, which can be printed to a terminal.
 
 */
 mod area;
 mod backend_impl;
+mod physical;
+mod progress;
+mod reflow;
 
 pub mod backend;
 
-pub use area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+pub use area::{DrawingArea, DrawingAreaErrorKind, ImageFit, IntoDrawingArea};
 
 pub use backend_impl::*;
 
-pub use backend::DrawingBackend;
+pub use physical::PhysicalSize;
+
+pub use reflow::Reflow;
+
+pub(crate) use progress::ReportProgress;
+
+pub use backend::{DrawingBackend, FillRule};
@@ -0,0 +1,81 @@
+#[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+use super::BitMapBackend;
+#[cfg(feature = "svg")]
+use super::SVGBackend;
+
+/// The physical size of a printed figure, which converts the physical lengths in inches and the
+/// font sizes in points to pixels at the given DPI.
+///
+/// ```rust,no_run
+/// use plotters::prelude::*;
+/// let size = PhysicalSize::inches(6.0, 4.0, 300.0);
+/// let root = size.bitmap("figure.png").into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root)
+///     .caption("Figure", ("Arial", size.font_size(12.0)).into_font())
+///     .x_label_area_size(size.length(0.5))
+///     .build_ranged(0.0..1.0, 0.0..1.0)
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhysicalSize {
+    width: f64,
+    height: f64,
+    dpi: f64,
+}
+
+impl PhysicalSize {
+    /// Create the physical size in inches
+    /// - `width`: The width in inches
+    /// - `height`: The height in inches
+    /// - `dpi`: The number of pixels per inch
+    pub fn inches(width: f64, height: f64, dpi: f64) -> Self {
+        Self { width, height, dpi }
+    }
+
+    /// Get the DPI of the figure
+    pub fn dpi(&self) -> f64 {
+        self.dpi
+    }
+
+    /// Get the size of the figure in pixels
+    pub fn pixel_size(&self) -> (u32, u32) {
+        (self.length(self.width), self.length(self.height))
+    }
+
+    /// Convert a physical length to pixels
+    /// - `inches`: The length in inches
+    /// - **returns**: The length in pixels
+    pub fn length(&self, inches: f64) -> u32 {
+        (inches * self.dpi).round().max(0.0) as u32
+    }
+
+    /// Convert a font size in points (1/72 inch) to the pixel size used by `FontDesc`
+    /// - `points`: The font size in points
+    /// - **returns**: The font size in pixels
+    pub fn font_size(&self, points: f64) -> f64 {
+        points * self.dpi / 72.0
+    }
+
+    /// Create a bitmap backend of the pixel size of the figure
+    /// - `path`: The path of the image file
+    #[cfg(all(not(target_arch = "wasm32"), feature = "image"))]
+    pub fn bitmap<'a, T: AsRef<std::path::Path> + ?Sized>(&self, path: &'a T) -> BitMapBackend<'a> {
+        BitMapBackend::new(path, self.pixel_size())
+    }
+
+    /// Create a SVG backend of the pixel size of the figure
+    /// - `path`: The path of the SVG file
+    #[cfg(feature = "svg")]
+    pub fn svg<'a, T: AsRef<std::path::Path> + ?Sized>(&self, path: &'a T) -> SVGBackend<'a> {
+        SVGBackend::new(path, self.pixel_size())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_physical_size() {
+    let size = PhysicalSize::inches(6.0, 4.5, 300.0);
+    assert_eq!(size.pixel_size(), (1800, 1350));
+    assert_eq!(size.length(0.1), 30);
+    assert_eq!(size.font_size(12.0), 50.0);
+}
@@ -0,0 +1,87 @@
+/// The iterator adapter which reports the completion of a long drawing operation, e.g. a
+/// full-resolution heatmap. The completion is relative to the given total, or estimated from the
+/// lower bound of the size hint of the iterator, which is exact for vectors and ranges but zero
+/// for the iterators like `flat_map`. The callback is invoked whenever another percent is done,
+/// and once with `1.0` when the iterator is exhausted. If the length is unknown, only the
+/// completion at the end is reported.
+pub(crate) struct ReportProgress<I: Iterator, F: FnMut(f64)> {
+    iter: I,
+    total: usize,
+    done: usize,
+    reported: usize,
+    finished: bool,
+    progress: F,
+}
+
+impl<I: Iterator, F: FnMut(f64)> ReportProgress<I, F> {
+    pub(crate) fn new(iter: I, total: Option<usize>, progress: F) -> Self {
+        Self {
+            total: total.unwrap_or_else(|| iter.size_hint().0),
+            iter,
+            done: 0,
+            reported: 0,
+            finished: false,
+            progress,
+        }
+    }
+}
+
+impl<I: Iterator, F: FnMut(f64)> Iterator for ReportProgress<I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.iter.next() {
+            Some(item) => {
+                self.done += 1;
+                if let Some(percent) = (self.done * 100).checked_div(self.total) {
+                    if percent > self.reported && percent < 100 {
+                        self.reported = percent;
+                        (self.progress)(percent as f64 / 100.0);
+                    }
+                }
+                Some(item)
+            }
+            None => {
+                if !self.finished {
+                    self.finished = true;
+                    (self.progress)(1.0);
+                }
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_report_progress() {
+    let mut reported = vec![];
+    assert_eq!(
+        ReportProgress::new(0..250, None, |p| reported.push(p)).count(),
+        250
+    );
+    assert_eq!(reported.len(), 100);
+    assert_eq!(reported[0], 0.01);
+    assert_eq!(reported[98], 0.99);
+    assert_eq!(reported[99], 1.0);
+
+    let mut reported = vec![];
+    let unknown = (0..10).filter(|x| x % 2 == 0);
+    assert_eq!(
+        ReportProgress::new(unknown.clone(), None, |p| reported.push(p)).count(),
+        5
+    );
+    assert_eq!(reported, vec![1.0]);
+
+    // The total of the iterator with an unknown length can be given
+    let mut reported = vec![];
+    assert_eq!(
+        ReportProgress::new(unknown, Some(5), |p| reported.push(p)).count(),
+        5
+    );
+    assert_eq!(reported, vec![0.2, 0.4, 0.6, 0.8, 1.0]);
+}
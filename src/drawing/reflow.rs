@@ -0,0 +1,100 @@
+use super::area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+use super::backend::DrawingBackend;
+use crate::coord::Shift;
+
+/// The wrapper of a plotting function which redraws the whole chart for the size of the
+/// window, e.g. in a GUI or a web page which is resized by the user. The plotting function
+/// builds the chart from scratch on a freshly sized drawing area, so the label areas and
+/// everything else are laid out again for the new dimension.
+///
+/// ```
+/// use plotters::coord::Shift;
+/// use plotters::drawing::DrawingAreaErrorKind;
+/// use plotters::prelude::*;
+///
+/// fn plot(root: &DrawingArea<SVGBackend, Shift>) -> Result<(), DrawingAreaErrorKind<std::io::Error>> {
+///     let mut chart = ChartBuilder::on(root).margin(10).build_ranged(0.0..1.0, 0.0..1.0)?;
+///     chart.draw_series(LineSeries::new(vec![(0.0, 0.0), (1.0, 1.0)], &RED))?;
+///     Ok(())
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut chart = Reflow::new(plot);
+/// let mut buffer = vec![];
+/// // On every resize event, the chart is only redrawn if the size is changed
+/// assert!(chart.render((640, 480), |size| SVGBackend::with_buffer(&mut buffer, size))?);
+/// assert!(!chart.render((640, 480), |size| SVGBackend::with_buffer(&mut buffer, size))?);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Reflow<F> {
+    draw: F,
+    size: Option<(u32, u32)>,
+}
+
+impl<F> Reflow<F> {
+    /// Create a new reflowing chart
+    /// - `draw`: The function plotting the chart on the root drawing area
+    pub fn new(draw: F) -> Self {
+        Self { draw, size: None }
+    }
+
+    /// Get the size of the last render, `None` if the chart hasn't been rendered
+    pub fn size(&self) -> Option<(u32, u32)> {
+        self.size
+    }
+
+    /// Force the next render to redraw the chart even if the size isn't changed, e.g. when the
+    /// data of the chart is updated
+    pub fn invalidate(&mut self) {
+        self.size = None;
+    }
+
+    /// Render the chart for the given size, the chart is only redrawn if the size is changed
+    /// since the last render
+    /// - `size`: The size of the chart in pixels
+    /// - `make_backend`: The function creating the backend of the given size
+    /// - **returns**: If the chart has been redrawn
+    pub fn render<DB, MakeBackend>(
+        &mut self,
+        size: (u32, u32),
+        make_backend: MakeBackend,
+    ) -> Result<bool, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        DB: DrawingBackend,
+        MakeBackend: FnOnce((u32, u32)) -> DB,
+        F: FnMut(&DrawingArea<DB, Shift>) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>,
+    {
+        if self.size == Some(size) {
+            return Ok(false);
+        }
+
+        let root = make_backend(size).into_drawing_area();
+        (self.draw)(&root)?;
+        root.present()?;
+        self.size = Some(size);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_reflow() {
+    use super::MockedBackend;
+
+    let mut sizes = vec![];
+    let mut chart = Reflow::new(|root: &DrawingArea<MockedBackend, Shift>| {
+        sizes.push(root.dim_in_pixel());
+        Ok(())
+    });
+
+    let make_backend = |(w, h): (u32, u32)| MockedBackend::new(w, h);
+    assert!(chart.render((100, 50), make_backend).unwrap());
+    assert!(!chart.render((100, 50), make_backend).unwrap());
+    assert!(chart.render((200, 80), make_backend).unwrap());
+    assert_eq!(chart.size(), Some((200, 80)));
+    chart.invalidate();
+    assert!(chart.render((200, 80), make_backend).unwrap());
+
+    assert_eq!(sizes, vec![(100, 50), (200, 80), (200, 80)]);
+}
@@ -1,7 +1,9 @@
-use super::{Drawable, PointCollection};
-use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use super::{Drawable, LegendSample, LegendShape, PointCollection};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind, FillRule};
 use crate::style::ShapeStyle;
 
+use std::ops::Range;
+
 /// An element of a single pixel
 pub struct Pixel<Coord> {
     pos: Coord,
@@ -76,6 +78,12 @@ impl<Coord> Path<Coord> {
     }
 }
 
+impl<Coord> LegendSample for Path<Coord> {
+    fn legend_shape(&self) -> LegendShape {
+        LegendShape::Line(self.style.clone())
+    }
+}
+
 impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Path<Coord> {
     type Borrow = &'a Coord;
     type IntoIter = &'a [Coord];
@@ -92,6 +100,113 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Path<Coord> {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         backend.draw_path(points, &self.style.color)
     }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() < 2 {
+            return self.draw(
+                points.into_iter().map(|p| truncate_point(p, &clip)),
+                backend,
+            );
+        }
+
+        // Each segment is clipped at the exact intersection with the boundary, the path is
+        // split where it leaves the rectangle and enters again
+        let mut runs = vec![];
+        let mut current: Vec<BackendCoord> = vec![];
+        for segment in points.windows(2) {
+            match clip_segment(segment[0], segment[1], clip.clone()) {
+                Some((from, to)) => {
+                    if current.last() != Some(&from) {
+                        if current.len() > 1 {
+                            runs.push(current);
+                        }
+                        current = vec![from];
+                    }
+                    if current.last() != Some(&to) {
+                        current.push(to);
+                    }
+                }
+                None => {
+                    if current.len() > 1 {
+                        runs.push(current);
+                    }
+                    current = vec![];
+                }
+            }
+        }
+        if current.len() > 1 {
+            runs.push(current);
+        }
+
+        for run in runs {
+            backend.draw_path(run, &self.style.color)?;
+        }
+        Ok(())
+    }
+}
+
+/// Move the point into the rectangle
+/// - `rect`: The pixel ranges of the rectangle, the end of the ranges are excluded
+pub(crate) fn truncate_point(
+    (x, y): BackendCoord,
+    (x_range, y_range): &(Range<i32>, Range<i32>),
+) -> BackendCoord {
+    (
+        x.min(x_range.end - 1).max(x_range.start),
+        y.min(y_range.end - 1).max(y_range.start),
+    )
+}
+
+/// Clip the segment by the rectangle with the Liang-Barsky algorithm
+/// - `from`: The start point of the segment
+/// - `to`: The end point of the segment
+/// - `rect`: The pixel ranges of the rectangle, the end of the ranges are excluded
+/// - **returns**: The visible part of the segment, `None` if it's totally outside
+pub(crate) fn clip_segment(
+    from: BackendCoord,
+    to: BackendCoord,
+    (x_range, y_range): (Range<i32>, Range<i32>),
+) -> Option<(BackendCoord, BackendCoord)> {
+    let (x0, y0) = (f64::from(from.0), f64::from(from.1));
+    let (dx, dy) = (f64::from(to.0) - x0, f64::from(to.1) - y0);
+    let (left, right) = (f64::from(x_range.start), f64::from(x_range.end - 1));
+    let (top, bottom) = (f64::from(y_range.start), f64::from(y_range.end - 1));
+
+    let (mut t0, mut t1) = (0.0f64, 1.0f64);
+    for (p, q) in [
+        (-dx, x0 - left),
+        (dx, right - x0),
+        (-dy, y0 - top),
+        (dy, bottom - y0),
+    ]
+    .iter()
+    {
+        if *p == 0.0 {
+            if *q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if *p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+
+    if t0 > t1 {
+        return None;
+    }
+
+    let point = |t: f64| ((x0 + dx * t).round() as i32, (y0 + dy * t).round() as i32);
+    Some((point(t0), point(t1)))
 }
 
 #[cfg(test)]
@@ -112,11 +227,64 @@ fn test_path_element() {
         .expect("Drawing Failure");
 }
 
+#[cfg(test)]
+#[test]
+fn test_clip_segment() {
+    let rect = (0..101, 0..101);
+    assert_eq!(
+        clip_segment((10, 10), (20, 30), rect.clone()),
+        Some(((10, 10), (20, 30)))
+    );
+    assert_eq!(
+        clip_segment((50, 50), (150, 50), rect.clone()),
+        Some(((50, 50), (100, 50)))
+    );
+    assert_eq!(
+        clip_segment((-50, 0), (50, 100), rect.clone()),
+        Some(((0, 50), (50, 100)))
+    );
+    assert_eq!(clip_segment((110, 0), (120, 100), rect), None);
+}
+
+#[cfg(test)]
+#[test]
+fn test_path_clipped() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_path(|_, path| {
+            assert_eq!(path, vec![(0, 50), (50, 100)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    // The line is clipped where it crosses the edges, rather than moving the points to the
+    // edges, which would change the slope
+    da.draw(&Path::new(vec![(-50, 0), (50, 100), (150, 200)], &BLUE))
+        .expect("Drawing Failure");
+}
+
+/// How the edges of a rectangle are snapped to the pixels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EdgeSnap {
+    /// Both edges are painted, so two adjacent rectangles sharing an edge overlap by one pixel
+    Inclusive,
+    /// The right and bottom edges are left to the next rectangle, so adjacent rectangles
+    /// sharing an edge tile without overlapping. The edges are still rounded to the pixels, so
+    /// bars of the same value width may differ by one pixel when the band isn't a whole number
+    /// of pixels wide. This is the default for the bars of the bar and histogram series.
+    Exclusive,
+}
+
 /// A rectangle element
 pub struct Rectangle<Coord> {
     points: [Coord; 2],
     style: ShapeStyle,
     margin: (u32, u32, u32, u32),
+    relative_span: ((f64, f64), (f64, f64)),
+    corner_radius: u32,
+    top_corner_radius: u32,
+    edge_snap: EdgeSnap,
 }
 
 impl<Coord> Rectangle<Coord> {
@@ -129,6 +297,10 @@ impl<Coord> Rectangle<Coord> {
             points,
             style: style.into(),
             margin: (0, 0, 0, 0),
+            relative_span: ((0.0, 1.0), (0.0, 1.0)),
+            corner_radius: 0,
+            top_corner_radius: 0,
+            edge_snap: EdgeSnap::Inclusive,
         }
     }
 
@@ -141,6 +313,57 @@ impl<Coord> Rectangle<Coord> {
         self.margin = (t, b, l, r);
         self
     }
+
+    /// Shrink the rectangle to a fraction of its size, the shrunk rectangle is centered in the
+    /// original one. Unlike the margin, the gap is relative to the size of the rectangle.
+    /// - `x`: The fraction of the width to keep, e.g. 0.8 keeps 80% of the width
+    /// - `y`: The fraction of the height to keep
+    pub fn set_size_fraction(&mut self, x: f64, y: f64) -> &mut Self {
+        self.set_relative_span(
+            ((1.0 - x) / 2.0, (1.0 + x) / 2.0),
+            ((1.0 - y) / 2.0, (1.0 + y) / 2.0),
+        )
+    }
+
+    /// Shrink the rectangle to a part of it, the part is given by its relative position in the
+    /// original rectangle, where 0.0 is the left (or top) side and 1.0 is the right (or bottom)
+    /// side. This is useful for placing multiple bars side by side in a band.
+    /// - `x`: The relative horizontal span of the part
+    /// - `y`: The relative vertical span of the part
+    pub fn set_relative_span(&mut self, x: (f64, f64), y: (f64, f64)) -> &mut Self {
+        self.relative_span = (x, y);
+        self
+    }
+
+    /// Round the corners of the rectangle, the radius is clamped to the half of the shorter
+    /// side of the rectangle
+    /// - `radius`: The radius of the corners in pixels
+    pub fn set_corner_radius(&mut self, radius: u32) -> &mut Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /// Round only the top corners of a filled rectangle, e.g. the cap of a bar, the radius is
+    /// clamped to the half of the width of the rectangle. Outlined rectangles are not affected.
+    /// - `radius`: The radius of the top corners in pixels
+    pub fn set_top_corner_radius(&mut self, radius: u32) -> &mut Self {
+        self.top_corner_radius = radius;
+        self
+    }
+
+    /// Set how the edges of the rectangle are snapped to the pixels, the default is
+    /// `EdgeSnap::Inclusive` for a single rectangle
+    /// - `snap`: The snapping of the edges
+    pub fn set_edge_snap(&mut self, snap: EdgeSnap) -> &mut Self {
+        self.edge_snap = snap;
+        self
+    }
+}
+
+impl<Coord> LegendSample for Rectangle<Coord> {
+    fn legend_shape(&self) -> LegendShape {
+        LegendShape::Bar(self.style.clone(), None)
+    }
 }
 
 impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Rectangle<Coord> {
@@ -160,11 +383,31 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
         match (points.next(), points.next()) {
             (Some(a), Some(b)) => {
                 let (mut a, mut b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+                let (w, h) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+                let ((x0, x1), (y0, y1)) = self.relative_span;
+                let (a0, a1) = a;
+                a = (a0 + (w * x0).round() as i32, a1 + (h * y0).round() as i32);
+                b = (a0 + (w * x1).round() as i32, a1 + (h * y1).round() as i32);
                 a.1 += self.margin.0 as i32;
                 b.1 -= self.margin.1 as i32;
                 a.0 += self.margin.2 as i32;
                 b.0 -= self.margin.3 as i32;
-                backend.draw_rect(a, b, &self.style.color, self.style.filled)
+                if self.edge_snap == EdgeSnap::Exclusive {
+                    b = ((b.0 - 1).max(a.0), (b.1 - 1).max(a.1));
+                }
+                if self.top_corner_radius > 0 && self.style.filled {
+                    backend.fill_top_rounded_rect(a, b, self.top_corner_radius, &self.style.color)
+                } else if self.corner_radius > 0 {
+                    backend.draw_rounded_rect(
+                        a,
+                        b,
+                        self.corner_radius,
+                        &self.style.color,
+                        self.style.filled,
+                    )
+                } else {
+                    backend.draw_rect(a, b, &self.style.color, self.style.filled)
+                }
             }
             _ => Ok(()),
         }
@@ -190,6 +433,233 @@ fn test_rect_element() {
         .expect("Drawing Failure");
 }
 
+#[cfg(test)]
+#[test]
+fn test_rect_size_fraction() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|_, _, u, d| {
+            assert_eq!([u, d], [(110, 100), (190, 200)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+        });
+    });
+    let mut rect = Rectangle::new([(100, 100), (200, 200)], &BLUE);
+    rect.set_size_fraction(0.8, 1.0);
+    da.draw(&rect).expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_rect_edge_snap() {
+    use crate::prelude::*;
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let da = backend.into_drawing_area();
+    let chart = ChartBuilder::on(&da)
+        .build_ranged(0.0..3.0, 0.0..10.0)
+        .expect("Build chart error");
+    for x in 0..2 {
+        let mut bar = Rectangle::new([(x as f64, 5.0), (x as f64 + 1.0, 0.0)], BLUE.filled());
+        bar.set_edge_snap(EdgeSnap::Exclusive);
+        chart.plotting_area().draw(&bar).expect("Drawing Failure");
+    }
+    let rects = calls.rects();
+    let widths: Vec<_> = rects.iter().map(|(_, _, u, d)| d.0 - u.0 + 1).collect();
+    assert_eq!(widths, vec![33, 33]);
+    // The shared edge is painted by only one of the bars
+    assert_eq!(rects[0].3 .0 + 1, rects[1].2 .0);
+}
+
+#[cfg(test)]
+#[test]
+fn test_rect_edge_snap_uneven_band() {
+    use crate::prelude::*;
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let da = backend.into_drawing_area();
+    let chart = ChartBuilder::on(&da)
+        .build_ranged(0.0..7.0, 0.0..10.0)
+        .expect("Build chart error");
+    for x in 0..7 {
+        let mut bar = Rectangle::new([(x as f64, 5.0), (x as f64 + 1.0, 0.0)], BLUE.filled());
+        bar.set_edge_snap(EdgeSnap::Exclusive);
+        chart.plotting_area().draw(&bar).expect("Drawing Failure");
+    }
+    let rects = calls.rects();
+    // The band is 100 / 7 pixels wide, so the widths differ by one pixel
+    let widths: Vec<_> = rects.iter().map(|(_, _, u, d)| d.0 - u.0 + 1).collect();
+    assert!(widths.iter().all(|w| *w == 14 || *w == 15));
+    assert!(widths.contains(&14) && widths.contains(&15));
+    // But the bars tile the whole plotting area without any gap or overlap
+    assert_eq!(rects[0].2 .0, 0);
+    for pair in rects.windows(2) {
+        assert_eq!(pair[0].3 .0 + 1, pair[1].2 .0);
+    }
+    assert_eq!(rects[6].3 .0, 99);
+}
+
+#[cfg(test)]
+#[test]
+fn test_rect_corner_radius() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, vert| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(vert.len(), 24);
+            assert_eq!(vert[0], (190, 100));
+            assert_eq!(vert[5], (200, 110));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_rect_call, 0);
+        });
+    });
+    let mut rect = Rectangle::new([(100, 100), (200, 150)], BLUE.filled());
+    rect.set_corner_radius(10);
+    da.draw(&rect).expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_rect_top_corner_radius() {
+    use crate::prelude::*;
+    // A bar narrower than two pixels can't have rounded corners, so it's a plain rectangle
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|c, f, u, d| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert!(f);
+            assert_eq!([u, d], [(100, 100), (101, 150)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+            assert_eq!(b.num_draw_circle_call, 0);
+        });
+    });
+    let mut rect = Rectangle::new([(100, 150), (101, 100)], BLUE.filled());
+    rect.set_top_corner_radius(10);
+    da.draw(&rect).expect("Drawing Failure");
+}
+
+/// A polygon element, which is filled if the style is filled, otherwise only the closed outline
+/// is drawn
+pub struct Polygon<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+    fill_rule: Option<FillRule>,
+    fade: bool,
+    fade_toward: Option<usize>,
+}
+
+impl<Coord> Polygon<Coord> {
+    /// Create a new polygon
+    /// - `points`: The vertices of the polygon
+    /// - `style`: The shape style
+    /// - returns the created element
+    pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(points: P, style: S) -> Self {
+        Self {
+            points: points.into(),
+            style: style.into(),
+            fill_rule: None,
+            fade: false,
+            fade_toward: None,
+        }
+    }
+
+    /// Set the fill rule of a self-intersecting polygon, e.g. a star. By default the backend
+    /// decides the rule
+    /// - `rule`: The fill rule
+    pub fn set_fill_rule(&mut self, rule: FillRule) -> &mut Self {
+        self.fill_rule = Some(rule);
+        self
+    }
+
+    /// Make the fill fade out vertically, from the color of the style at the topmost vertex
+    /// to transparent at the bottommost vertex. The fill rule is always even-odd with fading
+    /// - `fade`: If the fill should fade out
+    pub fn set_fade(&mut self, fade: bool) -> &mut Self {
+        self.fade = fade;
+        self
+    }
+
+    /// Make the fill fade out vertically toward the row of a vertex, e.g. the baseline of an
+    /// area, rather than the bottommost vertex. The fill has the color of the style at the
+    /// vertex farthest from the row, and the parts on the other side of the row fade in again.
+    /// - `vertex`: The index of the vertex on the transparent row
+    pub fn set_fade_toward(&mut self, vertex: usize) -> &mut Self {
+        self.fade = true;
+        self.fade_toward = Some(vertex);
+        self
+    }
+}
+
+impl<Coord> LegendSample for Polygon<Coord> {
+    fn legend_shape(&self) -> LegendShape {
+        LegendShape::Bar(self.style.clone(), None)
+    }
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Polygon<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Polygon<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let color = &self.style.color;
+        if !self.style.filled {
+            let points: Vec<_> = points.collect();
+            let first = points.first().cloned();
+            return backend.draw_path(points.into_iter().chain(first), color);
+        }
+        if self.fade {
+            let points: Vec<_> = points.collect();
+            let top = points.iter().map(|p| p.1).min().unwrap_or(0);
+            let bottom = points.iter().map(|p| p.1).max().unwrap_or(0);
+            let rows = match self.fade_toward.and_then(|idx| points.get(idx)) {
+                Some(&(_, y)) if bottom - y > y - top => (bottom, y),
+                Some(&(_, y)) => (top, y),
+                None => (top, bottom),
+            };
+            return backend.fill_polygon_with_vertical_gradient(points, color, rows);
+        }
+        match self.fill_rule {
+            Some(rule) => backend.fill_polygon_with_rule(points, color, rule),
+            None => backend.fill_polygon(points, color),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_polygon_fill_rule() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_fill_polygon(|c, vert| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(vert.len(), 5);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.last_fill_rule, Some(FillRule::NonZero));
+        });
+    });
+    let mut star = Polygon::new(
+        vec![(50, 0), (80, 90), (0, 35), (100, 35), (20, 90)],
+        RED.filled(),
+    );
+    star.set_fill_rule(FillRule::NonZero);
+    da.draw(&star).expect("Drawing Failure");
+}
+
 /// A circle element
 pub struct Circle<Coord> {
     center: Coord,
@@ -212,6 +682,12 @@ impl<Coord> Circle<Coord> {
     }
 }
 
+impl<Coord> LegendSample for Circle<Coord> {
+    fn legend_shape(&self) -> LegendShape {
+        LegendShape::Circle(self.style.clone(), self.size)
+    }
+}
+
 impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Circle<Coord> {
     type Borrow = &'a Coord;
     type IntoIter = std::iter::Once<&'a Coord>;
@@ -0,0 +1,122 @@
+use super::{Drawable, PointCollection};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{ShapeStyle, TextStyle};
+
+/// The gap between the bracket and its label in pixels
+const LABEL_GAP: f64 = 4.0;
+
+/// A bracket spanning between two coordinates, which is a line with a tick at each end, with
+/// an optional label centered on the other side of the line, e.g. to annotate a range of the
+/// data. The ticks point to the right-hand side of the direction from the start to the end,
+/// i.e. down for a bracket from left to right and right for a bracket from bottom to top.
+pub struct Bracket<'a, Coord> {
+    points: [Coord; 2],
+    depth: i32,
+    style: ShapeStyle,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, Coord> Bracket<'a, Coord> {
+    /// Create a new bracket
+    /// - `start`: The start of the bracket
+    /// - `end`: The end of the bracket
+    /// - `depth`: The length of the ticks in pixels, a negative depth flips the ticks
+    /// - `style`: The style of the bracket
+    pub fn new<S: Into<ShapeStyle>>(start: Coord, end: Coord, depth: i32, style: S) -> Self {
+        Self {
+            points: [start, end],
+            depth,
+            style: style.into(),
+            label: None,
+        }
+    }
+
+    /// Attach a label to the bracket, which is centered on the opposite side of the ticks
+    /// - `text`: The label text
+    /// - `style`: The style of the label
+    pub fn label<T: Into<String>, S: Into<TextStyle<'a>>>(mut self, text: T, style: S) -> Self {
+        self.label = Some((text.into(), style.into()));
+        self
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a Bracket<'b, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for Bracket<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (a, b) = match (points.next(), points.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(()),
+        };
+        let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+        let len = (dx * dx + dy * dy).sqrt();
+        if len == 0.0 {
+            return Ok(());
+        }
+        // The unit normal on the right-hand side of the bracket
+        let (nx, ny) = (-dy / len, dx / len);
+        let depth = f64::from(self.depth);
+        let tick = |p: BackendCoord| {
+            (
+                p.0 + (nx * depth).round() as i32,
+                p.1 + (ny * depth).round() as i32,
+            )
+        };
+
+        backend.draw_path(vec![tick(a), a, b, tick(b)], &self.style.color)?;
+
+        if let Some((ref text, ref style)) = self.label {
+            let (w, h) = style.font.box_size(text).unwrap_or((0, 0));
+            let (w, h) = (f64::from(w), f64::from(h));
+            let side = -depth.signum();
+            let side = if side == 0.0 { -1.0 } else { side };
+            let dist = nx.abs() * w / 2.0 + ny.abs() * h / 2.0 + LABEL_GAP;
+            let (cx, cy) = (
+                f64::from(a.0 + b.0) / 2.0 + side * nx * dist,
+                f64::from(a.1 + b.1) / 2.0 + side * ny * dist,
+            );
+            backend.draw_text(
+                text,
+                &style.font,
+                ((cx - w / 2.0).round() as i32, (cy - h / 2.0).round() as i32),
+                &style.color,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_bracket_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert!(
+                path == vec![(100, 60), (100, 50), (200, 50), (200, 60)]
+                    || path == vec![(150, 200), (140, 200), (140, 100), (150, 100)]
+            );
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 2);
+        });
+    });
+    // The ticks point down for a bracket from left to right
+    da.draw(&Bracket::new((100, 50), (200, 50), 10, &RED))
+        .expect("Drawing Failure");
+    // The ticks point right for a bracket from bottom to top
+    da.draw(&Bracket::new((140, 200), (140, 100), 10, &RED))
+        .expect("Drawing Failure");
+}
@@ -3,7 +3,7 @@ use crate::drawing::backend::DrawingBackend;
 use std::borrow::Borrow;
 use std::iter::{once, Once};
 use std::marker::PhantomData;
-use std::ops::Add;
+use std::ops::{Add, Range};
 
 /// An empty composibable element, which is the start point of an ad-hoc composible element
 pub struct EmptyElement<Coord, DB: DrawingBackend> {
@@ -91,6 +91,25 @@ where
         }
         Ok(())
     }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x0, y0)) = pos.next() {
+            self.inner.draw_clipped(
+                self.inner.point_iter().into_iter().map(|p| {
+                    let p = p.borrow();
+                    (p.0 + x0, p.1 + y0)
+                }),
+                clip,
+                backend,
+            )?;
+        }
+        Ok(())
+    }
 }
 
 impl<Coord, DB: DrawingBackend, My, Yours> Add<Yours> for BoxedElement<Coord, DB, My>
@@ -166,6 +185,33 @@ where
         }
         Ok(())
     }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x0, y0)) = pos.next() {
+            self.first.draw_clipped(
+                self.first.point_iter().into_iter().map(|p| {
+                    let p = p.borrow();
+                    (p.0 + x0, p.1 + y0)
+                }),
+                clip.clone(),
+                backend,
+            )?;
+            self.second.draw_clipped(
+                self.second.point_iter().into_iter().map(|p| {
+                    let p = p.borrow();
+                    (p.0 + x0, p.1 + y0)
+                }),
+                clip,
+                backend,
+            )?;
+        }
+        Ok(())
+    }
 }
 
 impl<Coord, DB: DrawingBackend, A, B, C> Add<C> for ComposedElement<Coord, DB, A, B>
@@ -192,3 +238,137 @@ where
         }
     }
 }
+
+/// A group of elements sharing an anchor, which can hold any number of elements of different
+/// types. The elements are placed in the pixel space relative to the anchor, so the group can
+/// be built by a function and reused at many positions, e.g. a callout made of a box, a text
+/// and an arrow which is drawn for each point of a series with `ChartContext::draw_series`.
+pub struct ElementGroup<'a, Coord, DB: DrawingBackend> {
+    anchor: Coord,
+    children: Vec<DynElement<'a, DB, BackendCoord>>,
+}
+
+impl<'a, Coord, DB: DrawingBackend> ElementGroup<'a, Coord, DB> {
+    /// Create an empty group
+    /// - `anchor`: The anchor of the group
+    pub fn at(anchor: Coord) -> Self {
+        Self {
+            anchor,
+            children: vec![],
+        }
+    }
+
+    /// Add an element to the group, the elements are drawn in the order they are added. The
+    /// `+` operator does the same.
+    /// - `element`: The element, whose coordinates are the pixel offsets from the anchor
+    pub fn with<E>(mut self, element: E) -> Self
+    where
+        E: Drawable<DB> + 'a,
+        for<'b> &'b E: PointCollection<'b, BackendCoord>,
+    {
+        self.children.push(element.into_dyn());
+        self
+    }
+
+    /// Get the number of the elements in the group
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Check if the group doesn't have any element
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend, E> Add<E> for ElementGroup<'a, Coord, DB>
+where
+    E: Drawable<DB> + 'a,
+    for<'b> &'b E: PointCollection<'b, BackendCoord>,
+{
+    type Output = Self;
+    fn add(self, element: E) -> Self {
+        self.with(element)
+    }
+}
+
+impl<'b, 'a, Coord, DB: DrawingBackend> PointCollection<'b, Coord>
+    for &'b ElementGroup<'a, Coord, DB>
+{
+    type Borrow = &'b Coord;
+    type IntoIter = Once<&'b Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        once(&self.anchor)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend + 'a> Drawable<DB> for ElementGroup<'a, Coord, DB> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x0, y0)) = pos.next() {
+            for child in self.children.iter() {
+                child.draw(child.point_iter().map(|p| (p.0 + x0, p.1 + y0)), backend)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x0, y0)) = pos.next() {
+            for child in self.children.iter() {
+                let points = child.point_iter().map(|p| (p.0 + x0, p.1 + y0));
+                child.draw_clipped(points, clip.clone(), backend)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_element_group() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|c, _, u, d| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!([u, d], [(95, 95), (105, 105)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+        });
+    });
+    let group = ElementGroup::at((100, 100)) + Rectangle::new([(-5, -5), (5, 5)], &RED);
+    assert_eq!(group.len(), 1);
+    da.draw(&group).expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_composed_path_clipped() {
+    use crate::prelude::*;
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let da = backend.into_drawing_area();
+
+    let path = || Path::new(vec![(-50, 0), (50, 100), (150, 200)], &BLUE);
+    da.draw(&IntoDynElement::<_, (i32, i32)>::into_dyn(path()))
+        .expect("Drawing Failure");
+    da.draw(&(EmptyElement::at((0, 0)) + path()))
+        .expect("Drawing Failure");
+    da.draw(&(EmptyElement::at((0, 0)) + EmptyElement::at((0, 0)) + path()))
+        .expect("Drawing Failure");
+    da.draw(&(ElementGroup::at((0, 0)) + path()))
+        .expect("Drawing Failure");
+
+    // The path is clipped where it crosses the edges, no matter how it's wrapped
+    let paths: Vec<_> = calls.paths().into_iter().map(|(_, path)| path).collect();
+    assert_eq!(paths, vec![vec![(0, 50), (50, 100)]; 4]);
+}
@@ -0,0 +1,100 @@
+/*!
+  The element decorator, which attaches metadata to an element.
+*/
+use crate::drawing::backend::{BackendCoord, BackendMetadata, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, ElementPoints, PointCollection};
+use std::ops::Range;
+
+/// An element that carries metadata, such as custom attributes.
+/// The metadata is passed to the drawing backend when the inner element is drawn, for example
+/// the SVG backend emits the attributes on the generated SVG elements, so that the data value
+/// can be used by JavaScript. Backends that can't carry metadata simply ignore it.
+pub struct Decorated<E> {
+    element: E,
+    metadata: BackendMetadata,
+}
+
+impl<E> Decorated<E> {
+    /// Create a new decorated element without any metadata
+    /// - `element`: The element to decorate
+    pub fn new(element: E) -> Self {
+        Self {
+            element,
+            metadata: BackendMetadata::default(),
+        }
+    }
+
+    /// Attach a custom attribute to the element
+    /// - `key`: The name of the attribute
+    /// - `value`: The value of the attribute
+    pub fn attribute<K: Into<String>, V: ToString>(mut self, key: K, value: V) -> Self {
+        self.metadata
+            .attributes
+            .push((key.into(), value.to_string()));
+        self
+    }
+
+    /// Attach a data value to the element, this is a shortcut for the attribute `data-<name>`
+    /// - `name`: The name of the data value, for example `x`
+    /// - `value`: The data value
+    pub fn data<V: ToString>(self, name: &str, value: V) -> Self {
+        self.attribute(format!("data-{}", name), value)
+    }
+
+    /// Attach a CSS class name to the element, which the SVG backend appends to the `class`
+    /// attribute, so the element can be restyled by an external stylesheet
+    /// - `name`: The class name
+    pub fn class<S: Into<String>>(mut self, name: S) -> Self {
+        self.metadata.classes.push(name.into());
+        self
+    }
+
+    /// Attach a tooltip to the element, which is shown when the mouse hovers over the element
+    /// in a browser. Only the SVG backend supports this, other backends ignore it.
+    /// - `text`: The tooltip text
+    pub fn tooltip<T: Into<String>>(mut self, text: T) -> Self {
+        self.metadata.tooltip = Some(text.into());
+        self
+    }
+
+    /// Get the metadata attached to the element
+    pub fn metadata(&self) -> &BackendMetadata {
+        &self.metadata
+    }
+}
+
+impl<'a, Coord, E> PointCollection<'a, Coord> for &'a Decorated<E>
+where
+    E: ElementPoints<'a, Coord>,
+{
+    type Borrow = E::Borrow;
+    type IntoIter = E::IntoIter;
+    fn point_iter(self) -> Self::IntoIter {
+        self.element.element_points()
+    }
+}
+
+impl<DB: DrawingBackend, E: Drawable<DB>> Drawable<DB> for Decorated<E> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.set_metadata(Some(&self.metadata))?;
+        let result = self.element.draw(pos, backend);
+        backend.set_metadata(None)?;
+        result
+    }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        backend.set_metadata(Some(&self.metadata))?;
+        let result = self.element.draw_clipped(pos, clip, backend);
+        backend.set_metadata(None)?;
+        result
+    }
+}
@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-use crate::element::{Drawable, PointCollection};
+use crate::element::{clip_segment, Drawable, PointCollection};
 use crate::style::ShapeStyle;
 
 pub trait ErrorBarOrient<K, V> {
@@ -133,3 +134,162 @@ impl<K, V, O: ErrorBarOrient<K, V>, DB: DrawingBackend> Drawable<DB> for ErrorBa
         Ok(())
     }
 }
+
+/// The error bar with both the X and the Y uncertainties, which is drawn as a cross of a
+/// horizontal and a vertical whisker with caps. Each side of the whiskers can have its own
+/// length, so asymmetric errors are supported.
+pub struct XYErrorBar<X, Y> {
+    style: ShapeStyle,
+    width: u32,
+    center: (X, Y),
+    x_range: (X, X),
+    y_range: (Y, Y),
+}
+
+impl<X, Y> XYErrorBar<X, Y> {
+    /// Create a new error bar with the X and Y uncertainties
+    /// - `center`: The data point
+    /// - `x_range`: The lower and upper bound of the X value
+    /// - `y_range`: The lower and upper bound of the Y value
+    /// - `style`: The style of the whiskers, the caps and the marker
+    /// - `width`: The width of the caps, the marker's diameter is the same
+    pub fn new<S: Into<ShapeStyle>>(
+        center: (X, Y),
+        x_range: (X, X),
+        y_range: (Y, Y),
+        style: S,
+        width: u32,
+    ) -> Self {
+        Self {
+            style: style.into(),
+            width,
+            center,
+            x_range,
+            y_range,
+        }
+    }
+}
+
+impl<'a, X: 'a + Clone, Y: 'a + Clone> PointCollection<'a, (X, Y)> for &'a XYErrorBar<X, Y> {
+    type Borrow = (X, Y);
+    type IntoIter = Vec<Self::Borrow>;
+    fn point_iter(self) -> Self::IntoIter {
+        let (x, y) = self.center.clone();
+        vec![
+            (x.clone(), y.clone()),
+            (self.x_range.0.clone(), y.clone()),
+            (self.x_range.1.clone(), y),
+            (x.clone(), self.y_range.0.clone()),
+            (x, self.y_range.1.clone()),
+        ]
+    }
+}
+
+/// Get the lines of the whiskers and the caps of a cross error bar. With a clip rectangle the
+/// whiskers are cut at its boundary, and a cap is dropped if the end of its whisker is cut.
+/// - `points`: The center, the left, right, lower and upper ends of the whiskers
+/// - `width`: The width of the caps
+/// - `clip`: The pixel ranges of the clip rectangle, the end of the ranges are excluded
+fn xy_error_bar_lines(
+    points: &[BackendCoord],
+    width: u32,
+    clip: Option<&(Range<i32>, Range<i32>)>,
+) -> Vec<(BackendCoord, BackendCoord)> {
+    let half = width as i32 / 2;
+    let mut lines = vec![];
+    for (idx, &end) in points[1..5].iter().enumerate() {
+        let whisker = match clip {
+            Some(clip) => clip_segment(points[0], end, clip.clone()),
+            None => Some((points[0], end)),
+        };
+        if let Some(whisker) = whisker {
+            lines.push(whisker);
+            if whisker.1 == end {
+                // The X whiskers get the vertical caps and the Y whiskers get the horizontal ones
+                lines.push(if idx < 2 {
+                    ((end.0, end.1 - half), (end.0, end.1 + half))
+                } else {
+                    ((end.0 - half, end.1), (end.0 + half, end.1))
+                });
+            }
+        }
+    }
+    lines
+}
+
+impl<X, Y> XYErrorBar<X, Y> {
+    fn draw_lines<DB: DrawingBackend>(
+        &self,
+        points: &[BackendCoord],
+        clip: Option<&(Range<i32>, Range<i32>)>,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if points.len() < 5 {
+            return Ok(());
+        }
+
+        for (from, to) in xy_error_bar_lines(points, self.width, clip) {
+            backend.draw_line(from, to, &self.style.color)?;
+        }
+
+        let visible = clip.map_or(true, |(x, y)| {
+            x.contains(&points[0].0) && y.contains(&points[0].1)
+        });
+        if visible {
+            backend.draw_circle(
+                points[0],
+                self.width / 2,
+                &self.style.color,
+                self.style.filled,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<X, Y, DB: DrawingBackend> Drawable<DB> for XYErrorBar<X, Y> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(5).collect();
+        self.draw_lines(&points, None, backend)
+    }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.take(5).collect();
+        self.draw_lines(&points, Some(&clip), backend)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_xy_error_bar_lines() {
+    let points = [(50, 50), (40, 50), (70, 50), (50, 55), (50, 20)];
+    let lines = xy_error_bar_lines(&points, 4, None);
+    assert_eq!(lines.len(), 8);
+    assert_eq!(lines[0], ((50, 50), (40, 50)));
+    assert_eq!(lines[1], ((40, 48), (40, 52)));
+    assert_eq!(lines[7], ((48, 20), (52, 20)));
+
+    // The right and the upper whiskers are cut at the boundary, so they have no caps
+    let lines = xy_error_bar_lines(&points, 4, Some(&(0..61, 30..101)));
+    assert_eq!(
+        lines,
+        vec![
+            ((50, 50), (40, 50)),
+            ((40, 48), (40, 52)),
+            ((50, 50), (60, 50)),
+            ((50, 50), (50, 55)),
+            ((48, 55), (52, 55)),
+            ((50, 50), (50, 30)),
+        ]
+    );
+}
@@ -0,0 +1,129 @@
+use super::{Drawable, LegendSample, LegendShape, PointCollection};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{ShapeStyle, TextStyle};
+
+/// A cell of a heatmap, which is a filled rectangle with an optional border and an optional
+/// label centered in the cell
+pub struct HeatmapCell<'a, Coord> {
+    points: [Coord; 2],
+    style: ShapeStyle,
+    border: Option<ShapeStyle>,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, Coord> HeatmapCell<'a, Coord> {
+    /// Create a new heatmap cell
+    /// - `points`: The two opposite corners of the cell
+    /// - `style`: The fill style of the cell
+    pub fn new<S: Into<ShapeStyle>>(points: [Coord; 2], style: S) -> Self {
+        Self {
+            points,
+            style: style.into(),
+            border: None,
+            label: None,
+        }
+    }
+
+    /// Draw a border around the cell
+    /// - `style`: The style of the border
+    pub fn border<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border = Some(style.into());
+        self
+    }
+
+    /// Attach a label to the cell, the label is drawn at the center of the cell
+    /// - `text`: The label text
+    /// - `style`: The style of the label
+    pub fn label<T: Into<String>, S: Into<TextStyle<'a>>>(mut self, text: T, style: S) -> Self {
+        self.label = Some((text.into(), style.into()));
+        self
+    }
+}
+
+impl<'a, Coord> LegendSample for HeatmapCell<'a, Coord> {
+    fn legend_shape(&self) -> LegendShape {
+        LegendShape::Bar(self.style.filled(), self.border.clone())
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a HeatmapCell<'b, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for HeatmapCell<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (a, b) = match (points.next(), points.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Ok(()),
+        };
+        let (a, b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+
+        backend.draw_rect(a, b, &self.style.color, true)?;
+
+        if let Some(ref border) = self.border {
+            backend.draw_rect(a, b, &border.color, false)?;
+        }
+
+        if let Some((ref text, ref style)) = self.label {
+            let (w, h) = style.font.box_size(text).unwrap_or((0, 0));
+            let (x, y) = ((a.0 + b.0) / 2, (a.1 + b.1) / 2);
+            backend.draw_text(
+                text,
+                &style.font,
+                (x - w as i32 / 2, y - h as i32 / 2),
+                &style.color,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_heatmap_cell_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_rect(|c, f, u, d| {
+            assert_eq!(c, RED.to_rgba());
+            assert!(f);
+            assert_eq!([u, d], [(100, 100), (200, 150)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_rect_call, 1);
+        });
+    });
+    da.draw(&HeatmapCell::new([(200, 100), (100, 150)], &RED))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_heatmap_cell_legend() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let da = backend.into_drawing_area();
+    let cell = HeatmapCell::new([(0, 0), (1, 1)], &RED).border(BLUE.filled());
+    da.draw(&cell.legend_shape().sample((10, 20)))
+        .expect("Drawing Failure");
+
+    // The sample is filled like the cell and then outlined with its border, which is never
+    // filled so it doesn't cover the fill
+    assert_eq!(
+        calls.rects(),
+        vec![
+            (RED.to_rgba(), true, (10, 15), (20, 25)),
+            (BLUE.to_rgba(), false, (10, 15), (20, 25)),
+        ]
+    );
+}
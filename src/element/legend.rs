@@ -0,0 +1,89 @@
+use super::{
+    Circle, Cross, Drawable, DynElement, IntoDynElement, Path, PointCollection, Rectangle,
+};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::ShapeStyle;
+
+/// The shape of a legend sample, which reproduces the appearance of a series
+#[derive(Clone)]
+pub enum LegendShape {
+    /// A short line, for the line series
+    Line(ShapeStyle),
+    /// A circle with the given radius, for the point series
+    Circle(ShapeStyle, u32),
+    /// A cross with the given size, for the point series
+    Cross(ShapeStyle, u32),
+    /// A small box with the fill style and the optional border style, for the bars, the areas
+    /// and the cells
+    Bar(ShapeStyle, Option<ShapeStyle>),
+}
+
+impl LegendShape {
+    /// Create the legend sample element
+    /// - `pos`: The left-middle point of the sample
+    /// - **returns**: The legend sample element
+    pub fn sample<'a, DB: DrawingBackend>(
+        &self,
+        (x, y): BackendCoord,
+    ) -> DynElement<'a, DB, BackendCoord> {
+        match self {
+            LegendShape::Line(style) => {
+                Path::new(vec![(x, y), (x + 20, y)], style.clone()).into_dyn()
+            }
+            LegendShape::Circle(style, size) => {
+                Circle::new((x + 10, y), (*size).min(5), style.clone()).into_dyn()
+            }
+            LegendShape::Cross(style, size) => {
+                Cross::new((x + 10, y), (*size).min(5), style.clone()).into_dyn()
+            }
+            LegendShape::Bar(style, None) => {
+                Rectangle::new([(x, y - 5), (x + 10, y + 5)], style.clone()).into_dyn()
+            }
+            LegendShape::Bar(style, Some(border)) => {
+                let mut border = border.clone();
+                border.filled = false;
+                BorderedBox {
+                    corners: [(x, y - 5), (x + 10, y + 5)],
+                    fill: Rectangle::new([(0, 0), (0, 0)], style.clone()),
+                    border: Rectangle::new([(0, 0), (0, 0)], border),
+                }
+                .into_dyn()
+            }
+        }
+    }
+}
+
+/// The sample of a bar with a border, the box is filled first and then outlined
+struct BorderedBox {
+    corners: [BackendCoord; 2],
+    fill: Rectangle<BackendCoord>,
+    border: Rectangle<BackendCoord>,
+}
+
+impl<'a> PointCollection<'a, BackendCoord> for &'a BorderedBox {
+    type Borrow = &'a BackendCoord;
+    type IntoIter = &'a [BackendCoord];
+    fn point_iter(self) -> &'a [BackendCoord] {
+        &self.corners
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for BorderedBox {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let pos: Vec<_> = pos.collect();
+        self.fill.draw(pos.iter().cloned(), backend)?;
+        backend.ensure_prepared()?;
+        self.border.draw(pos.into_iter(), backend)
+    }
+}
+
+/// The elements that know how their legend sample looks like, which allows the legend of a
+/// series to be generated from the series itself, see `ChartContext::draw_series_with_legend`
+pub trait LegendSample {
+    /// Get the shape of the legend sample matching the element
+    fn legend_shape(&self) -> LegendShape;
+}
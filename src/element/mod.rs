@@ -153,6 +153,7 @@
 */
 use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use std::borrow::Borrow;
+use std::ops::Range;
 
 mod basic_shapes;
 pub use basic_shapes::*;
@@ -164,13 +165,34 @@ mod points;
 pub use points::*;
 
 mod composable;
-pub use composable::{ComposedElement, EmptyElement};
+pub use composable::{ComposedElement, ElementGroup, EmptyElement};
 
 mod candlestick;
 pub use candlestick::CandleStick;
 
 mod errorbar;
-pub use errorbar::{ErrorBar, ErrorBarOrientH, ErrorBarOrientV};
+pub use errorbar::{ErrorBar, ErrorBarOrientH, ErrorBarOrientV, XYErrorBar};
+
+mod decorated;
+pub use decorated::Decorated;
+
+mod pie;
+pub use pie::{Arc, Wedge};
+
+mod heatmap;
+pub use heatmap::HeatmapCell;
+
+mod tapered;
+pub use tapered::TaperedPath;
+
+mod bracket;
+pub use bracket::Bracket;
+
+mod shadow;
+pub use shadow::Shadow;
+
+mod legend;
+pub use legend::{LegendSample, LegendShape};
 
 /// A type which is logically a collection of points, under any given coordinate system
 pub trait PointCollection<'a, Coord> {
@@ -184,6 +206,31 @@ pub trait PointCollection<'a, Coord> {
     fn point_iter(self) -> Self::IntoIter;
 }
 
+/// An element whose reference is a point collection. The element wrappers like `Decorated` are
+/// bound on this trait rather than on the reference, which keeps the type inference from
+/// recursing into nested wrappers.
+pub trait ElementPoints<'a, Coord> {
+    /// The item in point iterator
+    type Borrow: Borrow<Coord>;
+
+    /// The point iterator
+    type IntoIter: IntoIterator<Item = Self::Borrow>;
+
+    /// Get the points of the element
+    fn element_points(&'a self) -> Self::IntoIter;
+}
+
+impl<'a, Coord, E: 'a> ElementPoints<'a, Coord> for E
+where
+    &'a E: PointCollection<'a, Coord>,
+{
+    type Borrow = <&'a E as PointCollection<'a, Coord>>::Borrow;
+    type IntoIter = <&'a E as PointCollection<'a, Coord>>::IntoIter;
+    fn element_points(&'a self) -> Self::IntoIter {
+        self.point_iter()
+    }
+}
+
 /// The trait indicates we are able to draw it on a drawing area
 pub trait Drawable<DB: DrawingBackend> {
     /// Actually draws the element. The key points is already translated into the
@@ -193,6 +240,19 @@ pub trait Drawable<DB: DrawingBackend> {
         pos: I,
         backend: &mut DB,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    /// Draws the element clipped by a rectangle, the key points may be out of the rectangle.
+    /// By default the key points are moved into the rectangle, the elements like the path
+    /// override this to clip the shape exactly at the boundary.
+    /// - `clip`: The pixel ranges of the rectangle, the end of the ranges are excluded
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.draw(pos.map(|p| truncate_point(p, &clip)), backend)
+    }
 }
 
 trait DynDrawable<'a, DB: DrawingBackend>
@@ -204,6 +264,13 @@ where
         points: &mut dyn Iterator<Item = BackendCoord>,
         backend: &mut DB,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
+
+    fn draw_dyn_clipped(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
 }
 
 impl<'a, DB: DrawingBackend, T: Drawable<DB> + 'a> DynDrawable<'a, DB> for T {
@@ -214,6 +281,15 @@ impl<'a, DB: DrawingBackend, T: Drawable<DB> + 'a> DynDrawable<'a, DB> for T {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         T::draw(self, points, backend)
     }
+
+    fn draw_dyn_clipped(
+        &self,
+        points: &mut dyn Iterator<Item = BackendCoord>,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        T::draw_clipped(self, points, clip, backend)
+    }
 }
 
 /// The container for a dynamically dispatched element
@@ -244,6 +320,15 @@ impl<'a, DB: DrawingBackend + 'a, Coord: Clone> Drawable<DB> for DynElement<'a,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         self.drawable.draw_dyn(&mut pos, backend)
     }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        self.drawable.draw_dyn_clipped(&mut pos, clip, backend)
+    }
 }
 
 /// The trait that makes the conversion from the statically dispatched element
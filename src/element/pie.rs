@@ -0,0 +1,261 @@
+use std::f64::consts::PI;
+
+use super::{Drawable, PointCollection};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::{ShapeStyle, TextStyle};
+
+/// A wedge element, which is a slice of a pie chart, or a slice of a donut chart if the inner
+/// radius is set.
+/// The angles are in radian, measured clockwise from 12 o'clock.
+pub struct Wedge<'a, Coord> {
+    center: Coord,
+    radius: u32,
+    inner_radius: u32,
+    angles: (f64, f64),
+    style: ShapeStyle,
+    label: Option<(String, TextStyle<'a>)>,
+}
+
+impl<'a, Coord> Wedge<'a, Coord> {
+    /// Create a new wedge element
+    /// - `center`: The center of the pie
+    /// - `radius`: The radius of the pie
+    /// - `angles`: The start and end angle of the wedge
+    /// - `style`: The style of the wedge
+    /// - Return the newly created wedge element
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        radius: u32,
+        angles: (f64, f64),
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0,
+            angles,
+            style: style.into(),
+            label: None,
+        }
+    }
+
+    /// Set the inner radius of the wedge, which makes it a slice of a donut
+    /// - `radius`: The inner radius
+    pub fn inner_radius(mut self, radius: u32) -> Self {
+        self.inner_radius = radius;
+        self
+    }
+
+    /// Attach a label to the wedge, the label is drawn at the middle of the wedge
+    /// - `text`: The label text
+    /// - `style`: The style of the label
+    pub fn label<T: Into<String>, S: Into<TextStyle<'a>>>(mut self, text: T, style: S) -> Self {
+        self.label = Some((text.into(), style.into()));
+        self
+    }
+}
+
+/// Get the point on the circle for the given angle, which is measured clockwise from 12 o'clock
+fn point_on_circle(center: BackendCoord, radius: f64, angle: f64) -> BackendCoord {
+    (
+        center.0 + (radius * angle.sin()).round() as i32,
+        center.1 - (radius * angle.cos()).round() as i32,
+    )
+}
+
+/// Approximate the arc with a sequence of points, one point for each degree
+fn arc_points(
+    center: BackendCoord,
+    radius: u32,
+    (start, end): (f64, f64),
+) -> impl Iterator<Item = BackendCoord> {
+    let steps = ((end - start).abs() * 180.0 / PI).ceil().max(1.0) as usize;
+    (0..=steps).map(move |idx| {
+        let angle = start + (end - start) * idx as f64 / steps as f64;
+        point_on_circle(center, f64::from(radius), angle)
+    })
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a Wedge<'b, Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for Wedge<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let center = match points.next() {
+            Some(center) => center,
+            None => return Ok(()),
+        };
+
+        let color = &self.style.color;
+        let full_circle = (self.angles.1 - self.angles.0).abs() >= 2.0 * PI;
+
+        if full_circle && (self.inner_radius == 0 || !self.style.filled) {
+            // A single slice that takes the whole pie doesn't have any edge on its side
+            backend.draw_circle(center, self.radius, color, self.style.filled)?;
+            if self.inner_radius > 0 {
+                backend.draw_circle(center, self.inner_radius, color, false)?;
+            }
+        } else {
+            let mut vert: Vec<_> = arc_points(center, self.radius, self.angles).collect();
+            if self.inner_radius > 0 {
+                vert.extend(arc_points(
+                    center,
+                    self.inner_radius,
+                    (self.angles.1, self.angles.0),
+                ));
+            } else {
+                vert.push(center);
+            }
+
+            if self.style.filled {
+                backend.fill_polygon(vert, color)?;
+            } else {
+                vert.push(vert[0]);
+                backend.draw_path(vert, color)?;
+            }
+        }
+
+        if let Some((ref text, ref style)) = self.label {
+            let (w, h) = style.font.box_size(text).unwrap_or((0, 0));
+            let (x, y) = point_on_circle(
+                center,
+                f64::from(self.radius + self.inner_radius) / 2.0,
+                (self.angles.0 + self.angles.1) / 2.0,
+            );
+            backend.draw_text(
+                text,
+                &style.font,
+                (x - w as i32 / 2, y - h as i32 / 2),
+                &style.color,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An arc element, which is a part of the outline of a circle, e.g. the scale of a gauge.
+/// The angles are in radian, measured clockwise from 12 o'clock, and the arc always goes
+/// clockwise from the start angle to the end angle, so an arc from 350° to 10° crosses
+/// 12 o'clock rather than going around the other side.
+pub struct Arc<Coord> {
+    center: Coord,
+    radius: u32,
+    angles: (f64, f64),
+    style: ShapeStyle,
+}
+
+impl<Coord> Arc<Coord> {
+    /// Create a new arc element
+    /// - `center`: The center of the circle
+    /// - `radius`: The radius of the circle
+    /// - `angles`: The start and end angle of the arc
+    /// - `style`: The style of the arc
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        radius: u32,
+        angles: (f64, f64),
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            angles,
+            style: style.into(),
+        }
+    }
+
+    /// Get the clockwise span of the arc, which is in `[0, 2π]`
+    fn span(&self) -> f64 {
+        let span = self.angles.1 - self.angles.0;
+        if span.abs() >= 2.0 * PI {
+            2.0 * PI
+        } else {
+            span.rem_euclid(2.0 * PI)
+        }
+    }
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Arc<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Arc<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(center) = points.next() {
+            let start = self.angles.0;
+            backend.draw_arc(
+                center,
+                self.radius,
+                (start, start + self.span()),
+                &self.style.color,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_wedge_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, vert| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(vert[0], (150, 50));
+            assert_eq!(vert[vert.len() - 2], (250, 150));
+            assert_eq!(vert[vert.len() - 1], (150, 150));
+        });
+        m.check_draw_circle(|_, f, center, radius| {
+            assert!(f);
+            assert_eq!(center, (150, 150));
+            assert_eq!(radius, 100);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_circle_call, 1);
+        });
+    });
+    da.draw(&Wedge::new((150, 150), 100, (0.0, PI / 2.0), RED.filled()))
+        .expect("Drawing Failure");
+    da.draw(&Wedge::new((150, 150), 100, (0.0, 2.0 * PI), RED.filled()))
+        .expect("Drawing Failure");
+}
+
+#[cfg(test)]
+#[test]
+fn test_arc_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_path(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path.len(), 181);
+            assert_eq!(path[0], (50, 150));
+            assert_eq!(path[90], (150, 50));
+            assert_eq!(path[180], (250, 150));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+    // The arc from 9 o'clock to 3 o'clock crosses 12 o'clock
+    da.draw(&Arc::new((150, 150), 100, (1.5 * PI, 0.5 * PI), &RED))
+        .expect("Drawing Failure");
+}
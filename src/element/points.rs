@@ -25,6 +25,12 @@ impl<Coord> Cross<Coord> {
     }
 }
 
+impl<Coord> LegendSample for Cross<Coord> {
+    fn legend_shape(&self) -> LegendShape {
+        LegendShape::Cross(self.style.clone(), self.size)
+    }
+}
+
 impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Cross<Coord> {
     type Borrow = &'a Coord;
     type IntoIter = std::iter::Once<&'a Coord>;
@@ -44,6 +50,7 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Cross<Coord> {
             let (x0, y0) = (x - size, y - size);
             let (x1, y1) = (x + size, y + size);
             backend.draw_line((x0, y0), (x1, y1), &self.style.color)?;
+            backend.ensure_prepared()?;
             backend.draw_line((x0, y1), (x1, y0), &self.style.color)?;
         }
         Ok(())
@@ -66,3 +73,268 @@ impl<Coord> PointElement<Coord> for Pixel<Coord> {
         Self::new(pos, style)
     }
 }
+
+/// Define a marker which is a polygon in the box of `2 * size` by `2 * size` centered at the
+/// point, the polygon is filled if the style is filled, otherwise only the outline is drawn
+macro_rules! def_polygon_marker {
+    ($(#[$doc:meta])* $name:ident, |$s:ident| $vertices:expr) => {
+        $(#[$doc])*
+        pub struct $name<Coord> {
+            center: Coord,
+            size: u32,
+            style: ShapeStyle,
+        }
+
+        impl<Coord> $name<Coord> {
+            /// Create a new marker
+            /// - `coord`: The center of the marker
+            /// - `size`: The half of the width and height of the marker
+            /// - `style`: The style of the marker
+            pub fn new<T: Into<ShapeStyle>>(coord: Coord, size: u32, style: T) -> Self {
+                Self {
+                    center: coord,
+                    size,
+                    style: style.into(),
+                }
+            }
+        }
+
+        impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a $name<Coord> {
+            type Borrow = &'a Coord;
+            type IntoIter = std::iter::Once<&'a Coord>;
+            fn point_iter(self) -> std::iter::Once<&'a Coord> {
+                std::iter::once(&self.center)
+            }
+        }
+
+        impl<Coord, DB: DrawingBackend> Drawable<DB> for $name<Coord> {
+            fn draw<I: Iterator<Item = BackendCoord>>(
+                &self,
+                mut points: I,
+                backend: &mut DB,
+            ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+                if let Some((x, y)) = points.next() {
+                    let $s = self.size as i32;
+                    let vertices: Vec<BackendCoord> = $vertices;
+                    let mut vertices: Vec<_> =
+                        vertices.into_iter().map(|(dx, dy)| (x + dx, y + dy)).collect();
+                    if self.style.filled {
+                        backend.fill_polygon(vertices, &self.style.color)?;
+                    } else {
+                        vertices.push(vertices[0]);
+                        backend.draw_path(vertices, &self.style.color)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        impl<Coord> PointElement<Coord> for $name<Coord> {
+            fn make_point(pos: Coord, size: u32, style: ShapeStyle) -> Self {
+                Self::new(pos, size, style)
+            }
+        }
+    };
+}
+
+def_polygon_marker!(
+    /// Describe a square marker
+    Square,
+    |s| vec![(-s, -s), (s, -s), (s, s), (-s, s)]
+);
+
+def_polygon_marker!(
+    /// Describe a diamond marker, which is a square standing on its corner
+    Diamond,
+    |s| vec![(0, -s), (s, 0), (0, s), (-s, 0)]
+);
+
+def_polygon_marker!(
+    /// Describe a triangle marker pointing up
+    TriangleUp,
+    |s| vec![(0, -s), (s, s), (-s, s)]
+);
+
+def_polygon_marker!(
+    /// Describe a triangle marker pointing down
+    TriangleDown,
+    |s| vec![(-s, -s), (s, -s), (0, s)]
+);
+
+def_polygon_marker!(
+    /// Describe a triangle marker pointing left
+    TriangleLeft,
+    |s| vec![(-s, 0), (s, -s), (s, s)]
+);
+
+def_polygon_marker!(
+    /// Describe a triangle marker pointing right
+    TriangleRight,
+    |s| vec![(s, 0), (-s, s), (-s, -s)]
+);
+
+/// Define a marker which is made of the strokes in the box of `2 * size` by `2 * size` centered
+/// at the point, like `Cross`
+macro_rules! def_stroke_marker {
+    ($(#[$doc:meta])* $name:ident, |$s:ident| $strokes:expr) => {
+        $(#[$doc])*
+        pub struct $name<Coord> {
+            center: Coord,
+            size: u32,
+            style: ShapeStyle,
+        }
+
+        impl<Coord> $name<Coord> {
+            /// Create a new marker
+            /// - `coord`: The center of the marker
+            /// - `size`: The half of the width and height of the marker
+            /// - `style`: The style of the marker
+            pub fn new<T: Into<ShapeStyle>>(coord: Coord, size: u32, style: T) -> Self {
+                Self {
+                    center: coord,
+                    size,
+                    style: style.into(),
+                }
+            }
+        }
+
+        impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a $name<Coord> {
+            type Borrow = &'a Coord;
+            type IntoIter = std::iter::Once<&'a Coord>;
+            fn point_iter(self) -> std::iter::Once<&'a Coord> {
+                std::iter::once(&self.center)
+            }
+        }
+
+        impl<Coord, DB: DrawingBackend> Drawable<DB> for $name<Coord> {
+            fn draw<I: Iterator<Item = BackendCoord>>(
+                &self,
+                mut points: I,
+                backend: &mut DB,
+            ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+                if let Some((x, y)) = points.next() {
+                    let $s = self.size as i32;
+                    let strokes: Vec<(BackendCoord, BackendCoord)> = $strokes;
+                    for ((x0, y0), (x1, y1)) in strokes {
+                        backend.ensure_prepared()?;
+                        backend.draw_line((x + x0, y + y0), (x + x1, y + y1), &self.style.color)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        impl<Coord> PointElement<Coord> for $name<Coord> {
+            fn make_point(pos: Coord, size: u32, style: ShapeStyle) -> Self {
+                Self::new(pos, size, style)
+            }
+        }
+    };
+}
+
+def_stroke_marker!(
+    /// Describe a plus marker
+    Plus,
+    |s| vec![((-s, 0), (s, 0)), ((0, -s), (0, s))]
+);
+
+def_stroke_marker!(
+    /// Describe an asterisk marker, which is a plus overlaid with a cross whose arms have the
+    /// same length as the plus
+    Asterisk,
+    |s| {
+        let d = (f64::from(s) * std::f64::consts::FRAC_1_SQRT_2).round() as i32;
+        vec![
+            ((-s, 0), (s, 0)),
+            ((0, -s), (0, s)),
+            ((-d, -d), (d, d)),
+            ((-d, d), (d, -d)),
+        ]
+    }
+);
+
+#[cfg(test)]
+#[test]
+fn test_polygon_marker() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(300, 300);
+    let calls = backend.record_calls();
+    let da = backend.into_drawing_area();
+    let center = (100, 100);
+    da.draw(&TriangleUp::new(center, 5, RED.filled()))
+        .expect("Drawing Failure");
+    da.draw(&TriangleDown::new(center, 5, RED.filled()))
+        .expect("Drawing Failure");
+    da.draw(&TriangleLeft::new(center, 5, RED.filled()))
+        .expect("Drawing Failure");
+    da.draw(&TriangleRight::new(center, 5, RED.filled()))
+        .expect("Drawing Failure");
+    da.draw(&Square::new(center, 5, RED.filled()))
+        .expect("Drawing Failure");
+    da.draw(&Diamond::new(center, 5, RED.filled()))
+        .expect("Drawing Failure");
+    // The outline of an unfilled marker is closed
+    da.draw(&Square::new(center, 5, &BLUE))
+        .expect("Drawing Failure");
+
+    let polygons: Vec<_> = calls.polygons().into_iter().map(|(_, vert)| vert).collect();
+    assert!(calls.polygons().iter().all(|(c, _)| *c == RED.to_rgba()));
+    assert_eq!(
+        polygons,
+        vec![
+            vec![(100, 95), (105, 105), (95, 105)],
+            vec![(95, 95), (105, 95), (100, 105)],
+            vec![(95, 100), (105, 95), (105, 105)],
+            vec![(105, 100), (95, 105), (95, 95)],
+            vec![(95, 95), (105, 95), (105, 105), (95, 105)],
+            vec![(100, 95), (105, 100), (100, 105), (95, 100)],
+        ]
+    );
+    assert_eq!(
+        calls.paths(),
+        vec![(
+            BLUE.to_rgba(),
+            vec![(95, 95), (105, 95), (105, 105), (95, 105), (95, 95)]
+        )]
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_stroke_marker() {
+    use crate::coord::Shift;
+    use crate::prelude::*;
+
+    let strokes = |draw: &dyn Fn(&DrawingArea<MockedBackend, Shift>)| {
+        let mut backend = MockedBackend::new(300, 300);
+        let calls = backend.record_calls();
+        draw(&backend.into_drawing_area());
+        calls
+            .lines()
+            .into_iter()
+            .map(|(_, from, to)| (from, to))
+            .collect::<Vec<_>>()
+    };
+
+    let plus = strokes(&|da| {
+        da.draw(&Plus::new((100, 100), 5, &RED))
+            .expect("Drawing Failure")
+    });
+    assert_eq!(plus, vec![((95, 100), (105, 100)), ((100, 95), (100, 105))]);
+
+    // The diagonal arms have the same length as the straight ones
+    let asterisk = strokes(&|da| {
+        da.draw(&Asterisk::new((100, 100), 5, &RED))
+            .expect("Drawing Failure")
+    });
+    assert_eq!(
+        asterisk,
+        vec![
+            ((95, 100), (105, 100)),
+            ((100, 95), (100, 105)),
+            ((96, 96), (104, 104)),
+            ((96, 104), (104, 96)),
+        ]
+    );
+}
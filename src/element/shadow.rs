@@ -0,0 +1,440 @@
+/*!
+  The drop shadow decorator, which draws a tinted and offset copy under an element.
+*/
+use crate::drawing::backend::{
+    BackendCoord, BackendMetadata, BackendStyle, DrawingBackend, DrawingErrorKind, FillRule,
+};
+use crate::element::{Drawable, ElementPoints, PointCollection};
+use crate::style::{Color, FontDesc, RGBAColor};
+use std::ops::Range;
+
+/// An element with a drop shadow, which gives a sense of depth to the gridlines, bars and
+/// markers of a chart. The shadow is a copy of the element in the shadow color, shifted by the
+/// offset and drawn before the element itself. With a blur radius, the backends that can blur
+/// (e.g. the SVG backend) blur the copy by themselves. On the other backends the copy is spread
+/// over a box around the offset, each piece with a fraction of the opacity, so the edge of the
+/// shadow fades out like a box blur.
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0..5, 0..10)?;
+/// let data = vec![(0, 3), (1, 7), (2, 5), (3, 9)];
+/// chart.draw_series(data.iter().map(|&(x, y)| {
+///     Shadow::new(Rectangle::new([(x, 0), (x + 1, y)], BLUE.filled()), (3, 3), &BLACK.mix(0.3))
+///         .blur(2)
+/// }))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Shadow<E> {
+    element: E,
+    offset: (i32, i32),
+    color: RGBAColor,
+    blur: u32,
+}
+
+impl<E> Shadow<E> {
+    /// Create a new element with a hard drop shadow
+    /// - `element`: The element that casts the shadow
+    /// - `offset`: The offset of the shadow in pixels
+    /// - `color`: The color of the shadow, the opacity of the element is applied on top of it
+    pub fn new<C: Color>(element: E, offset: (i32, i32), color: &C) -> Self {
+        Self {
+            element,
+            offset,
+            color: color.to_rgba(),
+            blur: 0,
+        }
+    }
+
+    /// Soften the edge of the shadow
+    /// - `radius`: The radius of the box blur in pixels, 0 means a hard shadow
+    pub fn blur(mut self, radius: u32) -> Self {
+        self.blur = radius;
+        self
+    }
+
+    /// Get the offsets and the colors of the copies making up the box blurred shadow
+    fn copies(&self) -> Vec<((i32, i32), RGBAColor)> {
+        let radius = self.blur as i32;
+        let count = ((2 * radius + 1) * (2 * radius + 1)) as f64;
+        let color = self.color.mix(1.0 / count);
+        let mut copies = vec![];
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                copies.push(((self.offset.0 + dx, self.offset.1 + dy), color.clone()));
+            }
+        }
+        copies
+    }
+
+    /// Draw the shadow with the given drawing function. If the backend can blur, the function
+    /// is called once with the blur turned on, otherwise once for each copy of the box blur.
+    /// - `backend`: The backend to draw the shadow on
+    /// - `draw`: The function that draws the shape casting the shadow on the shadow backend
+    pub(crate) fn draw_shadow<DB: DrawingBackend, F>(
+        &self,
+        backend: &mut DB,
+        mut draw: F,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>>
+    where
+        F: FnMut(&mut ShadowBackend<DB>) -> Result<(), DrawingErrorKind<DB::ErrorType>>,
+    {
+        if self.blur > 0 && backend.set_blur(self.blur)? {
+            backend.ensure_prepared()?;
+            let result = draw(&mut ShadowBackend {
+                inner: &mut *backend,
+                offset: self.offset,
+                color: self.color.clone(),
+            });
+            backend.set_blur(0)?;
+            return result;
+        }
+        for (offset, color) in self.copies() {
+            // Each copy is drawn with drawing calls of its own, so the backend is prepared for it
+            backend.ensure_prepared()?;
+            draw(&mut ShadowBackend {
+                inner: &mut *backend,
+                offset,
+                color,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, Coord, E> PointCollection<'a, Coord> for &'a Shadow<E>
+where
+    E: ElementPoints<'a, Coord>,
+{
+    type Borrow = E::Borrow;
+    type IntoIter = E::IntoIter;
+    fn point_iter(self) -> Self::IntoIter {
+        self.element.element_points()
+    }
+}
+
+impl<DB: DrawingBackend, E> Drawable<DB> for Shadow<E>
+where
+    E: Drawable<DB> + for<'b> Drawable<ShadowBackend<'b, DB>>,
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let pos: Vec<_> = pos.collect();
+        self.draw_shadow(backend, |shadow| {
+            self.element.draw(pos.iter().cloned(), shadow)
+        })?;
+        backend.ensure_prepared()?;
+        self.element.draw(pos.into_iter(), backend)
+    }
+
+    fn draw_clipped<I: Iterator<Item = BackendCoord>>(
+        &self,
+        pos: I,
+        clip: (Range<i32>, Range<i32>),
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let pos: Vec<_> = pos.collect();
+        self.draw_shadow(backend, |shadow| {
+            // The copy is shifted after clipping, so it's clipped by the rectangle shifted back
+            let ((x, y), offset) = (&clip, shadow.offset);
+            let shadow_clip = (
+                x.start - offset.0..x.end - offset.0,
+                y.start - offset.1..y.end - offset.1,
+            );
+            self.element
+                .draw_clipped(pos.iter().cloned(), shadow_clip, shadow)
+        })?;
+        backend.ensure_prepared()?;
+        self.element.draw_clipped(pos.into_iter(), clip, backend)
+    }
+}
+
+/// The backend adapter used to draw the shadow of an element: every shape is shifted by the
+/// offset and painted in the shadow color, keeping the opacity of the original style
+pub struct ShadowBackend<'b, DB: DrawingBackend> {
+    inner: &'b mut DB,
+    offset: (i32, i32),
+    color: RGBAColor,
+}
+
+impl<'b, DB: DrawingBackend> ShadowBackend<'b, DB> {
+    fn shift(&self, (x, y): BackendCoord) -> BackendCoord {
+        (x + self.offset.0, y + self.offset.1)
+    }
+
+    fn tint(&self, color: &RGBAColor) -> RGBAColor {
+        self.color.mix(color.alpha())
+    }
+}
+
+impl<'b, DB: DrawingBackend> DrawingBackend for ShadowBackend<'b, DB> {
+    type ErrorType = DB::ErrorType;
+
+    fn get_size(&self) -> (u32, u32) {
+        self.inner.get_size()
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.ensure_prepared()
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        self.inner.present()
+    }
+
+    fn set_metadata(
+        &mut self,
+        _metadata: Option<&BackendMetadata>,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn set_blur(&mut self, radius: u32) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.set_blur(radius)
+    }
+
+    fn set_clip_polygon(
+        &mut self,
+        polygon: Option<&[BackendCoord]>,
+    ) -> Result<bool, DrawingErrorKind<Self::ErrorType>> {
+        self.inner.set_clip_polygon(polygon)
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (point, color) = (self.shift(point), self.tint(color));
+        self.inner.draw_pixel(point, &color)
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(&style.as_color());
+        let (from, to) = (self.shift(from), self.shift(to));
+        self.inner.draw_line(from, to, &color)
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(&style.as_color());
+        let (upper_left, bottom_right) = (self.shift(upper_left), self.shift(bottom_right));
+        self.inner.draw_rect(upper_left, bottom_right, &color, fill)
+    }
+
+    fn draw_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(&style.as_color());
+        let (upper_left, bottom_right) = (self.shift(upper_left), self.shift(bottom_right));
+        self.inner
+            .draw_rounded_rect(upper_left, bottom_right, radius, &color, fill)
+    }
+
+    fn fill_top_rounded_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        radius: u32,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(&style.as_color());
+        let (upper_left, bottom_right) = (self.shift(upper_left), self.shift(bottom_right));
+        self.inner
+            .fill_top_rounded_rect(upper_left, bottom_right, radius, &color)
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(&style.as_color());
+        let path: Vec<_> = path.into_iter().map(|p| self.shift(p)).collect();
+        self.inner.draw_path(path, &color)
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(&style.as_color());
+        let vert: Vec<_> = vert.into_iter().map(|p| self.shift(p)).collect();
+        self.inner.fill_polygon(vert, &color)
+    }
+
+    fn fill_polygon_with_rule<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+        rule: FillRule,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(&style.as_color());
+        let vert: Vec<_> = vert.into_iter().map(|p| self.shift(p)).collect();
+        self.inner.fill_polygon_with_rule(vert, &color, rule)
+    }
+
+    fn fill_polygon_with_vertical_gradient<
+        S: BackendStyle,
+        I: IntoIterator<Item = BackendCoord>,
+    >(
+        &mut self,
+        vert: I,
+        style: &S,
+        (top, bottom): (i32, i32),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(&style.as_color());
+        let vert: Vec<_> = vert.into_iter().map(|p| self.shift(p)).collect();
+        let rows = (top + self.offset.1, bottom + self.offset.1);
+        self.inner
+            .fill_polygon_with_vertical_gradient(vert, &color, rows)
+    }
+
+    fn draw_arc<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        angles: (f64, f64),
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (center, color) = (self.shift(center), self.tint(&style.as_color()));
+        self.inner.draw_arc(center, radius, angles, &color)
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (center, color) = (self.shift(center), self.tint(&style.as_color()));
+        self.inner.draw_circle(center, radius, &color, fill)
+    }
+
+    fn draw_text<'a>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        pos: BackendCoord,
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (pos, color) = (self.shift(pos), self.tint(color));
+        self.inner.draw_text(text, font, pos, &color)
+    }
+
+    fn draw_text_subpixel<'a>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        (x, y): (f32, f32),
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(color);
+        let pos = (x + self.offset.0 as f32, y + self.offset.1 as f32);
+        self.inner.draw_text_subpixel(text, font, pos, &color)
+    }
+
+    fn draw_text_on_path<'a>(
+        &mut self,
+        text: &str,
+        font: &FontDesc<'a>,
+        path: &[BackendCoord],
+        color: &RGBAColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.tint(color);
+        let path: Vec<_> = path.iter().map(|p| self.shift(*p)).collect();
+        self.inner.draw_text_on_path(text, font, &path, &color)
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        (x, y): BackendCoord,
+        (w, h): (u32, u32),
+        _src: &[u8],
+        _src_size: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let color = self.color.clone();
+        let (x0, y0) = self.shift((x, y));
+        self.inner
+            .draw_rect((x0, y0), (x0 + w as i32, y0 + h as i32), &color, true)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_shadow_backend() {
+    use crate::drawing::MockedBackend;
+    use crate::style::{BLACK, RED};
+
+    let mut inner = MockedBackend::new(100, 100);
+    inner.check_draw_rect(|c, filled, u, d| {
+        assert!(filled);
+        assert_eq!(c.rgb(), (0, 0, 0));
+        assert!((c.alpha() - 0.25).abs() < 1e-6);
+        assert_eq!((u, d), ((13, 14), (23, 24)));
+    });
+    inner.ensure_prepared().unwrap();
+
+    let mut shadow = ShadowBackend {
+        inner: &mut inner,
+        offset: (3, 4),
+        color: BLACK.mix(0.5),
+    };
+    shadow
+        .draw_rect((10, 10), (20, 20), &RED.mix(0.5), true)
+        .unwrap();
+}
+
+#[cfg(test)]
+#[test]
+fn test_blurred_shadow() {
+    use crate::drawing::MockedBackend;
+    use crate::element::Rectangle;
+    use crate::style::{BLACK, RED};
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    backend.ensure_prepared().unwrap();
+
+    let rect = Rectangle::new([(10, 10), (20, 20)], RED.filled());
+    Shadow::new(rect, (3, 4), &BLACK.mix(0.9))
+        .blur(1)
+        .draw(vec![(10, 10), (20, 20)].into_iter(), &mut backend)
+        .unwrap();
+
+    // The mocked backend can't blur, so the shadow is made of the 3x3 copies around the offset
+    let rects = calls.rects();
+    assert_eq!(rects.len(), 10);
+    for (idx, (color, filled, u, d)) in rects[..9].iter().enumerate() {
+        let (dx, dy) = (idx as i32 % 3 - 1, idx as i32 / 3 - 1);
+        assert!(filled);
+        assert_eq!(color.rgb(), (0, 0, 0));
+        assert!((color.alpha() - 0.1).abs() < 1e-6);
+        assert_eq!((*u, *d), ((13 + dx, 14 + dy), (23 + dx, 24 + dy)));
+    }
+    assert_eq!(rects[9], (RED.to_rgba(), true, (10, 10), (20, 20)));
+}
@@ -0,0 +1,128 @@
+use super::{Drawable, PointCollection};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::style::ShapeStyle;
+
+/// A path whose width varies along the path, which is useful for showing a magnitude, such as
+/// a flow, in the thickness of the line. Each segment is drawn as a filled trapezoid whose
+/// width follows the widths of its two ends.
+pub struct TaperedPath<Coord> {
+    points: Vec<Coord>,
+    widths: Vec<f64>,
+    style: ShapeStyle,
+    round_joins: bool,
+}
+
+impl<Coord> TaperedPath<Coord> {
+    /// Create a new tapered path
+    /// - `points`: The points of the path, each of them comes with the width of the path in
+    ///   pixels at that point
+    /// - `style`: The style of the path
+    /// - returns the created element
+    pub fn new<I: IntoIterator<Item = (Coord, f64)>, S: Into<ShapeStyle>>(
+        points: I,
+        style: S,
+    ) -> Self {
+        let (points, widths) = points.into_iter().unzip();
+        Self {
+            points,
+            widths,
+            style: style.into(),
+            round_joins: true,
+        }
+    }
+
+    /// Set if the joins between segments are rounded, which makes the path smooth at the
+    /// corners. This is enabled by default.
+    /// - `value`: If the joins should be rounded
+    pub fn round_joins(mut self, value: bool) -> Self {
+        self.round_joins = value;
+        self
+    }
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a TaperedPath<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+/// Get the vertices of the trapezoid covering the segment from `a` to `b`
+fn segment_polygon(
+    a: BackendCoord,
+    b: BackendCoord,
+    wa: f64,
+    wb: f64,
+) -> Option<[BackendCoord; 4]> {
+    let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+    let len = (dx * dx + dy * dy).sqrt();
+    if len == 0.0 {
+        return None;
+    }
+    let (nx, ny) = (-dy / len / 2.0, dx / len / 2.0);
+    let offset = |p: BackendCoord, w: f64, sign: f64| {
+        (
+            p.0 + (sign * nx * w).round() as i32,
+            p.1 + (sign * ny * w).round() as i32,
+        )
+    };
+    Some([
+        offset(a, wa, 1.0),
+        offset(b, wb, 1.0),
+        offset(b, wb, -1.0),
+        offset(a, wa, -1.0),
+    ])
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for TaperedPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let color = &self.style.color;
+
+        for (idx, seg) in points.windows(2).enumerate() {
+            let (wa, wb) = (self.widths[idx].max(0.0), self.widths[idx + 1].max(0.0));
+            if let Some(vert) = segment_polygon(seg[0], seg[1], wa, wb) {
+                backend.fill_polygon(vert.iter().cloned(), color)?;
+            }
+        }
+
+        if self.round_joins && points.len() > 2 {
+            for (point, width) in points[1..points.len() - 1]
+                .iter()
+                .zip(self.widths[1..].iter())
+            {
+                let radius = (width.max(0.0) / 2.0).round() as u32;
+                if radius > 0 {
+                    backend.draw_circle(*point, radius, color, true)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_tapered_path_element() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_fill_polygon(|c, vert| {
+            assert_eq!(c, BLUE.to_rgba());
+            assert_eq!(vert, vec![(100, 105), (200, 110), (200, 90), (100, 95)]);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+        });
+    });
+    da.draw(&TaperedPath::new(
+        vec![((100, 100), 10.0), ((200, 100), 20.0)],
+        &BLUE,
+    ))
+    .expect("Drawing Failure");
+}
@@ -239,10 +239,63 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB>
         backend: &mut DB,
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         if let Some(a) = points.next() {
-            for (point, text) in self.layout_lines(a).zip(self.lines.iter()) {
+            for (idx, (point, text)) in self.layout_lines(a).zip(self.lines.iter()).enumerate() {
+                // Each line is a drawing call of its own, which needs the backend prepared
+                if idx > 0 {
+                    backend.ensure_prepared()?;
+                }
                 backend.draw_text(text.borrow(), &self.style.font, point, &self.style.color)?;
             }
         }
         Ok(())
     }
 }
+
+/// A text that follows a path, such as a label along a contour line. Each glyph is placed on
+/// the path and rotated to the local direction of the path, the glyphs that don't fit on the
+/// path are dropped.
+pub struct TextOnPath<'a, Coord, T: Borrow<str>> {
+    text: T,
+    path: Vec<Coord>,
+    style: TextStyle<'a>,
+}
+
+impl<'a, Coord, T: Borrow<str>> TextOnPath<'a, Coord, T> {
+    /// Create a new text element along a path
+    /// - `text`: The text for the element
+    /// - `path`: The points of the polyline the text follows
+    /// - `style`: The text style
+    pub fn new<P: Into<Vec<Coord>>, S: Into<TextStyle<'a>>>(text: T, path: P, style: S) -> Self {
+        Self {
+            text,
+            path: path.into(),
+            style: style.into(),
+        }
+    }
+}
+
+impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord>
+    for &'a TextOnPath<'b, Coord, T>
+{
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> Self::IntoIter {
+        &self.path
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for TextOnPath<'a, Coord, T> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let path: Vec<_> = points.collect();
+        backend.draw_text_on_path(
+            self.text.borrow(),
+            &self.style.font,
+            &path,
+            &self.style.color,
+        )
+    }
+}
@@ -498,6 +498,7 @@ use `default_features = false` in the dependency description in `Cargo.toml` and
 
 - `svg` Enable the `SVGBackend`
 - `bitmap` Enable the `BitMapBackend`
+- `pdf` Enable the `PDFBackend`, which is not enabled by default
 
 For example, the following dependency description would avoid compiling with bitmap support:
 
@@ -540,6 +541,9 @@ pub mod style;
 #[cfg(feature = "evcxr")]
 pub mod evcxr;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "testing"))]
+pub mod testing;
+
 #[cfg(test)]
 pub use crate::drawing::create_mocked_drawing_area;
 
@@ -548,11 +552,15 @@ pub use palette;
 
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        AxisDescAlignment, AxisScale, ChartBuilder, ChartContext, LabelAreaPosition, LabelOverflow,
+        MarkerLabelPosition, RenderLayer, SeriesClip, SeriesLabelPosition, Sparkline,
+    };
     pub use crate::coord::{
-        CoordTranslate, IntoCentric, IntoPartialAxis, LogCoord, LogRange, LogScalable, Ranged,
-        RangedCoord, RangedCoordf32, RangedCoordf64, RangedCoordi32, RangedCoordi64,
-        RangedCoordu32, RangedCoordu64,
+        AutoScaleRange, BrokenAxis, CoordTranslate, CyclicRange, IntoCentric, IntoKeyPointPolicy,
+        IntoPartialAxis, KeyPointPolicy, LogCoord, LogRange, LogScalable, Ranged, RangedCoord,
+        RangedCoordf32, RangedCoordf64, RangedCoordi32, RangedCoordi64, RangedCoordu32,
+        RangedCoordu64, ScaleKind,
     };
 
     #[cfg(feature = "chrono")]
@@ -561,17 +569,27 @@ pub mod prelude {
     #[cfg(feature = "make_partial_axis")]
     pub use crate::coord::make_partial_axis;
 
+    pub use crate::data::{AutoRange, NumberFormat};
     pub use crate::drawing::*;
-    pub use crate::series::{Histogram, LineSeries, PointSeries};
+    pub use crate::series::{
+        AreaBetweenSeries, AreaSeries, ColoredPointSeries, GroupedBarSeries, Histogram,
+        LineMarkerSeries, LineSeries, LinearRegression, MatrixSeries, MovingAverage,
+        MovingAverageEdge, NonFinitePolicy, PieSeries, PointSeries, StackedBarSeries, StripSeries,
+        ViolinScale, ViolinSeries, WrappedLineSeries,
+    };
     pub use crate::style::{
         Color, FontDesc, FontTransform, HSLColor, IntoFont, Palette, Palette100, Palette99,
-        Palette9999, PaletteColor, RGBColor, ShapeStyle, SimpleColor, TextStyle,
+        Palette9999, PaletteColor, PaletteOkabeIto, PaletteTableau10, RGBColor, ShapeStyle,
+        SimpleColor, TextStyle,
     };
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
 
     pub use crate::element::{
-        CandleStick, Circle, Cross, DynElement, EmptyElement, ErrorBar, IntoDynElement,
-        MultiLineText, Path, Pixel, Rectangle, Text,
+        Arc, Asterisk, Bracket, CandleStick, Circle, Cross, Decorated, Diamond, DynElement,
+        EdgeSnap, ElementGroup, EmptyElement, ErrorBar, HeatmapCell, IntoDynElement, LegendSample,
+        LegendShape, MultiLineText, Path, Pixel, Plus, Polygon, Rectangle, Shadow, Square,
+        TaperedPath, Text, TextOnPath, TriangleDown, TriangleLeft, TriangleRight, TriangleUp,
+        Wedge, XYErrorBar,
     };
 
     #[allow(type_alias_bounds)]
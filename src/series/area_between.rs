@@ -0,0 +1,169 @@
+use crate::element::Polygon;
+use crate::style::ShapeStyle;
+
+/// The series that fills the area between two line series sampled on the same X grid, e.g. to
+/// highlight where one curve exceeds another:
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0.0..10.0, -1.5..1.5)?;
+/// let a: Vec<_> = (0..=100).map(|x| (x as f64 / 10.0, (x as f64 / 10.0).sin())).collect();
+/// let b: Vec<_> = (0..=100).map(|x| (x as f64 / 10.0, (x as f64 / 10.0).cos())).collect();
+/// chart.draw_series(AreaBetweenSeries::two_colored(
+///     a.iter().cloned(),
+///     b.iter().cloned(),
+///     GREEN.mix(0.3).filled(),
+///     RED.mix(0.3).filled(),
+/// ))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AreaBetweenSeries {
+    polygons: std::vec::IntoIter<Polygon<(f64, f64)>>,
+}
+
+/// Make the polygon between the two lines of a run of the samples `(x, y_a, y_b)`
+fn make_polygon(run: &[(f64, f64, f64)], style: ShapeStyle) -> Polygon<(f64, f64)> {
+    let points: Vec<_> = run
+        .iter()
+        .map(|&(x, a, _)| (x, a))
+        .chain(run.iter().rev().map(|&(x, _, b)| (x, b)))
+        .collect();
+    Polygon::new(points, style)
+}
+
+/// Split the samples `(x, y_a, y_b)` into the runs where line A is on the same side of line B,
+/// the runs are split at the crossing points of the lines
+/// - **returns**: The runs with the flag indicating if line A is above line B
+#[allow(clippy::type_complexity)]
+fn split_at_crossings(samples: &[(f64, f64, f64)]) -> Vec<(bool, Vec<(f64, f64, f64)>)> {
+    let mut runs = vec![];
+    let mut current: Vec<(f64, f64, f64)> = vec![];
+    let mut above = None;
+
+    for &(x, a, b) in samples {
+        let diff = a - b;
+        if let Some(&(px, pa, pb)) = current.last() {
+            let prev_diff = pa - pb;
+            if prev_diff * diff < 0.0 {
+                // The lines cross between the samples, both runs end at the crossing point
+                let t = prev_diff / (prev_diff - diff);
+                let crossing = (px + t * (x - px), pa + t * (a - pa), pa + t * (a - pa));
+                current.push(crossing);
+                runs.push((above.unwrap_or(true), current));
+                current = vec![crossing];
+                above = None;
+            } else if diff != 0.0 && above.map_or(false, |above| above != (diff > 0.0)) {
+                // The lines touch at the previous sample and then change the side
+                let touch = (px, pa, pb);
+                runs.push((above.unwrap_or(true), current));
+                current = vec![touch];
+                above = None;
+            }
+        }
+        if diff != 0.0 && above.is_none() {
+            above = Some(diff > 0.0);
+        }
+        current.push((x, a, b));
+    }
+
+    if current.len() > 1 {
+        runs.push((above.unwrap_or(true), current));
+    }
+    runs
+}
+
+impl AreaBetweenSeries {
+    /// Fill the area between two lines with a single style
+    /// - `a`: The points of the first line
+    /// - `b`: The points of the second line, which has the same X values as the first line
+    /// - `style`: The style of the area, which should be filled
+    pub fn new<A, B, S>(a: A, b: B, style: S) -> Self
+    where
+        A: IntoIterator<Item = (f64, f64)>,
+        B: IntoIterator<Item = (f64, f64)>,
+        S: Into<ShapeStyle>,
+    {
+        let samples: Vec<_> = a
+            .into_iter()
+            .zip(b)
+            .map(|((x, ya), (_, yb))| (x, ya, yb))
+            .collect();
+        let polygons = if samples.len() > 1 {
+            vec![make_polygon(&samples, style.into())]
+        } else {
+            vec![]
+        };
+        Self {
+            polygons: polygons.into_iter(),
+        }
+    }
+
+    /// Fill the area between two lines with two styles, one where the first line is above the
+    /// second line, the other where it's below. The area is split at the crossing points of the
+    /// lines, so the colors change exactly where the lines cross.
+    /// - `a`: The points of the first line
+    /// - `b`: The points of the second line, which has the same X values as the first line
+    /// - `above`: The style of the area where the first line is above the second line
+    /// - `below`: The style of the area where the first line is below the second line
+    pub fn two_colored<A, B, S1, S2>(a: A, b: B, above: S1, below: S2) -> Self
+    where
+        A: IntoIterator<Item = (f64, f64)>,
+        B: IntoIterator<Item = (f64, f64)>,
+        S1: Into<ShapeStyle>,
+        S2: Into<ShapeStyle>,
+    {
+        let (above, below) = (above.into(), below.into());
+        let samples: Vec<_> = a
+            .into_iter()
+            .zip(b)
+            .map(|((x, ya), (_, yb))| (x, ya, yb))
+            .collect();
+        let polygons: Vec<_> = split_at_crossings(&samples)
+            .into_iter()
+            .map(|(is_above, run)| {
+                let style = if is_above { &above } else { &below };
+                make_polygon(&run, style.clone())
+            })
+            .collect();
+        Self {
+            polygons: polygons.into_iter(),
+        }
+    }
+}
+
+impl Iterator for AreaBetweenSeries {
+    type Item = Polygon<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.polygons.next()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_area_between_crossings() {
+    let samples = vec![(0.0, 0.0, 1.0), (1.0, 2.0, 1.0), (2.0, 0.0, 1.0)];
+    let runs = split_at_crossings(&samples);
+    let sides: Vec<_> = runs.iter().map(|(above, _)| *above).collect();
+    assert_eq!(sides, vec![false, true, false]);
+    assert_eq!(
+        runs[1].1,
+        vec![(0.5, 1.0, 1.0), (1.0, 2.0, 1.0), (1.5, 1.0, 1.0)]
+    );
+
+    // The lines touching at a sample split the area at the sample
+    let samples = vec![(0.0, 1.0, 0.0), (1.0, 0.0, 0.0), (2.0, -1.0, 0.0)];
+    let runs = split_at_crossings(&samples);
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[1], (false, vec![(1.0, 0.0, 0.0), (2.0, -1.0, 0.0)]));
+
+    let series = AreaBetweenSeries::new(
+        vec![(0.0, 0.0), (1.0, 2.0)],
+        vec![(0.0, 1.0), (1.0, 1.0)],
+        &crate::style::RED,
+    );
+    assert_eq!(series.count(), 1);
+}
@@ -0,0 +1,121 @@
+use crate::element::{PointCollection, Polygon};
+use crate::style::ShapeStyle;
+
+/// The series that fills the area between a line and a horizontal baseline, which is drawn as
+/// a single polygon. The fill can fade out from the line to the baseline:
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0.0..10.0, 0.0..100.0)?;
+/// let data: Vec<_> = (0..=10).map(|x| (x as f64, (x * x) as f64)).collect();
+/// chart.draw_series(AreaSeries::new(data.iter().cloned(), 0.0, BLUE.mix(0.6).filled()).fade())?;
+/// chart.draw_series(LineSeries::new(data.iter().cloned(), &BLUE))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AreaSeries<X, Y> {
+    area: Option<Polygon<(X, Y)>>,
+}
+
+impl<X: Clone, Y: Clone> AreaSeries<X, Y> {
+    /// Create a new area series
+    /// - `points`: The points of the line, which are sorted by the X value
+    /// - `baseline`: The Y value of the baseline
+    /// - `style`: The style of the area, which should be filled
+    pub fn new<I: IntoIterator<Item = (X, Y)>, S: Into<ShapeStyle>>(
+        points: I,
+        baseline: Y,
+        style: S,
+    ) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        let area = match (points.first(), points.last()) {
+            (Some((first, _)), Some((last, _))) if points.len() > 1 => {
+                let (first, last) = (first.clone(), last.clone());
+                let mut vert = points;
+                vert.push((last, baseline.clone()));
+                vert.push((first, baseline));
+                Some(Polygon::new(vert, style))
+            }
+            _ => None,
+        };
+        Self { area }
+    }
+
+    /// Make the fill fade out from the alpha of the style at the point of the line farthest from
+    /// the baseline to transparent at the baseline. The values below the baseline fade out
+    /// toward the baseline at the same rate. The bitmap backend interpolates the alpha row by
+    /// row and the SVG backend uses a linear gradient
+    pub fn fade(mut self) -> Self {
+        if let Some(area) = self.area.as_mut() {
+            // The last vertex is on the baseline
+            let baseline = (&*area).point_iter().len() - 1;
+            area.set_fade_toward(baseline);
+        }
+        self
+    }
+}
+
+impl<X, Y> Iterator for AreaSeries<X, Y> {
+    type Item = Polygon<(X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.area.take()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_area_series_fade() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let recorded = calls.clone();
+    backend.drop_check(move |b| {
+        let polygons = recorded.polygons();
+        let (color, vert) = &polygons[0];
+        assert_eq!(*color, RED.to_rgba());
+        assert_eq!(vert.len(), 4);
+        // The gradient starts at the highest point of the line and ends at the baseline
+        let top = vert.iter().map(|p| p.1).min().unwrap();
+        assert_eq!(b.last_gradient_rows, Some((top, vert[2].1)));
+        assert!(top < vert[0].1);
+    });
+    let da = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&da)
+        .build_ranged(0.0..10.0, 0.0..10.0)
+        .unwrap();
+    chart
+        .draw_series(AreaSeries::new(vec![(0.0, 5.0), (10.0, 10.0)], 0.0, RED.filled()).fade())
+        .unwrap();
+    assert_eq!(calls.polygons().len(), 1);
+
+    assert_eq!(AreaSeries::new(vec![(0.0, 1.0)], 0.0, &RED).count(), 0);
+}
+
+#[cfg(test)]
+#[test]
+fn test_area_series_fade_below_baseline() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let recorded = calls.clone();
+    backend.drop_check(move |b| {
+        let (_, vert) = &recorded.polygons()[0];
+        // The gradient runs from the lowest point of the line, which is the farthest from the
+        // baseline, up to the baseline
+        let bottom = vert.iter().map(|p| p.1).max().unwrap();
+        assert_eq!(b.last_gradient_rows, Some((bottom, vert[3].1)));
+        assert!(bottom > vert[3].1);
+    });
+    let da = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&da)
+        .build_ranged(0.0..10.0, -10.0..10.0)
+        .unwrap();
+    chart
+        .draw_series(AreaSeries::new(vec![(0.0, 2.0), (10.0, -8.0)], 0.0, RED.filled()).fade())
+        .unwrap();
+}
@@ -0,0 +1,224 @@
+use std::marker::PhantomData;
+
+use crate::chart::ChartContext;
+use crate::coord::{DescreteRanged, Ranged, RangedCoord};
+use crate::drawing::DrawingBackend;
+use crate::element::{EdgeSnap, Rectangle};
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+
+/// The error caused by the values of a grouped or a stacked bar series which don't match the
+/// categories
+#[derive(Debug)]
+pub enum BarSeriesError {
+    /// The number of values differs from the number of categories
+    LengthMismatch {
+        /// The index of the bad series
+        series: usize,
+        /// The number of categories
+        expected: usize,
+        /// The number of values in the series
+        actual: usize,
+    },
+    /// The number of values of an overlaid line differs from the number of categories
+    LineLengthMismatch {
+        /// The index of the bad line
+        line: usize,
+        /// The number of categories
+        expected: usize,
+        /// The number of values of the line
+        actual: usize,
+    },
+}
+
+impl std::fmt::Display for BarSeriesError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match self {
+            BarSeriesError::LengthMismatch {
+                series,
+                expected,
+                actual,
+            } => write!(
+                fmt,
+                "Series #{} has {} values, but there are {} categories",
+                series, actual, expected
+            ),
+            BarSeriesError::LineLengthMismatch {
+                line,
+                expected,
+                actual,
+            } => write!(
+                fmt,
+                "Line #{} has {} values, but there are {} categories",
+                line, actual, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BarSeriesError {}
+
+/// The grouped (clustered) bar series, which puts the bars of several value series side by side
+/// within each category. Each of the value series is drawn with `GroupedBarSeries::bars`, so
+/// that it can have its own legend entry:
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0..3, 0..10)?;
+/// let names = ["2018", "2019"];
+/// let mut groups = GroupedBarSeries::vertical(&chart, 0..3);
+/// groups
+///     .add_series(vec![3, 5, 7], &RED)?
+///     .add_series(vec![4, 6, 9], &BLUE)?;
+/// for idx in 0..groups.series_count() {
+///     chart.draw_series(groups.bars(idx))?.label(names[idx]);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct GroupedBarSeries<BR, A>
+where
+    BR: DescreteRanged,
+    BR::ValueType: Eq + Clone,
+    A: Clone + Default,
+{
+    categories: Vec<BR::ValueType>,
+    series: Vec<(Vec<A>, ShapeStyle)>,
+    group_width: f64,
+    gap: f64,
+    _p: PhantomData<BR>,
+}
+
+impl<BR, A> GroupedBarSeries<BR, A>
+where
+    BR: DescreteRanged,
+    BR::ValueType: Eq + Clone,
+    A: Clone + Default,
+{
+    /// Create a new grouped bar series without any value series
+    /// - `categories`: The categories, each of them is a group of bars
+    pub fn new<I: IntoIterator<Item = BR::ValueType>>(categories: I) -> Self {
+        Self {
+            categories: categories.into_iter().collect(),
+            series: vec![],
+            group_width: 0.8,
+            gap: 0.0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Create a new grouped bar series for the chart, which uses the categories as the X axis
+    /// - `categories`: The categories, each of them is a group of bars
+    pub fn vertical<ACoord, DB: DrawingBackend, I: IntoIterator<Item = BR::ValueType>>(
+        _: &ChartContext<DB, RangedCoord<BR, ACoord>>,
+        categories: I,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = A>,
+    {
+        Self::new(categories)
+    }
+
+    /// Set the width of the group as a fraction of the category band, the group is centered in
+    /// the band
+    /// - `fraction`: The fraction of the band occupied by the group, 0.8 by default
+    pub fn group_width(mut self, fraction: f64) -> Self {
+        self.group_width = fraction.max(0.0).min(1.0);
+        self
+    }
+
+    /// Set the gap between the bars within a group
+    /// - `fraction`: The gap as a fraction of the category band, 0.0 by default
+    pub fn gap(mut self, fraction: f64) -> Self {
+        self.gap = fraction.max(0.0);
+        self
+    }
+
+    /// Add a value series to the group
+    /// - `values`: The values, one for each category in the same order
+    /// - `style`: The style of the bars of this series
+    /// - **returns**: An error if the number of values doesn't match the number of categories
+    pub fn add_series<S: Into<ShapeStyle>, I: IntoIterator<Item = A>>(
+        &mut self,
+        values: I,
+        style: S,
+    ) -> Result<&mut Self, BarSeriesError> {
+        let values: Vec<_> = values.into_iter().collect();
+        if values.len() != self.categories.len() {
+            return Err(BarSeriesError::LengthMismatch {
+                series: self.series.len(),
+                expected: self.categories.len(),
+                actual: values.len(),
+            });
+        }
+        self.series.push((values, style.into()));
+        Ok(self)
+    }
+
+    /// Get the number of value series
+    pub fn series_count(&self) -> usize {
+        self.series.len()
+    }
+
+    /// Get the style of a value series, which is useful for the legend
+    /// - `idx`: The index of the series
+    pub fn style(&self, idx: usize) -> ShapeStyle {
+        self.series
+            .get(idx)
+            .map(|(_, style)| style.clone())
+            .unwrap_or_else(|| Palette99::pick(idx).filled())
+    }
+
+    /// Get the relative horizontal span of the bars of a series within the category band
+    fn bar_span(&self, idx: usize) -> (f64, f64) {
+        let count = self.series.len().max(1) as f64;
+        let start = (1.0 - self.group_width) / 2.0;
+        let slot = self.group_width / count;
+        let half_gap = (self.gap / 2.0).min(slot / 2.0);
+        (
+            start + slot * idx as f64 + half_gap,
+            start + slot * (idx + 1) as f64 - half_gap,
+        )
+    }
+
+    /// Get the bars of a value series
+    /// - `idx`: The index of the series
+    /// - **returns**: The series of bars, which can be drawn with `ChartContext::draw_series`
+    pub fn bars(&self, idx: usize) -> impl Iterator<Item = Rectangle<(BR::ValueType, A)>> + '_ {
+        let span = self.bar_span(idx);
+        let style = self.style(idx);
+        let values = self.series.get(idx).map_or(&[][..], |(v, _)| &v[..]);
+        self.categories
+            .iter()
+            .zip(values.iter())
+            .map(move |(x, y)| {
+                let mut rect = Rectangle::new(
+                    [(x.clone(), y.clone()), (BR::next_value(x), A::default())],
+                    style.clone(),
+                );
+                rect.set_relative_span(span, (0.0, 1.0));
+                rect.set_edge_snap(EdgeSnap::Exclusive);
+                rect
+            })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_grouped_bar_series() {
+    use crate::prelude::*;
+    let mut groups = GroupedBarSeries::<RangedCoordi32, i32>::new(0..3).group_width(0.5);
+    groups
+        .add_series(vec![1, 2, 3], &RED)
+        .expect("Bad series")
+        .add_series(vec![3, 2, 1], &BLUE)
+        .expect("Bad series");
+    assert!(groups.add_series(vec![1, 2], &GREEN).is_err());
+
+    assert_eq!(groups.series_count(), 2);
+    assert_eq!(groups.bar_span(0), (0.25, 0.5));
+    assert_eq!(groups.bar_span(1), (0.5, 0.75));
+    assert_eq!(groups.bars(1).count(), 3);
+}
@@ -6,7 +6,7 @@ use std::ops::AddAssign;
 use crate::chart::ChartContext;
 use crate::coord::{DescreteRanged, Ranged, RangedCoord};
 use crate::drawing::DrawingBackend;
-use crate::element::Rectangle;
+use crate::element::{EdgeSnap, Rectangle};
 use crate::style::{Color, ShapeStyle, GREEN};
 
 pub trait HistogramType {}
@@ -26,6 +26,8 @@ where
 {
     style: ShapeStyle,
     margin: u32,
+    bar_width: f64,
+    edge_snap: EdgeSnap,
     iter: HashMapIter<BR::ValueType, A>,
     baseline: Box<dyn Fn() -> A>,
     _p: PhantomData<(BR, Tag)>,
@@ -42,6 +44,8 @@ where
         Self {
             style: GREEN.filled(),
             margin: 5,
+            bar_width: 1.0,
+            edge_snap: EdgeSnap::Exclusive,
             iter: HashMap::new().into_iter(),
             baseline: Box::new(|| A::default()),
             _p: PhantomData,
@@ -68,6 +72,24 @@ where
         self
     }
 
+    /// Set the width of each bar as a fraction of its band, the bar is centered in the band.
+    /// This is applied before the margin, so the gap can be specified either as a fraction, in
+    /// pixels, or both.
+    /// - `fraction`: The fraction of the band occupied by the bar, e.g. 0.8
+    pub fn bar_width(mut self, fraction: f64) -> Self {
+        self.bar_width = fraction;
+        self
+    }
+
+    /// Set how the edges of the bars are snapped to the pixels. The default is
+    /// `EdgeSnap::Exclusive`, which keeps adjacent bars without margin from overlapping at their
+    /// shared edges, `EdgeSnap::Inclusive` paints both edges of every bar
+    /// - `snap`: The snapping of the bar edges
+    pub fn edge_snap(mut self, snap: EdgeSnap) -> Self {
+        self.edge_snap = snap;
+        self
+    }
+
     /// Set the data iterator
     pub fn data<I: IntoIterator<Item = (BR::ValueType, A)>>(mut self, iter: I) -> Self {
         let mut buffer = HashMap::<BR::ValueType, A>::new();
@@ -104,6 +126,8 @@ where
         Self {
             style: style.into(),
             margin,
+            bar_width: 1.0,
+            edge_snap: EdgeSnap::Exclusive,
             iter: buffer.into_iter(),
             baseline: Box::new(|| A::default()),
             _p: PhantomData,
@@ -148,6 +172,8 @@ where
             let nx = BR::next_value(&x);
             let mut rect = Rectangle::new([(x, y), (nx, (self.baseline)())], self.style.clone());
             rect.set_margin(0, 0, self.margin, self.margin);
+            rect.set_size_fraction(self.bar_width, 1.0);
+            rect.set_edge_snap(self.edge_snap);
             return Some(rect);
         }
         None
@@ -166,8 +192,34 @@ where
             let ny = BR::next_value(&y);
             let mut rect = Rectangle::new([(x, y), ((self.baseline)(), ny)], self.style.clone());
             rect.set_margin(self.margin, self.margin, 0, 0);
+            rect.set_size_fraction(1.0, self.bar_width);
+            rect.set_edge_snap(self.edge_snap);
             return Some(rect);
         }
         None
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_histogram_bars_tile() {
+    use crate::prelude::*;
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let da = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&da)
+        .build_ranged(0u32..7u32, 0u32..10u32)
+        .expect("Build chart error");
+    let hist = Histogram::vertical(&chart)
+        .margin(0)
+        .data((0..7).map(|x| (x, 5)));
+    chart.draw_series(hist).expect("Drawing Failure");
+
+    // The bars are snapped exclusively by default, so the adjacent bars don't overlap
+    let mut rects = calls.rects();
+    rects.sort_by_key(|(_, _, u, _)| u.0);
+    assert_eq!(rects.len(), 7);
+    for pair in rects.windows(2) {
+        assert_eq!(pair[0].3 .0 + 1, pair[1].2 .0);
+    }
+}
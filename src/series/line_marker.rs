@@ -0,0 +1,222 @@
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Circle, Drawable, PointCollection, PointElement};
+use crate::style::ShapeStyle;
+
+/// The line with markers on a subset of its points, which is the element of
+/// `LineMarkerSeries`. The line and the markers are drawn as a single element, so they share
+/// one legend entry.
+pub struct LineWithMarkers<Coord, E> {
+    points: Vec<Coord>,
+    markers: Vec<usize>,
+    style: ShapeStyle,
+    marker_style: ShapeStyle,
+    marker_size: u32,
+    phantom: PhantomData<E>,
+}
+
+impl<'a, Coord, E> PointCollection<'a, Coord> for &'a LineWithMarkers<Coord, E> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, E, DB: DrawingBackend> Drawable<DB> for LineWithMarkers<Coord, E>
+where
+    E: PointElement<BackendCoord> + Drawable<DB>,
+    for<'b> &'b E: PointCollection<'b, BackendCoord>,
+{
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        backend.draw_path(points.iter().cloned(), &self.style.color)?;
+        for idx in self.markers.iter() {
+            if let Some(pos) = points.get(*idx) {
+                backend.ensure_prepared()?;
+                let marker = E::make_point(*pos, self.marker_size, self.marker_style.clone());
+                marker.draw(
+                    marker.point_iter().into_iter().map(|p| *p.borrow()),
+                    backend,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The line series which is drawn through all the data points, but only has markers on a
+/// sparse subset of them, e.g. a densely sampled function with a marker on every 10th point.
+/// The markers are circles by default, use `LineMarkerSeries::marker` to change the shape.
+pub struct LineMarkerSeries<Coord, E> {
+    line: Option<LineWithMarkers<Coord, E>>,
+}
+
+impl<Coord> LineMarkerSeries<Coord, Circle<BackendCoord>> {
+    /// Create a new line series with a marker on every `stride`-th point, starting from the
+    /// first point
+    /// - `iter`: The iterator of the points of the line
+    /// - `style`: The style of the line, which is also the style of the markers by default
+    /// - `stride`: The number of points between two markers, 0 means no marker
+    pub fn new<I: IntoIterator<Item = Coord>, S: Into<ShapeStyle>>(
+        iter: I,
+        style: S,
+        stride: usize,
+    ) -> Self {
+        let points: Vec<_> = iter.into_iter().collect();
+        let style = style.into();
+        let markers = if stride == 0 {
+            vec![]
+        } else {
+            (0..points.len()).step_by(stride).collect()
+        };
+        Self {
+            line: Some(LineWithMarkers {
+                points,
+                markers,
+                marker_style: style.clone(),
+                style,
+                marker_size: 3,
+                phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+impl<Coord, E> LineMarkerSeries<Coord, E> {
+    /// Put the markers on the given points instead of every `stride`-th point
+    /// - `indices`: The indices of the points which have a marker
+    pub fn markers_at<I: IntoIterator<Item = usize>>(mut self, indices: I) -> Self {
+        if let Some(line) = self.line.as_mut() {
+            line.markers = indices.into_iter().collect();
+        }
+        self
+    }
+
+    /// Set the style of the markers
+    /// - `style`: The style of the markers
+    pub fn marker_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        if let Some(line) = self.line.as_mut() {
+            line.marker_style = style.into();
+        }
+        self
+    }
+
+    /// Set the size of the markers
+    /// - `size`: The size of the markers, 3 by default
+    pub fn marker_size(mut self, size: u32) -> Self {
+        if let Some(line) = self.line.as_mut() {
+            line.marker_size = size;
+        }
+        self
+    }
+
+    /// Change the shape of the markers, e.g. `.marker::<Cross<_>>()`
+    pub fn marker<F: PointElement<BackendCoord>>(self) -> LineMarkerSeries<Coord, F> {
+        LineMarkerSeries {
+            line: self.line.map(|line| LineWithMarkers {
+                points: line.points,
+                markers: line.markers,
+                style: line.style,
+                marker_style: line.marker_style,
+                marker_size: line.marker_size,
+                phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+impl<Coord, E> Iterator for LineMarkerSeries<Coord, E> {
+    type Item = LineWithMarkers<Coord, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.take()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_line_marker_series() {
+    use crate::prelude::*;
+    let data: Vec<_> = (0..10).map(|x| (x * 10, x * 10)).collect();
+
+    let line = LineMarkerSeries::new(data.clone(), &RED, 4).next().unwrap();
+    assert_eq!(line.markers, vec![0, 4, 8]);
+    let line = LineMarkerSeries::new(data.clone(), &RED, 4)
+        .markers_at(vec![1, 2])
+        .marker::<Cross<_>>()
+        .next()
+        .unwrap();
+    assert_eq!(line.markers, vec![1, 2]);
+
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_path(|c, path| {
+            assert_eq!(c, RED.to_rgba());
+            assert_eq!(path.len(), 10);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.num_draw_circle_call, 0);
+        });
+    });
+    for element in LineMarkerSeries::new(data, &RED, 0) {
+        da.draw(&element).expect("Drawing Failure");
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_line_marker_series_draw() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let drawing_area = backend.into_drawing_area();
+    let mut chart = ChartBuilder::on(&drawing_area)
+        .build_ranged(0..100, 0..100)
+        .expect("Build chart error");
+    let data: Vec<_> = (0..10).map(|x| (x * 10, x * 10)).collect();
+    chart
+        .draw_series(LineMarkerSeries::new(data.clone(), &RED, 4).marker_style(BLUE.filled()))
+        .expect("Drawing Failure")
+        .label("line");
+    assert_eq!(chart.drawn_element_count(), 1);
+
+    // The line goes through all the points, the markers are only on every 4th point
+    let paths = calls.paths();
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].0, RED.to_rgba());
+    let expected: Vec<_> = data.iter().map(|p| chart.backend_coord(p)).collect();
+    assert_eq!(paths[0].1, expected);
+    let markers: Vec<_> = [0, 4, 8]
+        .iter()
+        .map(|idx| (BLUE.to_rgba(), true, expected[*idx], 3))
+        .collect();
+    assert_eq!(calls.circles(), markers);
+
+    // The markers can be any point element, the indices out of the line are skipped
+    calls.clear();
+    chart
+        .draw_series(
+            LineMarkerSeries::new(data, &RED, 4)
+                .markers_at(vec![2, 20])
+                .marker_size(5)
+                .marker::<Cross<_>>(),
+        )
+        .expect("Drawing Failure");
+    let (cx, cy) = expected[2];
+    let crosses = calls.lines();
+    assert_eq!(crosses.len(), 2);
+    for (color, from, to) in crosses {
+        assert_eq!(color, RED.to_rgba());
+        for (x, y) in [from, to].iter() {
+            assert_eq!(((x - cx).abs(), (y - cy).abs()), (5, 5));
+        }
+    }
+    assert!(calls.circles().is_empty());
+}
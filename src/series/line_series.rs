@@ -1,11 +1,91 @@
+use crate::coord::CyclicRange;
 use crate::element::Path;
 use crate::style::ShapeStyle;
 
+/// The policy of the line series for the points with a non-finite (NaN or infinite) component
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NonFinitePolicy {
+    /// Break the line at the non-finite points, so the missing intervals are left blank
+    Break,
+    /// Skip the non-finite points and connect the points around them
+    Skip,
+    /// Report an error if there's any non-finite point
+    Error,
+}
+
+impl Default for NonFinitePolicy {
+    fn default() -> Self {
+        NonFinitePolicy::Break
+    }
+}
+
+/// The error reported by `LineSeries::with_policy` with `NonFinitePolicy::Error`
+#[derive(Debug)]
+pub struct NonFiniteError {
+    /// The index of the first non-finite point
+    pub index: usize,
+}
+
+impl std::fmt::Display for NonFiniteError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        write!(fmt, "The point at index {} is not finite", self.index)
+    }
+}
+
+impl std::error::Error for NonFiniteError {}
+
+/// The trait for the values that may be non-finite
+pub trait FiniteValue {
+    /// Check if the value is finite
+    fn is_finite_value(&self) -> bool;
+}
+
+macro_rules! impl_finite_value {
+    (float: $($t:ty),*) => {
+        $(impl FiniteValue for $t {
+            fn is_finite_value(&self) -> bool {
+                self.is_finite()
+            }
+        })*
+    };
+    (always: $($t:ty),*) => {
+        $(impl FiniteValue for $t {
+            fn is_finite_value(&self) -> bool {
+                true
+            }
+        })*
+    };
+}
+
+impl_finite_value!(float: f32, f64);
+impl_finite_value!(always: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "chrono")]
+impl<Z: chrono::TimeZone> FiniteValue for chrono::Date<Z> {
+    fn is_finite_value(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Z: chrono::TimeZone> FiniteValue for chrono::DateTime<Z> {
+    fn is_finite_value(&self) -> bool {
+        true
+    }
+}
+
+impl<X: FiniteValue, Y: FiniteValue> FiniteValue for (X, Y) {
+    fn is_finite_value(&self) -> bool {
+        self.0.is_finite_value() && self.1.is_finite_value()
+    }
+}
+
 /// The line series object, which takes an iterator of points in guest coordinate system
 /// and creates the element rendering the line plot
 pub struct LineSeries<Coord, I: IntoIterator<Item = Coord>> {
     style: ShapeStyle,
     data_iter: Option<I::IntoIter>,
+    parts: std::vec::IntoIter<Vec<Coord>>,
 }
 
 impl<Coord, I: IntoIterator<Item = Coord>> Iterator for LineSeries<Coord, I> {
@@ -19,7 +99,8 @@ impl<Coord, I: IntoIterator<Item = Coord>> Iterator for LineSeries<Coord, I> {
                 self.style.clone(),
             ))
         } else {
-            None
+            let part = self.parts.next()?;
+            Some(Path::new(part, self.style.clone()))
         }
     }
 }
@@ -29,6 +110,127 @@ impl<Coord, I: IntoIterator<Item = Coord>> LineSeries<Coord, I> {
         Self {
             style: style.into(),
             data_iter: Some(iter.into_iter()),
+            parts: vec![].into_iter(),
+        }
+    }
+
+    /// Create a new line series with the policy for the non-finite points
+    /// - `iter`: The iterator of the points
+    /// - `style`: The style of the line
+    /// - `policy`: How the points with a NaN or infinite component are handled
+    /// - **returns**: The line series, or the error if the policy is `NonFinitePolicy::Error`
+    ///   and there's a non-finite point
+    pub fn with_policy<S: Into<ShapeStyle>>(
+        iter: I,
+        style: S,
+        policy: NonFinitePolicy,
+    ) -> Result<Self, NonFiniteError>
+    where
+        Coord: FiniteValue,
+    {
+        let mut parts = vec![];
+        let mut current = vec![];
+
+        for (index, point) in iter.into_iter().enumerate() {
+            if point.is_finite_value() {
+                current.push(point);
+                continue;
+            }
+            match policy {
+                NonFinitePolicy::Break => {
+                    if !current.is_empty() {
+                        parts.push(std::mem::take(&mut current));
+                    }
+                }
+                NonFinitePolicy::Skip => {}
+                NonFinitePolicy::Error => return Err(NonFiniteError { index }),
+            }
+        }
+
+        if !current.is_empty() {
+            parts.push(current);
+        }
+
+        Ok(Self {
+            style: style.into(),
+            data_iter: None,
+            parts: parts.into_iter(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_line_series_non_finite_policy() {
+    use crate::element::PointCollection;
+    use crate::style::RED;
+    let data = vec![(0.0, 1.0), (1.0, std::f64::NAN), (2.0, 3.0), (3.0, 4.0)];
+
+    let parts: Vec<_> = LineSeries::with_policy(data.clone(), &RED, NonFinitePolicy::Break)
+        .unwrap()
+        .map(|path| path.point_iter().len())
+        .collect();
+    assert_eq!(parts, vec![1, 2]);
+
+    let parts: Vec<_> = LineSeries::with_policy(data.clone(), &RED, NonFinitePolicy::Skip)
+        .unwrap()
+        .map(|path| path.point_iter().len())
+        .collect();
+    assert_eq!(parts, vec![3]);
+
+    let error = LineSeries::with_policy(data, &RED, NonFinitePolicy::Error).err();
+    assert_eq!(error.map(|e| e.index), Some(1));
+}
+
+/// The line series on a cyclic axis, such as the wind direction over time. The line is split
+/// where it crosses the wrap boundary of the axis, so that no spurious segment is drawn across
+/// the whole plot, and both parts of the line are extended to the boundary.
+pub struct WrappedLineSeries {
+    style: ShapeStyle,
+    parts: std::vec::IntoIter<Vec<(f64, f64)>>,
+}
+
+impl WrappedLineSeries {
+    /// Create a new line series whose X axis is cyclic
+    /// - `iter`: The iterator of the points
+    /// - `axis`: The cyclic X axis
+    /// - `style`: The style of the line
+    pub fn on_x<I: IntoIterator<Item = (f64, f64)>, S: Into<ShapeStyle>>(
+        iter: I,
+        axis: &CyclicRange,
+        style: S,
+    ) -> Self {
+        Self {
+            style: style.into(),
+            parts: axis.split_path(iter).into_iter(),
+        }
+    }
+
+    /// Create a new line series whose Y axis is cyclic
+    /// - `iter`: The iterator of the points
+    /// - `axis`: The cyclic Y axis
+    /// - `style`: The style of the line
+    pub fn on_y<I: IntoIterator<Item = (f64, f64)>, S: Into<ShapeStyle>>(
+        iter: I,
+        axis: &CyclicRange,
+        style: S,
+    ) -> Self {
+        let parts = axis
+            .split_path(iter.into_iter().map(|(x, y)| (y, x)))
+            .into_iter()
+            .map(|part| part.into_iter().map(|(y, x)| (x, y)).collect())
+            .collect::<Vec<_>>();
+        Self {
+            style: style.into(),
+            parts: parts.into_iter(),
         }
     }
 }
+
+impl Iterator for WrappedLineSeries {
+    type Item = Path<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let part = self.parts.next()?;
+        Some(Path::new(part, self.style.clone()))
+    }
+}
@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use crate::chart::ChartContext;
+use crate::coord::{DescreteRanged, RangedCoord};
+use crate::drawing::DrawingBackend;
+use crate::element::HeatmapCell;
+use crate::style::{Color, RGBColor, ShapeStyle, TextStyle};
+
+/// The matrix series, which draws an annotated heatmap (such as a confusion matrix) on two
+/// categorical axes. Each value becomes a cell filling the band of its column and row, the
+/// color of the cell is decided by the value.
+pub struct MatrixSeries<'a, XR, YR>
+where
+    XR: DescreteRanged,
+    YR: DescreteRanged,
+    XR::ValueType: Eq,
+    YR::ValueType: Eq,
+{
+    data: Vec<(XR::ValueType, YR::ValueType, f64)>,
+    color_map: Box<dyn Fn(f64) -> RGBColor + 'a>,
+    border: Option<ShapeStyle>,
+    label_style: Option<TextStyle<'a>>,
+    label_formatter: Box<dyn Fn(f64) -> String + 'a>,
+    value_range: (f64, f64),
+    idx: usize,
+    _p: PhantomData<(XR, YR)>,
+}
+
+impl<'a, XR, YR> MatrixSeries<'a, XR, YR>
+where
+    XR: DescreteRanged,
+    YR: DescreteRanged,
+    XR::ValueType: Eq + Clone,
+    YR::ValueType: Eq + Clone,
+{
+    /// Create an empty matrix series for the chart, which has categorical axes
+    pub fn on<DB: DrawingBackend>(_: &ChartContext<DB, RangedCoord<XR, YR>>) -> Self {
+        Self {
+            data: vec![],
+            color_map: Box::new(|v| {
+                let mix =
+                    |from: u8, to: u8| (f64::from(from) * (1.0 - v) + f64::from(to) * v) as u8;
+                RGBColor(mix(255, 8), mix(255, 48), mix(255, 107))
+            }),
+            border: None,
+            label_style: None,
+            label_formatter: Box::new(|v| format!("{}", v)),
+            value_range: (0.0, 0.0),
+            idx: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Set the data of the matrix
+    /// - `iter`: The iterator of the column, the row and the value of each cell
+    pub fn data<I: IntoIterator<Item = (XR::ValueType, YR::ValueType, f64)>>(
+        mut self,
+        iter: I,
+    ) -> Self {
+        self.data = iter.into_iter().collect();
+        let min = self
+            .data
+            .iter()
+            .map(|(_, _, v)| *v)
+            .fold(std::f64::INFINITY, f64::min);
+        let max = self
+            .data
+            .iter()
+            .map(|(_, _, v)| *v)
+            .fold(std::f64::NEG_INFINITY, f64::max);
+        self.value_range = (min, max);
+        self
+    }
+
+    /// Set the color map, by default the cells are colored from white to dark blue
+    /// - `map`: The function that maps the value, normalized to 0.0 - 1.0 by the smallest and
+    ///   the largest values, to the color of the cell
+    pub fn color_map<F: Fn(f64) -> RGBColor + 'a>(mut self, map: F) -> Self {
+        self.color_map = Box::new(map);
+        self
+    }
+
+    /// Draw a border around each cell
+    /// - `style`: The style of the border
+    pub fn border<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border = Some(style.into());
+        self
+    }
+
+    /// Show the value of each cell as a label at the center of the cell
+    /// - `style`: The style of the labels
+    pub fn labels<S: Into<TextStyle<'a>>>(mut self, style: S) -> Self {
+        self.label_style = Some(style.into());
+        self
+    }
+
+    /// Set the formatter of the value labels
+    /// - `fmt`: The function that formats the value
+    pub fn label_formatter<F: Fn(f64) -> String + 'a>(mut self, fmt: F) -> Self {
+        self.label_formatter = Box::new(fmt);
+        self
+    }
+}
+
+impl<'a, XR, YR> Iterator for MatrixSeries<'a, XR, YR>
+where
+    XR: DescreteRanged,
+    YR: DescreteRanged,
+    XR::ValueType: Eq + Clone,
+    YR::ValueType: Eq + Clone,
+{
+    type Item = HeatmapCell<'a, (XR::ValueType, YR::ValueType)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, value) = self.data.get(self.idx)?.clone();
+        self.idx += 1;
+
+        let (min, max) = self.value_range;
+        let normalized = if max > min {
+            (value - min) / (max - min)
+        } else {
+            1.0
+        };
+
+        let corners = [
+            (x.clone(), y.clone()),
+            (XR::next_value(&x), YR::next_value(&y)),
+        ];
+        let mut cell = HeatmapCell::new(corners, (self.color_map)(normalized).filled());
+        if let Some(ref border) = self.border {
+            cell = cell.border(border.clone());
+        }
+        if let Some(ref style) = self.label_style {
+            cell = cell.label((self.label_formatter)(value), style.clone());
+        }
+        Some(cell)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_matrix_cell_placement() {
+        let mut backend = MockedBackend::new(300, 200);
+        let calls = backend.record_calls();
+        let area = backend.into_drawing_area();
+        let mut chart = ChartBuilder::on(&area)
+            .build_ranged(0..3, 0..2)
+            .expect("Build chart failed");
+        let series = MatrixSeries::on(&chart).data(vec![(0, 0, 1.0), (2, 1, 3.0)]);
+        chart.draw_series(series).expect("Drawing Failure");
+
+        // Each cell fills the band of its column and row, the smallest value is white and the
+        // largest is dark blue
+        assert_eq!(
+            calls.rects(),
+            vec![
+                (WHITE.to_rgba(), true, (0, 100), (100, 200)),
+                (RGBColor(8, 48, 107).to_rgba(), true, (200, 0), (300, 100)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matrix_labels() {
+        let mut buffer = String::new();
+        {
+            let area = TextDrawingBackend::with_buffer(&mut buffer, (150, 50)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&area)
+                .build_ranged(0..3, 0..2)
+                .expect("Build chart failed");
+            let series = MatrixSeries::on(&chart)
+                .data(vec![(0, 0, 1.0), (2, 1, 3.0)])
+                .labels(("Arial", 10).into_font().color(&BLACK))
+                .label_formatter(|v| format!("v{:.0}", v));
+            chart.draw_series(series).expect("Drawing Failure");
+        }
+
+        let find = |label: &str| {
+            buffer.lines().enumerate().find_map(|(y, row)| {
+                row.find(label)
+                    .map(|byte| (row[..byte].chars().count() as i32, y as i32))
+            })
+        };
+        let near =
+            |(x, y): (i32, i32), (cx, cy): (i32, i32)| (x - cx).abs() <= 5 && (y - cy).abs() <= 2;
+        // The labels are drawn at the center of their cells, a cell of text is 2x4 pixels
+        assert!(near(find("v1").expect("Missing label"), (25, 37)));
+        assert!(near(find("v3").expect("Missing label"), (125, 12)));
+    }
+}
@@ -10,10 +10,34 @@
   So iterator combinator such as `map`, `zip`, etc can also be used.
 */
 
+mod area_between;
+mod area_series;
+mod grouped_bar;
 mod histogram;
+mod line_marker;
 mod line_series;
+mod matrix;
+mod moving_average;
+mod pie_series;
 mod point_series;
+mod regression;
+mod stacked_bar;
+mod strip;
+mod violin;
 
+pub use area_between::AreaBetweenSeries;
+pub use area_series::AreaSeries;
+pub use grouped_bar::{BarSeriesError, GroupedBarSeries};
 pub use histogram::Histogram;
-pub use line_series::LineSeries;
-pub use point_series::PointSeries;
+pub use line_marker::{LineMarkerSeries, LineWithMarkers};
+pub use line_series::{
+    FiniteValue, LineSeries, NonFiniteError, NonFinitePolicy, WrappedLineSeries,
+};
+pub use matrix::MatrixSeries;
+pub use moving_average::{MovingAverage, MovingAverageEdge};
+pub use pie_series::PieSeries;
+pub use point_series::{ColoredPointSeries, PointSeries};
+pub use regression::LinearRegression;
+pub use stacked_bar::StackedBarSeries;
+pub use strip::{StripPoint, StripSeries};
+pub use violin::{ViolinScale, ViolinSeries};
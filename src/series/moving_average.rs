@@ -0,0 +1,95 @@
+use super::{LineSeries, NonFinitePolicy};
+use crate::style::ShapeStyle;
+
+/// How the first points of the data, which don't have a full window before them, are averaged
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MovingAverageEdge {
+    /// Average the points available so far, so the line starts at the first point
+    Shrink,
+    /// Leave the points without a full window as NaN, so the line starts at the first full window
+    Pad,
+}
+
+/// The trailing moving average of a data series, which is usually drawn as a smoothed line
+/// over the raw data:
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0.0..30.0, -10.0..40.0)?;
+/// let data: Vec<_> = (0..30).map(|x| (x as f64, x as f64 + (x % 5) as f64 * 3.0)).collect();
+/// chart.draw_series(LineSeries::new(data.iter().cloned(), &BLUE))?;
+/// let average = MovingAverage::new(data.iter().cloned(), 7, MovingAverageEdge::Shrink);
+/// assert_eq!(average.label(), "MA(7)");
+/// chart.draw_moving_average(&average, &RED)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MovingAverage {
+    window: usize,
+    points: Vec<(f64, f64)>,
+}
+
+impl MovingAverage {
+    /// Compute the moving average of the data
+    /// - `data`: The data points, which are sorted by the X value
+    /// - `window`: The number of points averaged for each point, including the point itself
+    /// - `edge`: How the points without a full window are averaged
+    pub fn new<I: IntoIterator<Item = (f64, f64)>>(
+        data: I,
+        window: usize,
+        edge: MovingAverageEdge,
+    ) -> Self {
+        let window = window.max(1);
+        let data: Vec<_> = data.into_iter().collect();
+        let points = data
+            .iter()
+            .enumerate()
+            .map(|(idx, &(x, _))| {
+                if idx + 1 < window && edge == MovingAverageEdge::Pad {
+                    return (x, std::f64::NAN);
+                }
+                let part = &data[(idx + 1).saturating_sub(window)..=idx];
+                let sum: f64 = part.iter().map(|(_, y)| y).sum();
+                (x, sum / part.len() as f64)
+            })
+            .collect();
+        Self { window, points }
+    }
+
+    /// Get the label of the moving average for the legend, e.g. "MA(7)"
+    pub fn label(&self) -> String {
+        format!("MA({})", self.window)
+    }
+
+    /// Get the averaged points, the padded points are NaN
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Create the line series of the moving average, which is broken at the NaN points
+    /// - `style`: The style of the line
+    pub fn series<S: Into<ShapeStyle>>(&self, style: S) -> LineSeries<(f64, f64), Vec<(f64, f64)>> {
+        LineSeries::with_policy(self.points.clone(), style, NonFinitePolicy::Break)
+            .expect("Breaking the line never fails")
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_moving_average() {
+    let data: Vec<_> = (0..5).map(|x| (f64::from(x), f64::from(x * x))).collect();
+
+    let average = MovingAverage::new(data.clone(), 3, MovingAverageEdge::Shrink);
+    let values: Vec<_> = average.points().iter().map(|(_, y)| *y).collect();
+    assert_eq!(values, vec![0.0, 0.5, 5.0 / 3.0, 14.0 / 3.0, 29.0 / 3.0]);
+    assert_eq!(average.label(), "MA(3)");
+
+    let average = MovingAverage::new(data, 3, MovingAverageEdge::Pad);
+    assert!(average.points()[1].1.is_nan());
+    assert_eq!(average.points()[2], (2.0, 5.0 / 3.0));
+    assert_eq!(average.series(&crate::style::RED).count(), 1);
+}
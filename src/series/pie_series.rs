@@ -0,0 +1,170 @@
+use std::f64::consts::PI;
+
+use crate::element::Wedge;
+use crate::style::{Color, Palette, Palette99, ShapeStyle, TextStyle};
+
+/// The pie series, which takes an iterator of values and creates a wedge for each value.
+/// The angle of each wedge is proportional to the value, and the wedges start from 12 o'clock
+/// clockwise. By setting the inner radius, the series becomes a donut chart.
+pub struct PieSeries<'a, Coord> {
+    center: Coord,
+    radius: u32,
+    inner_radius: u32,
+    values: Vec<f64>,
+    total: f64,
+    styles: Vec<ShapeStyle>,
+    labels: Vec<String>,
+    label_style: Option<TextStyle<'a>>,
+    start_angle: f64,
+    idx: usize,
+}
+
+impl<'a, Coord: Clone> PieSeries<'a, Coord> {
+    /// Create a new pie series, the wedges are colored with `Palette99` by default
+    /// - `center`: The center of the pie
+    /// - `radius`: The radius of the pie
+    /// - `values`: The values of the wedges
+    pub fn new<I: IntoIterator<Item = f64>>(center: Coord, radius: u32, values: I) -> Self {
+        let values: Vec<f64> = values.into_iter().map(|v| v.max(0.0)).collect();
+        let total = values.iter().sum();
+        Self {
+            center,
+            radius,
+            inner_radius: 0,
+            values,
+            total,
+            styles: vec![],
+            labels: vec![],
+            label_style: None,
+            start_angle: 0.0,
+            idx: 0,
+        }
+    }
+
+    /// Set the inner radius of the wedges, which makes the series a donut chart
+    /// - `radius`: The inner radius
+    pub fn inner_radius(mut self, radius: u32) -> Self {
+        self.inner_radius = radius;
+        self
+    }
+
+    /// Set the style of each wedge
+    /// - `styles`: The styles of the wedges, in the same order of the values
+    pub fn styles<S: Into<ShapeStyle>, I: IntoIterator<Item = S>>(mut self, styles: I) -> Self {
+        self.styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the label of each wedge
+    /// - `labels`: The labels of the wedges, in the same order of the values
+    /// - `style`: The style of the labels
+    pub fn labels<T: Into<String>, I: IntoIterator<Item = T>, S: Into<TextStyle<'a>>>(
+        mut self,
+        labels: I,
+        style: S,
+    ) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self.label_style = Some(style.into());
+        self
+    }
+}
+
+impl<'a, Coord: Clone> Iterator for PieSeries<'a, Coord> {
+    type Item = Wedge<'a, Coord>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = *self.values.get(self.idx)?;
+
+        let span = if self.total > 0.0 {
+            value / self.total * 2.0 * PI
+        } else {
+            0.0
+        };
+        let angles = (self.start_angle, self.start_angle + span);
+        self.start_angle += span;
+
+        let style = self
+            .styles
+            .get(self.idx)
+            .cloned()
+            .unwrap_or_else(|| Palette99::pick(self.idx).filled());
+
+        let mut wedge = Wedge::new(self.center.clone(), self.radius, angles, style)
+            .inner_radius(self.inner_radius);
+
+        if let (Some(text), Some(style)) = (self.labels.get(self.idx), self.label_style.as_ref()) {
+            wedge = wedge.label(text.clone(), style.clone());
+        }
+
+        self.idx += 1;
+        Some(wedge)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_pie_series() {
+    use crate::prelude::*;
+
+    let mut backend = MockedBackend::new(100, 100);
+    let calls = backend.record_calls();
+    let area = backend.into_drawing_area();
+    let series = PieSeries::new((50, 50), 40, vec![1.0, 1.0, 2.0, -1.0]).styles(vec![
+        RED.filled(),
+        GREEN.filled(),
+        BLUE.filled(),
+    ]);
+    for wedge in series {
+        area.draw(&wedge).expect("Drawing Failure");
+    }
+
+    // The spans are proportional to the values, starting from 12 o'clock clockwise
+    let wedges = calls.polygons();
+    let ends: Vec<_> = wedges
+        .iter()
+        .map(|(c, vert)| (c.clone(), vert[0], vert[vert.len() - 2]))
+        .collect();
+    assert_eq!(ends[0], (RED.to_rgba(), (50, 10), (90, 50)));
+    assert_eq!(ends[1], (GREEN.to_rgba(), (90, 50), (50, 90)));
+    assert_eq!(ends[2], (BLUE.to_rgba(), (50, 90), (50, 10)));
+    assert!(wedges
+        .iter()
+        .all(|(_, vert)| vert[vert.len() - 1] == (50, 50)));
+    // The negative value becomes an empty wedge in the default palette
+    assert_eq!(ends[3].0, Palette99::pick(3).to_rgba());
+    assert_eq!(ends[3].1, ends[3].2);
+}
+
+#[cfg(test)]
+#[test]
+fn test_pie_series_single_slice() {
+    use crate::prelude::*;
+
+    // A single slice takes the whole pie, which is a circle without the edges of a wedge
+    let area = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_draw_circle(|c, filled, center, radius| {
+            assert_eq!(c, RED.to_rgba());
+            assert!(filled);
+            assert_eq!((center, radius), ((50, 50), 40));
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_circle_call, 1);
+            assert_eq!(b.num_fill_polygon_call, 0);
+        });
+    });
+    for wedge in PieSeries::new((50, 50), 40, vec![5.0]).styles(vec![RED.filled()]) {
+        area.draw(&wedge).expect("Drawing Failure");
+    }
+
+    assert_eq!(PieSeries::new((50, 50), 40, vec![]).count(), 0);
+    // Nothing to be proportional to, so every wedge is empty rather than NaN
+    let area = crate::create_mocked_drawing_area(100, 100, |m| {
+        m.check_fill_polygon(|_, vert| {
+            assert!(vert
+                .iter()
+                .all(|&(x, y)| (x, y) == (50, 10) || (x, y) == (50, 50)));
+        });
+    });
+    for wedge in PieSeries::new((50, 50), 40, vec![0.0, 0.0]) {
+        area.draw(&wedge).expect("Drawing Failure");
+    }
+}
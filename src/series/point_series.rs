@@ -1,5 +1,7 @@
+use std::ops::Range;
+
 use crate::element::PointElement;
-use crate::style::ShapeStyle;
+use crate::style::{Color, RGBColor, ShapeStyle};
 
 /// The point plot object, which takes an iterator of points in guest coordinate system
 /// and create an element for each point
@@ -54,3 +56,115 @@ impl<'a, Coord, I: IntoIterator<Item = Coord>, E> PointSeries<'a, Coord, I, E> {
         }
     }
 }
+
+/// The point plot object where each point has its own color, e.g. a scatter plot colored by a
+/// third variable. The data iterator yields the point along with either its style, or a value
+/// which is mapped to the color by a color map. The whole series is drawn by a single
+/// `ChartContext::draw_series` call, so it shares one legend entry.
+pub struct ColoredPointSeries<'a, Coord, V, I: IntoIterator<Item = (Coord, V)>, E> {
+    size: u32,
+    data_iter: I::IntoIter,
+    style_of: Box<dyn Fn(V) -> ShapeStyle + 'a>,
+    make_point: &'a dyn Fn(Coord, u32, ShapeStyle) -> E,
+}
+
+impl<'a, Coord, V, I: IntoIterator<Item = (Coord, V)>, E> Iterator
+    for ColoredPointSeries<'a, Coord, V, I, E>
+{
+    type Item = E;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.data_iter
+            .next()
+            .map(|(x, v)| (self.make_point)(x, self.size, (self.style_of)(v)))
+    }
+}
+
+impl<'a, Coord, S, I, E> ColoredPointSeries<'a, Coord, S, I, E>
+where
+    S: Into<ShapeStyle>,
+    I: IntoIterator<Item = (Coord, S)>,
+    E: PointElement<Coord>,
+{
+    /// Create a new colored point series, where the data iterator yields each point with its
+    /// style, for example, the color of the category of the point
+    /// - `iter`: The iterator of the points and their styles
+    /// - `size`: The size of the points
+    pub fn new(iter: I, size: u32) -> Self {
+        Self {
+            size,
+            data_iter: iter.into_iter(),
+            style_of: Box::new(|style: S| style.into()),
+            make_point: &|a, b, c| E::make_point(a, b, c),
+        }
+    }
+}
+
+impl<'a, Coord, I, E> ColoredPointSeries<'a, Coord, f64, I, E>
+where
+    I: IntoIterator<Item = (Coord, f64)>,
+    E: PointElement<Coord>,
+{
+    /// Create a new colored point series, where the data iterator yields each point with a
+    /// value which is mapped to the color of the point. The points are filled.
+    /// - `iter`: The iterator of the points and their values
+    /// - `size`: The size of the points
+    /// - `value_range`: The range of the values, which is normalized to 0.0 - 1.0 for the color
+    ///   map, the values out of the range are clamped
+    /// - `color_map`: The function that maps the normalized value to the color
+    pub fn with_color_map<F: Fn(f64) -> RGBColor + 'a>(
+        iter: I,
+        size: u32,
+        value_range: Range<f64>,
+        color_map: F,
+    ) -> Self {
+        Self {
+            size,
+            data_iter: iter.into_iter(),
+            style_of: Box::new(move |v: f64| {
+                let span = value_range.end - value_range.start;
+                let normalized = if span != 0.0 {
+                    ((v - value_range.start) / span).max(0.0).min(1.0)
+                } else {
+                    1.0
+                };
+                color_map(normalized).filled()
+            }),
+            make_point: &|a, b, c| E::make_point(a, b, c),
+        }
+    }
+}
+
+impl<'a, Coord, V, I: IntoIterator<Item = (Coord, V)>, E> ColoredPointSeries<'a, Coord, V, I, E> {
+    /// Use the customized function to construct the point elements, which receives the style
+    /// of each point, see `PointSeries::of_element`
+    /// - `cons`: The function that constructs the point element
+    pub fn of_element<F: Fn(Coord, u32, ShapeStyle) -> E>(mut self, cons: &'a F) -> Self {
+        self.make_point = cons;
+        self
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_colored_point_series() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(100, 100, |m| {
+        let expected = [RGBColor(0, 0, 0), RGBColor(127, 0, 0), RGBColor(255, 0, 0)];
+        let mut idx = 0;
+        m.check_draw_circle(move |c, filled, _, size| {
+            assert_eq!(c, expected[idx].to_rgba());
+            assert!(filled);
+            assert_eq!(size, 3);
+            idx += 1;
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_circle_call, 3);
+        });
+    });
+    let data = vec![((10, 10), 0.0), ((20, 20), 5.0), ((30, 30), 20.0)];
+    for point in ColoredPointSeries::<_, _, _, Circle<_>>::with_color_map(data, 3, 0.0..10.0, |v| {
+        RGBColor((255.0 * v) as u8, 0, 0)
+    }) {
+        da.draw(&point).expect("Drawing Failure");
+    }
+}
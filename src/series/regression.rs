@@ -0,0 +1,133 @@
+use crate::element::Path;
+use crate::style::ShapeStyle;
+
+/// The least-squares linear regression of a data set, which can be drawn as a line spanning
+/// the X range of the data:
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0.0..3.0, 0.0..10.0)?;
+/// let data = vec![(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)];
+/// let fit = LinearRegression::fit(data.iter().cloned()).unwrap();
+/// assert_eq!(fit.equation(), "y = 2x + 1");
+/// chart.draw_series(fit.series(&RED))?.label(fit.equation());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LinearRegression {
+    /// The slope of the fitted line
+    pub slope: f64,
+    /// The intercept of the fitted line
+    pub intercept: f64,
+    /// The coefficient of determination
+    pub r_squared: f64,
+    x_range: (f64, f64),
+}
+
+/// Format the number with at most 2 decimal places
+fn format_coef(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+impl LinearRegression {
+    /// Fit a line to the data with the least-squares method. The data is centered before
+    /// fitting, so large X values don't hurt the precision.
+    /// - `data`: The data points
+    /// - **returns**: The fitted line, or `None` if there are less than 2 distinct X values
+    pub fn fit<I: IntoIterator<Item = (f64, f64)>>(data: I) -> Option<Self> {
+        let data: Vec<_> = data.into_iter().collect();
+        if data.len() < 2 {
+            return None;
+        }
+
+        let n = data.len() as f64;
+        let mean_x = data.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = data.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+        let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+        let (mut min_x, mut max_x) = (std::f64::INFINITY, std::f64::NEG_INFINITY);
+        for &(x, y) in data.iter() {
+            let (dx, dy) = (x - mean_x, y - mean_y);
+            sxx += dx * dx;
+            sxy += dx * dy;
+            syy += dy * dy;
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+        }
+
+        if sxx == 0.0 {
+            return None;
+        }
+
+        let slope = sxy / sxx;
+        Some(Self {
+            slope,
+            intercept: mean_y - slope * mean_x,
+            r_squared: if syy == 0.0 {
+                1.0
+            } else {
+                sxy * sxy / (sxx * syy)
+            },
+            x_range: (min_x, max_x),
+        })
+    }
+
+    /// Evaluate the fitted line at the given X value
+    pub fn predict(&self, x: f64) -> f64 {
+        self.slope * x + self.intercept
+    }
+
+    /// Get the equation of the fitted line, e.g. "y = 2.1x + 0.3", which is useful as the
+    /// legend label
+    pub fn equation(&self) -> String {
+        let sign = if self.intercept < 0.0 { '-' } else { '+' };
+        format!(
+            "y = {}x {} {}",
+            format_coef(self.slope),
+            sign,
+            format_coef(self.intercept.abs())
+        )
+    }
+
+    /// Get the equation of the fitted line followed by the R², e.g. "y = 2.1x + 0.3, R² = 0.98"
+    pub fn equation_with_r_squared(&self) -> String {
+        format!("{}, R² = {}", self.equation(), format_coef(self.r_squared))
+    }
+
+    /// Create the series of the fitted line which spans the X range of the data
+    /// - `style`: The style of the line
+    pub fn series<S: Into<ShapeStyle>>(&self, style: S) -> std::iter::Once<Path<(f64, f64)>> {
+        let (x0, x1) = self.x_range;
+        std::iter::once(Path::new(
+            vec![(x0, self.predict(x0)), (x1, self.predict(x1))],
+            style,
+        ))
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_linear_regression() {
+    let base = 1.0e9;
+    let fit =
+        LinearRegression::fit((0..10).map(|x| (base + f64::from(x), 2.1 * f64::from(x) + 0.3)))
+            .expect("Fitting Failure");
+
+    assert!((fit.slope - 2.1).abs() < 1e-6);
+    assert!((fit.predict(base) - 0.3).abs() < 1e-3);
+    assert!((fit.r_squared - 1.0).abs() < 1e-9);
+    assert!(LinearRegression::fit(vec![(1.0, 1.0), (1.0, 2.0)]).is_none());
+
+    let fit = LinearRegression::fit(vec![(0.0, 0.3), (1.0, 2.4)]).expect("Fitting Failure");
+    assert_eq!(fit.equation(), "y = 2.1x + 0.3");
+}
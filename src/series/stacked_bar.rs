@@ -0,0 +1,307 @@
+use std::ops::Range;
+
+use super::BarSeriesError;
+use crate::element::{EdgeSnap, Path, Rectangle};
+use crate::style::{Color, Palette, Palette99, ShapeStyle};
+
+/// The stacked bar series, which stacks the bars of several value series on top of each other
+/// within each category, optionally with lines overlaid on the bars, e.g. the total of a
+/// dashboard. The categories are placed at `0.0, 1.0, 2.0, ...` on a numeric X axis, so that the
+/// bars and the lines share the axis, and `StackedBarSeries::x_range` and
+/// `StackedBarSeries::y_range` give the ranges which fit the stacked totals as well as the lines:
+///
+/// ```no_run
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let root = SVGBackend::new("stacked-bar.svg", (640, 480)).into_drawing_area();
+/// let names = ["Desktop", "Mobile"];
+/// let mut combo = StackedBarSeries::new(vec!["Mon", "Tue", "Wed"]);
+/// combo
+///     .add_series(vec![3.0, 4.0, 2.0], &BLUE)?
+///     .add_series(vec![5.0, 6.0, 7.0], &GREEN)?
+///     .add_line(vec![8.0, 10.0, 9.0], &RED)?;
+/// let mut chart = ChartBuilder::on(&root)
+///     .build_ranged(combo.x_range(), combo.y_range())?;
+/// let category = |x: &f64| combo.category(*x).map_or(String::new(), |c| c.to_string());
+/// chart.configure_mesh().x_label_formatter(&category).draw()?;
+/// for idx in 0..combo.stack_count() {
+///     chart.draw_series_with_legend(combo.bars(idx))?.label(names[idx]);
+/// }
+/// chart.draw_series_with_legend(combo.line(0))?.label("Total");
+/// # Ok(())
+/// # }
+/// ```
+pub struct StackedBarSeries<C> {
+    categories: Vec<C>,
+    stacks: Vec<(Vec<f64>, ShapeStyle)>,
+    lines: Vec<(Vec<f64>, ShapeStyle)>,
+    bar_width: f64,
+}
+
+impl<C> StackedBarSeries<C> {
+    /// Create a new stacked bar series without any value series
+    /// - `categories`: The categories, each of them is a stack of bars
+    pub fn new<I: IntoIterator<Item = C>>(categories: I) -> Self {
+        Self {
+            categories: categories.into_iter().collect(),
+            stacks: vec![],
+            lines: vec![],
+            bar_width: 0.8,
+        }
+    }
+
+    /// Set the width of the bars as a fraction of the category band, the bars are centered in
+    /// the band
+    /// - `fraction`: The fraction of the band occupied by the bars, 0.8 by default
+    pub fn bar_width(mut self, fraction: f64) -> Self {
+        self.bar_width = fraction.max(0.0).min(1.0);
+        self
+    }
+
+    /// Add a value series on the top of the stacks. The positive values are stacked upward
+    /// and the negative values are stacked downward from zero.
+    /// - `values`: The values, one for each category in the same order, the NaN and infinite
+    ///   values leave the category without a bar of this series
+    /// - `style`: The style of the bars of this series
+    /// - **returns**: An error if the number of values doesn't match the number of categories
+    pub fn add_series<S: Into<ShapeStyle>, I: IntoIterator<Item = f64>>(
+        &mut self,
+        values: I,
+        style: S,
+    ) -> Result<&mut Self, BarSeriesError> {
+        let values: Vec<_> = values.into_iter().collect();
+        if values.len() != self.categories.len() {
+            return Err(BarSeriesError::LengthMismatch {
+                series: self.stacks.len(),
+                expected: self.categories.len(),
+                actual: values.len(),
+            });
+        }
+        self.stacks.push((values, style.into()));
+        Ok(self)
+    }
+
+    /// Add a line overlaid on the bars, which goes through the centers of the categories
+    /// - `values`: The values, one for each category in the same order, the NaN and infinite
+    ///   values are skipped by the line
+    /// - `style`: The style of the line
+    /// - **returns**: An error with the index of the line if the number of values doesn't match
+    ///   the number of categories
+    pub fn add_line<S: Into<ShapeStyle>, I: IntoIterator<Item = f64>>(
+        &mut self,
+        values: I,
+        style: S,
+    ) -> Result<&mut Self, BarSeriesError> {
+        let values: Vec<_> = values.into_iter().collect();
+        if values.len() != self.categories.len() {
+            return Err(BarSeriesError::LineLengthMismatch {
+                line: self.lines.len(),
+                expected: self.categories.len(),
+                actual: values.len(),
+            });
+        }
+        self.lines.push((values, style.into()));
+        Ok(self)
+    }
+
+    /// Get the number of the stacked value series
+    pub fn stack_count(&self) -> usize {
+        self.stacks.len()
+    }
+
+    /// Get the number of the overlaid lines
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Get the category at a position of the X axis, which is useful for the label formatter
+    /// - `pos`: The position on the X axis
+    /// - **returns**: The category nearest to the position, if there is any
+    pub fn category(&self, pos: f64) -> Option<&C> {
+        let idx = pos.round();
+        if idx < 0.0 || (pos - idx).abs() > 1e-6 {
+            return None;
+        }
+        self.categories.get(idx as usize)
+    }
+
+    /// Get the range of the X axis, which has a half of band of room at both sides
+    pub fn x_range(&self) -> Range<f64> {
+        -0.5..(self.categories.len() as f64 - 0.5).max(0.5)
+    }
+
+    /// Get the range of the Y axis, which covers zero, the totals of the stacks in both
+    /// directions and all the overlaid lines
+    pub fn y_range(&self) -> Range<f64> {
+        let mut min = 0.0f64;
+        let mut max = 0.0f64;
+
+        for idx in 0..self.categories.len() {
+            let (mut neg, mut pos) = (0.0, 0.0);
+            for (values, _) in self.stacks.iter() {
+                if !values[idx].is_finite() {
+                    continue;
+                }
+                if values[idx] < 0.0 {
+                    neg += values[idx];
+                } else {
+                    pos += values[idx];
+                }
+            }
+            min = min.min(neg);
+            max = max.max(pos);
+        }
+
+        for value in self.lines.iter().flat_map(|(values, _)| values.iter()) {
+            if value.is_finite() {
+                min = min.min(*value);
+                max = max.max(*value);
+            }
+        }
+
+        if min == max {
+            return min..min + 1.0;
+        }
+        min..max
+    }
+
+    /// Get the style of a stacked value series, which is useful for the legend
+    /// - `idx`: The index of the series
+    pub fn style(&self, idx: usize) -> ShapeStyle {
+        self.stacks
+            .get(idx)
+            .map(|(_, style)| style.clone())
+            .unwrap_or_else(|| Palette99::pick(idx).filled())
+    }
+
+    /// Get the bars of a stacked value series, each of them starts from the top of the bar of
+    /// the previous series in the same direction. The non-finite values don't have a bar.
+    /// - `idx`: The index of the series
+    /// - **returns**: The series of bars, which can be drawn with `ChartContext::draw_series`
+    pub fn bars(&self, idx: usize) -> impl Iterator<Item = Rectangle<(f64, f64)>> + '_ {
+        let style = self.style(idx);
+        let half = self.bar_width / 2.0;
+        let values = self.stacks.get(idx).map_or(&[][..], |(v, _)| &v[..]);
+        values
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| value.is_finite())
+            .map(move |(cat, value)| {
+                let base: f64 = self.stacks[..idx]
+                    .iter()
+                    .map(|(values, _)| values[cat])
+                    .filter(|v| v.is_finite() && (*v < 0.0) == (*value < 0.0))
+                    .sum();
+                let x = cat as f64;
+                // The bars on top of each other share the edges
+                let mut rect =
+                    Rectangle::new([(x - half, base), (x + half, base + value)], style.clone());
+                rect.set_edge_snap(EdgeSnap::Exclusive);
+                rect
+            })
+    }
+
+    /// Get an overlaid line
+    /// - `idx`: The index of the line
+    /// - **returns**: The series with the line, which can be drawn with `ChartContext::draw_series`
+    pub fn line(&self, idx: usize) -> impl Iterator<Item = Path<(f64, f64)>> {
+        self.lines
+            .get(idx)
+            .map(|(values, style)| {
+                Path::new(
+                    values
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, y)| y.is_finite())
+                        .map(|(x, y)| (x as f64, *y))
+                        .collect::<Vec<_>>(),
+                    style.clone(),
+                )
+            })
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_stacked_bar_series() {
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+    let mut combo = StackedBarSeries::new(vec!["a", "b", "c"]).bar_width(0.5);
+    combo
+        .add_series(vec![1.0, 2.0, -1.0], &RED)
+        .expect("Bad series")
+        .add_series(vec![3.0, 2.0, -2.0], &BLUE)
+        .expect("Bad series")
+        .add_line(vec![4.0, 6.0, -4.0], &BLACK)
+        .expect("Bad series");
+    assert!(combo.add_series(vec![1.0], &GREEN).is_err());
+
+    assert_eq!(combo.x_range(), -0.5..2.5);
+    assert_eq!(combo.y_range(), -4.0..6.0);
+    assert_eq!(combo.category(1.0), Some(&"b"));
+    assert_eq!(combo.category(0.5), None);
+    assert_eq!(combo.bars(1).count(), 3);
+    assert_eq!(combo.line(0).count(), 1);
+    assert_eq!(combo.line(1).count(), 0);
+
+    let bar = combo.bars(1).nth(1).unwrap();
+    let corners: Vec<_> = (&bar).point_iter().iter().cloned().collect();
+    assert_eq!(corners, vec![(0.75, 2.0), (1.25, 4.0)]);
+    let bar = combo.bars(1).nth(2).unwrap();
+    let corners: Vec<_> = (&bar).point_iter().iter().cloned().collect();
+    assert_eq!(corners, vec![(1.75, -1.0), (2.25, -3.0)]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_stacked_bar_non_finite_values() {
+    use crate::element::PointCollection;
+    use crate::prelude::*;
+    let mut combo = StackedBarSeries::new(vec!["a", "b", "c"]).bar_width(0.5);
+    combo
+        .add_series(vec![1.0, std::f64::NAN, 2.0], &RED)
+        .expect("Bad series")
+        .add_series(vec![3.0, 2.0, std::f64::INFINITY], &BLUE)
+        .expect("Bad series")
+        .add_line(vec![4.0, std::f64::NAN, 5.0], &BLACK)
+        .expect("Bad series");
+
+    // The non-finite values don't have bars, and the bars above them start from the finite
+    // values below
+    let corners = |idx| -> Vec<Vec<(f64, f64)>> {
+        combo
+            .bars(idx)
+            .map(|bar| (&bar).point_iter().iter().cloned().collect())
+            .collect()
+    };
+    assert_eq!(
+        corners(0),
+        vec![
+            vec![(-0.25, 0.0), (0.25, 1.0)],
+            vec![(1.75, 0.0), (2.25, 2.0)]
+        ]
+    );
+    assert_eq!(
+        corners(1),
+        vec![
+            vec![(-0.25, 1.0), (0.25, 4.0)],
+            vec![(0.75, 0.0), (1.25, 2.0)]
+        ]
+    );
+    let line = combo.line(0).next().unwrap();
+    let points: Vec<_> = (&line).point_iter().iter().cloned().collect();
+    assert_eq!(points, vec![(0.0, 4.0), (2.0, 5.0)]);
+
+    // The error of a bad line reports the index of the line rather than the stacked series
+    match combo.add_line(vec![1.0], &BLACK) {
+        Err(BarSeriesError::LineLengthMismatch {
+            line,
+            expected,
+            actual,
+        }) => assert_eq!((line, expected, actual), (1, 3, 1)),
+        _ => panic!("The line with a wrong length is accepted"),
+    }
+    let message = combo.add_line(vec![], &BLACK).err().unwrap().to_string();
+    assert_eq!(message, "Line #1 has 0 values, but there are 3 categories");
+}
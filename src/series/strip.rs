@@ -0,0 +1,235 @@
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::chart::ChartContext;
+use crate::coord::{DescreteRanged, Ranged, RangedCoord};
+use crate::drawing::backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use crate::element::{Drawable, LegendSample, LegendShape, PointCollection};
+use crate::style::ShapeStyle;
+
+/// A point of the strip plot, which is the element of `StripSeries`. The point is placed at a
+/// relative position within the band of its category, which goes from the category to the next
+/// one on the X axis, like the bars of a histogram.
+pub struct StripPoint<Coord> {
+    band: [Coord; 2],
+    offset: f64,
+    size: u32,
+    style: ShapeStyle,
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a StripPoint<Coord> {
+    type Borrow = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.band
+    }
+}
+
+impl<Coord> LegendSample for StripPoint<Coord> {
+    fn legend_shape(&self) -> LegendShape {
+        LegendShape::Circle(self.style.clone(), self.size)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for StripPoint<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let (Some(left), Some(right)) = (points.next(), points.next()) {
+            let x = left.0 + (f64::from(right.0 - left.0) * self.offset).round() as i32;
+            return backend.draw_circle(
+                (x, left.1),
+                self.size,
+                &self.style.color,
+                self.style.filled,
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The strip plot, which draws every sample of each category as a point, spread horizontally
+/// within the band of the category on a descrete X axis by a jitter, so that the points with
+/// the same value are less likely to cover each other. The jitter is deterministic for the
+/// same seed, so the plot is reproducible:
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let groups = vec![(0, vec![1.0, 1.5, 1.5, 2.0]), (1, vec![2.5, 3.0, 3.0])];
+/// let strip = StripSeries::<RangedCoordi32>::new(groups, 3, BLUE.filled())
+///     .jitter(0.6)
+///     .seed(42);
+/// let mut chart = ChartBuilder::on(&root).build_ranged(0..2, strip.y_range())?;
+/// chart.draw_series(strip.points())?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct StripSeries<BR>
+where
+    BR: DescreteRanged,
+    BR::ValueType: Eq + Clone,
+{
+    groups: Vec<(BR::ValueType, Vec<f64>)>,
+    size: u32,
+    style: ShapeStyle,
+    jitter: f64,
+    seed: u64,
+    _p: PhantomData<BR>,
+}
+
+/// The SplitMix64 generator, which is small but good enough for spreading the points
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Get the next random number uniformly distributed in `0.0..1.0`
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl<BR> StripSeries<BR>
+where
+    BR: DescreteRanged,
+    BR::ValueType: Eq + Clone,
+{
+    /// Create a new strip plot
+    /// - `groups`: The categories and their samples, the NaN and infinite samples are ignored
+    /// - `size`: The radius of the points
+    /// - `style`: The style of the points
+    pub fn new<I, S>(groups: I, size: u32, style: S) -> Self
+    where
+        I: IntoIterator<Item = (BR::ValueType, Vec<f64>)>,
+        S: Into<ShapeStyle>,
+    {
+        let groups = groups
+            .into_iter()
+            .map(|(c, samples)| (c, samples.into_iter().filter(|v| v.is_finite()).collect()))
+            .collect();
+        Self {
+            groups,
+            size,
+            style: style.into(),
+            jitter: 0.5,
+            seed: 0,
+            _p: PhantomData,
+        }
+    }
+
+    /// Create a new strip plot for the chart, which uses the categories as the X axis
+    /// - `groups`: The categories and their samples, the NaN and infinite samples are ignored
+    /// - `size`: The radius of the points
+    /// - `style`: The style of the points
+    pub fn vertical<ACoord, DB, I, S>(
+        _: &ChartContext<DB, RangedCoord<BR, ACoord>>,
+        groups: I,
+        size: u32,
+        style: S,
+    ) -> Self
+    where
+        ACoord: Ranged<ValueType = f64>,
+        DB: DrawingBackend,
+        I: IntoIterator<Item = (BR::ValueType, Vec<f64>)>,
+        S: Into<ShapeStyle>,
+    {
+        Self::new(groups, size, style)
+    }
+
+    /// Set the width of the jitter as a fraction of the category band, the points of a
+    /// category are spread over this width around the center of the band
+    /// - `fraction`: The fraction of the band, 0.5 by default, 0.0 puts all the points on the
+    ///   center of the band
+    pub fn jitter(mut self, fraction: f64) -> Self {
+        self.jitter = fraction.max(0.0).min(1.0);
+        self
+    }
+
+    /// Set the seed of the jitter, the same seed always gives the same offsets
+    /// - `seed`: The seed, 0 by default
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Get the range of the Y axis, which covers all the samples
+    pub fn y_range(&self) -> Range<f64> {
+        let values = self.groups.iter().flat_map(|(_, samples)| samples.iter());
+        let min = values.clone().cloned().fold(std::f64::INFINITY, f64::min);
+        let max = values.cloned().fold(std::f64::NEG_INFINITY, f64::max);
+        if min < max {
+            min..max
+        } else if min == max {
+            min - 0.5..max + 0.5
+        } else {
+            0.0..1.0
+        }
+    }
+
+    /// Get the jittered points of all the groups
+    pub fn points(&self) -> impl Iterator<Item = StripPoint<(BR::ValueType, f64)>> + '_ {
+        let mut rng = SplitMix64(self.seed);
+        let (jitter, size) = (self.jitter, self.size);
+        self.groups
+            .iter()
+            .flat_map(|(c, samples)| samples.iter().map(move |y| (c, *y)))
+            .map(move |(c, y)| StripPoint {
+                band: [(c.clone(), y), (BR::next_value(c), y)],
+                offset: 0.5 + (rng.next_f64() - 0.5) * jitter,
+                size,
+                style: self.style.clone(),
+            })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_strip_series() {
+    use crate::prelude::*;
+
+    let groups = vec![
+        (0, vec![1.0; 20]),
+        (1, vec![2.0, std::f64::NAN, std::f64::INFINITY]),
+    ];
+    let strip = |seed, jitter| {
+        StripSeries::<RangedCoordi32>::new(groups.clone(), 2, &RED)
+            .jitter(jitter)
+            .seed(seed)
+    };
+    assert_eq!(strip(7, 0.4).y_range(), 1.0..2.0);
+
+    let xs = |strip: StripSeries<RangedCoordi32>| -> Vec<i32> {
+        let mut backend = MockedBackend::new(100, 100);
+        let calls = backend.record_calls();
+        let da = backend.into_drawing_area();
+        let mut chart = ChartBuilder::on(&da)
+            .build_ranged(0..2, 0.0..3.0)
+            .expect("Build chart error");
+        chart.draw_series(strip.points()).expect("Drawing Failure");
+        calls
+            .circles()
+            .into_iter()
+            .map(|(_, _, c, _)| c.0)
+            .collect()
+    };
+    let first = xs(strip(7, 0.4));
+    assert_eq!(first.len(), 21);
+    // The bands are 50 pixels wide, the points stay within the jitter width around the center
+    // of the band of their category, but they are spread out rather than on a single column
+    assert!(first[..20].iter().all(|x| (x - 25).abs() <= 10));
+    assert!(first[..20].iter().any(|x| *x != first[0]));
+    assert!((first[20] - 75).abs() <= 10);
+
+    // The same seed gives the same jitter
+    assert_eq!(first, xs(strip(7, 0.4)));
+    assert_ne!(first, xs(strip(8, 0.4)));
+    assert!(xs(strip(7, 0.0)).iter().all(|x| *x == 25 || *x == 75));
+}
@@ -0,0 +1,315 @@
+use std::ops::Range;
+
+use crate::element::{Path, Polygon, Rectangle};
+use crate::style::ShapeStyle;
+
+/// How the widths of the violins are normalized across the groups
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ViolinScale {
+    /// Every violin has the same maximum width
+    Width,
+    /// Every violin has the same area, so a group with a more concentrated distribution has a
+    /// wider violin
+    Area,
+}
+
+/// The violin plot, which draws the distribution of the samples of each category as a shape
+/// mirrored around the center of the category, estimated with a Gaussian kernel density
+/// estimate. The categories are placed at `0.0, 1.0, 2.0, ...` on a numeric X axis:
+///
+/// ```
+/// use plotters::prelude::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let mut buffer = vec![];
+/// # let root = SVGBackend::with_buffer(&mut buffer, (640, 480)).into_drawing_area();
+/// let groups = vec![
+///     ("GET", vec![12.0, 15.0, 14.0, 30.0, 13.0, 16.0]),
+///     ("POST", vec![40.0, 42.0, 55.0, 38.0, 47.0]),
+/// ];
+/// let violin = ViolinSeries::new(groups, GREEN.mix(0.5).filled());
+/// let mut chart = ChartBuilder::on(&root).build_ranged(violin.x_range(), violin.y_range())?;
+/// chart.draw_series_with_legend(violin.violins())?.label("Latency");
+/// chart.draw_series(violin.quartile_boxes(&BLACK))?;
+/// chart.draw_series(violin.medians(&WHITE))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ViolinSeries<C> {
+    groups: Vec<(C, Vec<f64>)>,
+    style: ShapeStyle,
+    width: f64,
+    scale: ViolinScale,
+    resolution: usize,
+}
+
+/// The bandwidth of the Gaussian kernel by the Silverman's rule of thumb
+fn silverman_bandwidth(samples: &[f64]) -> f64 {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let var = samples.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / n;
+    let bandwidth = 1.06 * var.sqrt() * n.powf(-0.2);
+    if bandwidth > 0.0 {
+        bandwidth
+    } else {
+        1.0
+    }
+}
+
+/// Estimate the density of the samples at the value with a Gaussian kernel
+fn kernel_density(samples: &[f64], bandwidth: f64, value: f64) -> f64 {
+    let norm = 1.0 / ((2.0 * std::f64::consts::PI).sqrt() * bandwidth * samples.len() as f64);
+    samples
+        .iter()
+        .map(|s| {
+            let z = (value - s) / bandwidth;
+            (-0.5 * z * z).exp()
+        })
+        .sum::<f64>()
+        * norm
+}
+
+/// Get the quantile of the sorted samples with the linear interpolation
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+impl<C> ViolinSeries<C> {
+    /// Create a new violin plot
+    /// - `groups`: The categories and their samples, the NaN and infinite samples are ignored
+    /// - `style`: The style of the violins
+    pub fn new<I, S>(groups: I, style: S) -> Self
+    where
+        I: IntoIterator<Item = (C, Vec<f64>)>,
+        S: Into<ShapeStyle>,
+    {
+        let groups = groups
+            .into_iter()
+            .map(|(c, samples)| {
+                let mut samples: Vec<_> = samples.into_iter().filter(|v| v.is_finite()).collect();
+                samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                (c, samples)
+            })
+            .collect();
+        Self {
+            groups,
+            style: style.into(),
+            width: 0.8,
+            scale: ViolinScale::Width,
+            resolution: 50,
+        }
+    }
+
+    /// Set the maximum width of the violins as a fraction of the category band
+    /// - `fraction`: The fraction of the band, 0.8 by default
+    pub fn width(mut self, fraction: f64) -> Self {
+        self.width = fraction.max(0.0).min(1.0);
+        self
+    }
+
+    /// Set how the widths of the violins are normalized across the groups
+    /// - `scale`: The normalization, `ViolinScale::Width` by default
+    pub fn scale(mut self, scale: ViolinScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the number of the points where the density is estimated along each violin
+    /// - `points`: The number of the points, 50 by default
+    pub fn resolution(mut self, points: usize) -> Self {
+        self.resolution = points.max(2);
+        self
+    }
+
+    /// Get the category at a position of the X axis, which is useful for the label formatter
+    /// - `pos`: The position on the X axis
+    /// - **returns**: The category nearest to the position, if there is any
+    pub fn category(&self, pos: f64) -> Option<&C> {
+        let idx = pos.round();
+        if idx < 0.0 || (pos - idx).abs() > 1e-6 {
+            return None;
+        }
+        self.groups.get(idx as usize).map(|(c, _)| c)
+    }
+
+    /// Get the range of the X axis, which has a half of band of room at both sides
+    pub fn x_range(&self) -> Range<f64> {
+        -0.5..(self.groups.len() as f64 - 0.5).max(0.5)
+    }
+
+    /// Get the range of the Y axis, which covers all the samples
+    pub fn y_range(&self) -> Range<f64> {
+        let values = self.groups.iter().flat_map(|(_, samples)| samples.iter());
+        let min = values.clone().cloned().fold(std::f64::INFINITY, f64::min);
+        let max = values.cloned().fold(std::f64::NEG_INFINITY, f64::max);
+        if min < max {
+            min..max
+        } else if min == max {
+            min - 0.5..max + 0.5
+        } else {
+            0.0..1.0
+        }
+    }
+
+    /// Estimate the density profile of each group over the range of its samples
+    fn profiles(&self) -> Vec<Vec<(f64, f64)>> {
+        self.groups
+            .iter()
+            .map(|(_, samples)| {
+                if samples.is_empty() {
+                    return vec![];
+                }
+                let bandwidth = silverman_bandwidth(samples);
+                let (lo, hi) = (samples[0], samples[samples.len() - 1]);
+                (0..self.resolution)
+                    .map(|idx| {
+                        let y = lo + (hi - lo) * idx as f64 / (self.resolution - 1) as f64;
+                        (y, kernel_density(samples, bandwidth, y))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Get the violins, one for each group with any sample
+    pub fn violins(&self) -> impl Iterator<Item = Polygon<(f64, f64)>> {
+        let profiles = self.profiles();
+        let peak = |profile: &[(f64, f64)]| profile.iter().map(|(_, d)| *d).fold(0.0, f64::max);
+        let global_peak = profiles.iter().map(|p| peak(p)).fold(0.0, f64::max);
+        let half_width = self.width / 2.0;
+        let style = self.style.clone();
+        let scale = self.scale;
+
+        profiles
+            .into_iter()
+            .enumerate()
+            .filter(|(_, profile)| !profile.is_empty())
+            .map(move |(idx, profile)| {
+                let max_density = match scale {
+                    ViolinScale::Width => peak(&profile),
+                    ViolinScale::Area => global_peak,
+                };
+                let x = idx as f64;
+                let offset = |d: f64| {
+                    if max_density > 0.0 {
+                        half_width * (d / max_density)
+                    } else {
+                        0.0
+                    }
+                };
+                let points: Vec<_> = profile
+                    .iter()
+                    .map(|&(y, d)| (x + offset(d), y))
+                    .chain(profile.iter().rev().map(|&(y, d)| (x - offset(d), y)))
+                    .collect();
+                Polygon::new(points, style.clone())
+            })
+    }
+
+    /// Get the boxes from the first quartile to the third quartile of each group, which are
+    /// drawn inside of the violins
+    /// - `style`: The style of the boxes
+    pub fn quartile_boxes<S: Into<ShapeStyle>>(
+        &self,
+        style: S,
+    ) -> impl Iterator<Item = Rectangle<(f64, f64)>> + '_ {
+        let style = style.into();
+        let half_width = self.width / 16.0;
+        self.groups
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, samples))| !samples.is_empty())
+            .map(move |(idx, (_, samples))| {
+                let x = idx as f64;
+                Rectangle::new(
+                    [
+                        (x - half_width, quantile(samples, 0.75)),
+                        (x + half_width, quantile(samples, 0.25)),
+                    ],
+                    style.clone(),
+                )
+            })
+    }
+
+    /// Get the median marks of each group, which are short horizontal lines at the center of
+    /// the violins
+    /// - `style`: The style of the marks
+    pub fn medians<S: Into<ShapeStyle>>(
+        &self,
+        style: S,
+    ) -> impl Iterator<Item = Path<(f64, f64)>> + '_ {
+        let style = style.into();
+        let half_width = self.width / 8.0;
+        self.groups
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, samples))| !samples.is_empty())
+            .map(move |(idx, (_, samples))| {
+                let (x, median) = (idx as f64, quantile(samples, 0.5));
+                Path::new(
+                    vec![(x - half_width, median), (x + half_width, median)],
+                    style.clone(),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_violin_series() {
+    use crate::element::PointCollection;
+    use crate::style::RED;
+
+    let sorted = [1.0, 2.0, 3.0, 4.0];
+    assert_eq!(quantile(&sorted, 0.5), 2.5);
+    assert_eq!(quantile(&sorted, 0.25), 1.75);
+
+    let groups = vec![
+        ("a", vec![0.0, 1.0, 1.0, 2.0]),
+        ("b", vec![0.0, 2.0, 4.0, 6.0, 8.0]),
+        ("c", vec![]),
+    ];
+    let violin = ViolinSeries::new(groups.clone(), &RED).resolution(5);
+    assert_eq!(violin.x_range(), -0.5..2.5);
+    assert_eq!(violin.y_range(), 0.0..8.0);
+    assert_eq!(violin.category(1.0), Some(&"b"));
+
+    let widest = |violin: &ViolinSeries<&str>| -> Vec<f64> {
+        violin
+            .violins()
+            .enumerate()
+            .map(|(idx, p)| {
+                let xs = p.point_iter().iter().map(|(x, _)| *x - idx as f64);
+                xs.clone().fold(0.0, f64::max) - xs.fold(0.0, f64::min)
+            })
+            .collect()
+    };
+    // Each violin is symmetric and the widest point reaches the full width
+    let widths = widest(&violin);
+    assert!(widths.iter().all(|w| (w - 0.8).abs() < 1e-9));
+
+    // With the equal area, the wider distribution has a narrower violin
+    let widths = widest(&violin.scale(ViolinScale::Area));
+    assert!((widths[0] - 0.8).abs() < 1e-9);
+    assert!(widths[1] < 0.5);
+
+    let violin = ViolinSeries::new(groups, &RED);
+    let medians: Vec<_> = violin.medians(&RED).map(|p| p.point_iter()[0].1).collect();
+    assert_eq!(medians, vec![1.0, 4.0]);
+
+    // The non-finite samples don't break the range or the density estimate
+    let samples = vec![
+        1.0,
+        3.0,
+        std::f64::NAN,
+        std::f64::INFINITY,
+        std::f64::NEG_INFINITY,
+    ];
+    let violin = ViolinSeries::new(vec![("d", samples)], &RED).resolution(5);
+    assert_eq!(violin.y_range(), 1.0..3.0);
+    assert!(violin
+        .violins()
+        .flat_map(|p| p.point_iter().to_vec())
+        .all(|(x, y)| x.is_finite() && y.is_finite()));
+}
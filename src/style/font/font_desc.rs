@@ -16,6 +16,9 @@ pub enum FontTransform {
     Rotate90,
     Rotate180,
     Rotate270,
+    /// Stack the glyphs from top to bottom without rotating them,
+    /// which is the vertical layout of CJK text
+    Vertical,
 }
 
 impl FontTransform {
@@ -27,6 +30,7 @@ impl FontTransform {
             FontTransform::Rotate90 => ((layout.1).1 - (layout.0).1, 0),
             FontTransform::Rotate180 => ((layout.1).0 - (layout.0).0, (layout.1).1 - (layout.0).1),
             FontTransform::Rotate270 => (0, (layout.1).0 - (layout.0).0),
+            FontTransform::Vertical => (0, 0),
         }
     }
 
@@ -37,6 +41,7 @@ impl FontTransform {
             FontTransform::Rotate90 => (-y, x),
             FontTransform::Rotate180 => (-x, -y),
             FontTransform::Rotate270 => (y, -x),
+            FontTransform::Vertical => (x, y),
         }
     }
 }
@@ -129,11 +134,41 @@ impl<'a> FontDesc<'a> {
     /// Get the size of the text if rendered in this font
     pub fn layout_box(&self, text: &str) -> FontResult<((i32, i32), (i32, i32))> {
         match &self.data {
-            Ok(ref font) => font.estimate_layout(self.size, text),
+            Ok(ref font) => {
+                if let FontTransform::Vertical = self.transform {
+                    let mut width = 0;
+                    for c in text.chars() {
+                        let mut buf = [0; 4];
+                        let ((x0, _), (x1, _)) =
+                            font.estimate_layout(self.size, c.encode_utf8(&mut buf))?;
+                        width = width.max(x1 - x0);
+                    }
+                    let height = (self.size * text.chars().count() as f64).ceil() as i32;
+                    return Ok(((0, 0), (width, height)));
+                }
+                font.estimate_layout(self.size, text)
+            }
             Err(e) => Err(e.clone()),
         }
     }
 
+    /// Get the position of each glyph of the text if it's rendered with the vertical layout,
+    /// the position is relative to the upper-left corner of the text
+    pub fn vertical_glyph_positions<'b>(
+        &self,
+        text: &'b str,
+    ) -> impl Iterator<Item = ((i32, i32), &'b str)> {
+        let size = self.size;
+        text.char_indices()
+            .enumerate()
+            .map(move |(idx, (offset, c))| {
+                (
+                    (0, (size * idx as f64).round() as i32),
+                    &text[offset..offset + c.len_utf8()],
+                )
+            })
+    }
+
     /// Get the size of the text if rendered in this font
     pub fn box_size(&self, text: &str) -> FontResult<(u32, u32)> {
         let ((min_x, min_y), (max_x, max_y)) = self.layout_box(text)?;
@@ -147,9 +182,37 @@ impl<'a> FontDesc<'a> {
         text: &str,
         (x, y): (i32, i32),
         draw: DrawFunc,
+    ) -> FontResult<Result<(), E>> {
+        self.draw_subpixel(text, (x as f32, y as f32), draw)
+    }
+
+    /// Draws a font at a sub-pixel position with a drawing function, the glyphs are rasterized
+    /// with the fractional offset, which makes a moving text smooth
+    pub fn draw_subpixel<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
+        &self,
+        text: &str,
+        (x, y): (f32, f32),
+        mut draw: DrawFunc,
     ) -> FontResult<Result<(), E>> {
         match &self.data {
-            Ok(ref font) => font.draw((x, y), self.size, text, self.get_transform(), draw),
+            Ok(ref font) => {
+                if let FontTransform::Vertical = self.transform {
+                    for ((dx, dy), glyph) in self.vertical_glyph_positions(text) {
+                        let result = font.draw_subpixel(
+                            (x + dx as f32, y + dy as f32),
+                            self.size,
+                            glyph,
+                            FontTransform::None,
+                            &mut draw,
+                        )?;
+                        if result.is_err() {
+                            return Ok(result);
+                        }
+                    }
+                    return Ok(Ok(()));
+                }
+                font.draw_subpixel((x, y), self.size, text, self.get_transform(), draw)
+            }
             Err(e) => Err(e.clone()),
         }
     }
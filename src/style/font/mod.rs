@@ -37,4 +37,22 @@ pub trait FontData: Clone {
     ) -> Result<Result<(), E>, Self::ErrorType> {
         panic!("The font implementation is unable to rasterize font");
     }
+    /// Draw the text at a sub-pixel position, by default the position is rounded to the nearest
+    /// pixel
+    fn draw_subpixel<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
+        &self,
+        pos: (f32, f32),
+        size: f64,
+        text: &str,
+        trans: FontTransform,
+        draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::ErrorType> {
+        self.draw(
+            (pos.0.round() as i32, pos.1.round() as i32),
+            size,
+            text,
+            trans,
+            draw,
+        )
+    }
 }
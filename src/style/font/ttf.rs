@@ -152,6 +152,16 @@ impl FontData for FontDataInternal {
         size: f64,
         text: &str,
         trans: FontTransform,
+        draw: DrawFunc,
+    ) -> Result<Result<(), E>, Self::ErrorType> {
+        self.draw_subpixel((x as f32, y as f32), size, text, trans, draw)
+    }
+    fn draw_subpixel<E, DrawFunc: FnMut(i32, i32, f32) -> Result<(), E>>(
+        &self,
+        (x, y): (f32, f32),
+        size: f64,
+        text: &str,
+        trans: FontTransform,
         mut draw: DrawFunc,
     ) -> Result<Result<(), E>, Self::ErrorType> {
         //let ((_, b), (_, _)) = self.estimate_layout(size, text)?;
@@ -161,10 +171,17 @@ impl FontData for FontDataInternal {
         let mut result = Ok(());
         let font = self.0;
 
-        let base_x = x + trans.offset(layout).0;
-        let base_y = y + trans.offset(layout).1;
+        // The fractional part of the position is handed to the rasterizer, so the glyph coverage
+        // is shifted accordingly. Rotated text is snapped to the nearest pixel.
+        let (x, y, sub_pixel) = match trans {
+            FontTransform::None => (x.floor(), y.floor(), point(x - x.floor(), y - y.floor())),
+            _ => (x.round(), y.round(), point(0.0, 0.0)),
+        };
+
+        let base_x = x as i32 + trans.offset(layout).0;
+        let base_y = y as i32 + trans.offset(layout).1;
 
-        for g in font.layout(text, scale, point(0.0, 0.0)) {
+        for g in font.layout(text, scale, sub_pixel) {
             if let Some(rect) = g.pixel_bounding_box() {
                 let x0 = rect.min.x;
                 let y0 = rect.min.y - (layout.0).1;
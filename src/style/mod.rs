@@ -37,6 +37,11 @@ impl<'a> TextStyle<'a> {
             color: self.color.clone(),
         }
     }
+
+    /// Stack the glyphs of the text from top to bottom, which is the vertical layout of CJK text
+    pub fn vertical(&self) -> Self {
+        self.transform(FontTransform::Vertical)
+    }
 }
 
 /// Make sure that we are able to automatically copy the `TextStyle`
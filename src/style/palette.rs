@@ -1,4 +1,4 @@
-use super::color::PaletteColor;
+use super::color::{PaletteColor, RGBColor};
 
 pub trait Palette {
     const COLORS: &'static [(u8, u8, u8)];
@@ -8,6 +8,22 @@ pub trait Palette {
     {
         PaletteColor::<Self>::pick(idx)
     }
+
+    /// Get the colors for `n` series, the palette is cycled if `n` is larger than the number
+    /// of the colors in the palette
+    /// - `n`: The number of the colors
+    /// - **returns**: The colors in the order of the palette
+    fn colors(n: usize) -> Vec<RGBColor>
+    where
+        Self: Sized,
+    {
+        (0..n)
+            .map(|idx| {
+                let (r, g, b) = Self::COLORS[idx % Self::COLORS.len()];
+                RGBColor(r, g, b)
+            })
+            .collect()
+    }
 }
 
 /// The palette of 99% accessibility
@@ -61,3 +77,47 @@ impl Palette for Palette100 {
     const COLORS: &'static [(u8, u8, u8)] =
         &[(255, 225, 25), (0, 130, 200), (128, 128, 128), (0, 0, 0)];
 }
+
+/// The categorical palette of Tableau, which has 10 distinct colors
+pub struct PaletteTableau10;
+/// The colorblind-safe palette by Okabe and Ito
+pub struct PaletteOkabeIto;
+
+impl Palette for PaletteTableau10 {
+    const COLORS: &'static [(u8, u8, u8)] = &[
+        (31, 119, 180),
+        (255, 127, 14),
+        (44, 160, 44),
+        (214, 39, 40),
+        (148, 103, 189),
+        (140, 86, 75),
+        (227, 119, 194),
+        (127, 127, 127),
+        (188, 189, 34),
+        (23, 190, 207),
+    ];
+}
+
+impl Palette for PaletteOkabeIto {
+    const COLORS: &'static [(u8, u8, u8)] = &[
+        (230, 159, 0),
+        (86, 180, 233),
+        (0, 158, 115),
+        (240, 228, 66),
+        (0, 114, 178),
+        (213, 94, 0),
+        (204, 121, 167),
+        (0, 0, 0),
+    ];
+}
+
+#[cfg(test)]
+#[test]
+fn test_palette_colors() {
+    use crate::style::SimpleColor;
+    let colors = PaletteTableau10::colors(12);
+    assert_eq!(colors.len(), 12);
+    assert_eq!(colors[0].rgb(), (31, 119, 180));
+    assert_eq!(colors[10].rgb(), colors[0].rgb());
+    assert_eq!(PaletteOkabeIto::pick(9).rgb(), (86, 180, 233));
+}
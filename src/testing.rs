@@ -0,0 +1,118 @@
+/*!
+  The helpers for the golden-image tests, which render the drawing to a deterministic bitmap
+  buffer and compare it with a reference image.
+
+  ```
+  use plotters::prelude::*;
+  use plotters::testing::{diff_pixels, render_to_bytes};
+  # fn main() -> Result<(), Box<dyn std::error::Error>> {
+  let actual = render_to_bytes((320, 240), |root| {
+      root.fill(&WHITE)?;
+      Ok(())
+  })?;
+  // The reference is usually a golden image, e.g. `load_reference("tests/golden/white.png")?`
+  let reference = vec![255; 320 * 240 * 3];
+  assert_eq!(diff_pixels(&actual, &reference, 0), 0);
+  # Ok(())
+  # }
+  ```
+*/
+use crate::coord::Shift;
+use crate::drawing::{BitMapBackend, DrawingArea, IntoDrawingArea};
+use image::{ColorType, ImageError};
+use std::path::Path;
+
+/// Render the drawing on a bitmap of the given size and get the raw RGB bytes of the image
+/// - `size`: The size of the bitmap
+/// - `draw`: The function that draws on the root drawing area
+/// - **returns**: The raw pixels, 3 bytes for each pixel in row-major order
+pub fn render_to_bytes<
+    Draw: FnOnce(DrawingArea<BitMapBackend, Shift>) -> Result<(), Box<dyn std::error::Error>>,
+>(
+    size: (u32, u32),
+    draw: Draw,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut buffer = vec![];
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, size).into_drawing_area();
+        draw(root)?;
+    }
+    Ok(buffer)
+}
+
+/// Count the pixels that differ between two raw RGB images of the same size. The pixels that
+/// only exist in one of the images are counted as different.
+/// - `actual`: The raw pixels of the rendered image
+/// - `reference`: The raw pixels of the reference image
+/// - `tolerance`: The largest difference of a color channel that is considered the same
+/// - **returns**: The number of different pixels
+pub fn diff_pixels(actual: &[u8], reference: &[u8], tolerance: u8) -> usize {
+    // Only the whole pixels both images have are compared, a trailing partial pixel of the
+    // shorter image is counted with the extra pixels of the longer one
+    let common_len = actual.len().min(reference.len()) / 3 * 3;
+    let common = actual[..common_len]
+        .chunks(3)
+        .zip(reference[..common_len].chunks(3))
+        .filter(|(a, b)| {
+            a.iter()
+                .zip(b.iter())
+                .any(|(x, y)| (i16::from(*x) - i16::from(*y)).abs() > i16::from(tolerance))
+        })
+        .count();
+    let extra = (actual.len().max(reference.len()) + 2) / 3 - common_len / 3;
+    common + extra
+}
+
+/// Load a reference image as raw RGB pixels
+/// - `path`: The path to the image, in any format that can be decoded
+pub fn load_reference<P: AsRef<Path>>(path: P) -> Result<Vec<u8>, ImageError> {
+    Ok(image::open(path)?.to_rgb().into_raw())
+}
+
+/// Save the raw RGB pixels as a reference image, the format is decided by the file extension
+/// - `path`: The path to the image
+/// - `size`: The size of the image
+/// - `pixels`: The raw pixels
+pub fn save_reference<P: AsRef<Path>>(
+    path: P,
+    size: (u32, u32),
+    pixels: &[u8],
+) -> Result<(), std::io::Error> {
+    image::save_buffer(path, pixels, size.0, size.1, ColorType::RGB(8))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_render_and_diff() {
+        let white = render_to_bytes((10, 10), |root| {
+            root.fill(&WHITE)?;
+            Ok(())
+        })
+        .expect("Drawing Failure");
+        assert_eq!(white.len(), 300);
+
+        let dotted = render_to_bytes((10, 10), |root| {
+            root.fill(&WHITE)?;
+            root.draw_pixel((5, 5), &RED)?;
+            Ok(())
+        })
+        .expect("Drawing Failure");
+
+        assert_eq!(diff_pixels(&white, &white, 0), 0);
+        assert_eq!(diff_pixels(&white, &dotted, 0), 1);
+        assert_eq!(diff_pixels(&white, &dotted[..150], 0), 50);
+    }
+
+    #[test]
+    fn test_diff_partial_pixel() {
+        let white = vec![255; 300];
+        // The 51st pixel is cut after its first channel, it's counted once as different
+        assert_eq!(diff_pixels(&white, &white[..151], 0), 50);
+        assert_eq!(diff_pixels(&white[..151], &white, 0), 50);
+        assert_eq!(diff_pixels(&white[..151], &white[..150], 0), 1);
+    }
+}